@@ -0,0 +1,385 @@
+//! Approximate equality with absolute, relative and ULPs tolerances.
+//!
+//! [`ApproxEq::first_mismatch`] walks two values in storage order and reports
+//! the first scalar pair that is not within a [`Tolerance`], as a
+//! [`Mismatch`] carrying its location, both values and their difference.
+//! [`assert_approx_eq!`](crate::assert_approx_eq) panics with that report.
+//!
+//! Two scalars `a`, `b` are approximately equal if they are equal (including
+//! equal infinities) or, both being finite, if any of the criteria holds:
+//!
+//! - absolute: `|a - b| <= abs`
+//! - relative: `|a - b| <= rel * max(|a|, |b|)`
+//! - ULPs: `a` and `b` have the same sign and are at most `ulps` representable
+//!   floats apart
+//!
+//! NaN is never approximately equal to anything.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::approx::{ApproxEq, Tolerance};
+//! use strelitzia::assert_approx_eq;
+//! use strelitzia::multiarray::Vector3;
+//!
+//! let a = Vector3::new(1.0, 2.0, 3.0);
+//! let b = a + Vector3::new(0.0, 1e-14, 0.0);
+//! assert_approx_eq!(a, b);
+//! assert_approx_eq!(a, b, abs = 1e-13);
+//! assert!(!a.approx_eq(&b, Tolerance::ulps(4)));
+//!
+//! let m = a.first_mismatch(&b, Tolerance::absolute(1e-15)).unwrap();
+//! assert_eq!(m.index, "[1]");
+//! ```
+
+use crate::common::{Complex, Real};
+use crate::fields::Field;
+use crate::multiarray::{DenseRawStorage, MultiArray};
+use std::fmt;
+
+/// Tolerance for [`ApproxEq`]. Scalars match if any criterion holds; a zero
+/// criterion never matches on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Maximum absolute difference.
+    pub abs: Real,
+    /// Maximum difference relative to the larger magnitude.
+    pub rel: Real,
+    /// Maximum distance in units in the last place.
+    pub ulps: u32,
+}
+
+impl Default for Tolerance {
+    /// `abs = rel = 1e-12`, `ulps = 4`: round-off level for `Real = f64`.
+    fn default() -> Self {
+        Self {
+            abs: 1e-12,
+            rel: 1e-12,
+            ulps: 4,
+        }
+    }
+}
+
+impl Tolerance {
+    /// Exact comparison: only equal values match.
+    pub const EXACT: Self = Self {
+        abs: 0.0,
+        rel: 0.0,
+        ulps: 0,
+    };
+
+    /// Absolute tolerance only.
+    pub const fn absolute(abs: Real) -> Self {
+        Self { abs, ..Self::EXACT }
+    }
+
+    /// Relative tolerance only.
+    pub const fn relative(rel: Real) -> Self {
+        Self { rel, ..Self::EXACT }
+    }
+
+    /// ULPs tolerance only.
+    pub const fn ulps(ulps: u32) -> Self {
+        Self {
+            ulps,
+            ..Self::EXACT
+        }
+    }
+
+    pub const fn with_abs(self, abs: Real) -> Self {
+        Self { abs, ..self }
+    }
+
+    pub const fn with_rel(self, rel: Real) -> Self {
+        Self { rel, ..self }
+    }
+
+    pub const fn with_ulps(self, ulps: u32) -> Self {
+        Self { ulps, ..self }
+    }
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "abs = {:e}, rel = {:e}, ulps = {}",
+            self.abs, self.rel, self.ulps
+        )
+    }
+}
+
+/// The first pair of values that differ by more than a [`Tolerance`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// Location within the compared values, e.g. `[4]`, `seeds[2][1]` or
+    /// `cells.len()` (empty for bare scalars).
+    pub index: String,
+    pub left: String,
+    pub right: String,
+    /// `|left - right|`, for numeric values.
+    pub diff: Option<f64>,
+}
+
+impl Mismatch {
+    /// Mismatch between two numbers.
+    pub fn numeric(left: f64, right: f64) -> Self {
+        Self {
+            index: String::new(),
+            left: format!("{left:?}"),
+            right: format!("{right:?}"),
+            diff: Some((left - right).abs()),
+        }
+    }
+
+    /// Mismatch between two non-numeric values (no difference reported).
+    pub fn other(left: impl fmt::Debug, right: impl fmt::Debug) -> Self {
+        Self {
+            index: String::new(),
+            left: format!("{left:?}"),
+            right: format!("{right:?}"),
+            diff: None,
+        }
+    }
+
+    /// Mismatch between two lengths, at index `len()`.
+    pub fn length(left: usize, right: usize) -> Self {
+        Self::numeric(left as f64, right as f64).within("len()")
+    }
+
+    /// Mismatch between two `(nrows, ncols)` shapes, at index `shape`.
+    pub fn shape(left: (usize, usize), right: (usize, usize)) -> Self {
+        Self::other(left, right).within("shape")
+    }
+
+    /// Prefixes the location with `outer`: `[3]` within `seeds` becomes
+    /// `seeds[3]`, `len()` within `cells` becomes `cells.len()`.
+    pub fn within(mut self, outer: impl fmt::Display) -> Self {
+        self.index = if self.index.is_empty() || self.index.starts_with('[') {
+            format!("{outer}{}", self.index)
+        } else {
+            format!("{outer}.{}", self.index)
+        };
+        self
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.index.is_empty() {
+            write!(f, "at {}: ", self.index)?;
+        }
+        write!(f, "left = {}, right = {}", self.left, self.right)?;
+        if let Some(diff) = self.diff {
+            write!(f, ", diff = {diff:e}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Approximate equality within a [`Tolerance`].
+pub trait ApproxEq {
+    /// The first mismatch in storage order, or `None` if `self` and `other`
+    /// are approximately equal.
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch>;
+
+    fn approx_eq(&self, other: &Self, tol: Tolerance) -> bool {
+        self.first_mismatch(other, tol).is_none()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, abs: Real) -> bool {
+        self.approx_eq(other, Tolerance::absolute(abs))
+    }
+
+    fn relative_eq(&self, other: &Self, rel: Real) -> bool {
+        self.approx_eq(other, Tolerance::relative(rel))
+    }
+
+    fn ulps_eq(&self, other: &Self, ulps: u32) -> bool {
+        self.approx_eq(other, Tolerance::ulps(ulps))
+    }
+}
+
+// ============================================================================
+// Scalars
+// ============================================================================
+
+macro_rules! impl_approx_eq_float {
+    ($($t:ty),*) => {$(
+        impl ApproxEq for $t {
+            fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+                let (a, b) = (*self, *other);
+                if a == b {
+                    return None;
+                }
+                if a.is_finite() && b.is_finite() {
+                    let diff = (a - b).abs();
+                    if diff <= tol.abs as $t
+                        || diff <= tol.rel as $t * a.abs().max(b.abs())
+                        || (a.is_sign_positive() == b.is_sign_positive()
+                            && a.to_bits().abs_diff(b.to_bits()) <= tol.ulps.into())
+                    {
+                        return None;
+                    }
+                }
+                Some(Mismatch::numeric(a as f64, b as f64))
+            }
+        }
+    )*};
+}
+
+impl_approx_eq_float!(f32, f64);
+
+impl ApproxEq for Complex {
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        let re = self
+            .re
+            .first_mismatch(&other.re, tol)
+            .map(|m| m.within("re"));
+        re.or_else(|| {
+            self.im
+                .first_mismatch(&other.im, tol)
+                .map(|m| m.within("im"))
+        })
+    }
+}
+
+/// Element-wise over `[T]`, with a length check first.
+fn first_mismatch_in<T: ApproxEq>(a: &[T], b: &[T], tol: Tolerance) -> Option<Mismatch> {
+    if a.len() != b.len() {
+        return Some(Mismatch::length(a.len(), b.len()));
+    }
+    a.iter().zip(b).enumerate().find_map(|(i, (x, y))| {
+        x.first_mismatch(y, tol)
+            .map(|m| m.within(format_args!("[{i}]")))
+    })
+}
+
+// ============================================================================
+// MultiArray and Field
+// ============================================================================
+
+/// Dense arrays, element-wise in storage order (column-major for matrices);
+/// the index is the flat storage index. Dynamic arrays report a `shape`
+/// mismatch first when their `(nrows, ncols)` differ.
+impl<T: ApproxEq, S, B: DenseRawStorage<T>> ApproxEq for MultiArray<T, S, B> {
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        let (lhs, rhs) = (self.data.matrix_dims(), other.data.matrix_dims());
+        if lhs != rhs {
+            return Some(Mismatch::shape(lhs, rhs));
+        }
+        first_mismatch_in(self.data.as_slice(), other.data.as_slice(), tol)
+    }
+}
+
+/// Element-wise; the index is the element index, followed by the location
+/// within the element (`[3][1]`).
+impl<T: ApproxEq> ApproxEq for Field<T> {
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        first_mismatch_in(self.as_slice(), other.as_slice(), tol)
+    }
+}
+
+// ============================================================================
+// Assertion macros
+// ============================================================================
+
+/// Asserts that two values are approximately equal ([`ApproxEq`]).
+///
+/// The tolerance defaults to [`Tolerance::default`]; pass `abs = ..`,
+/// `rel = ..`, `ulps = ..` or a full `tol = ..` to override it. On failure
+/// the message shows the first mismatch: its index, both values and their
+/// difference.
+///
+/// ```should_panic
+/// use strelitzia::assert_approx_eq;
+/// use strelitzia::fields::ScalarField;
+///
+/// let a: ScalarField = [1.0, 2.0, 3.0].into_iter().collect();
+/// let b: ScalarField = [1.0, 2.1, 3.0].into_iter().collect();
+/// // panics: "... first mismatch at [1]: left = 2.0, right = 2.1, diff = 1.0..."
+/// assert_approx_eq!(a, b, rel = 1e-3);
+/// ```
+///
+/// [`ApproxEq`]: crate::approx::ApproxEq
+/// [`Tolerance::default`]: crate::approx::Tolerance
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, tol = $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, abs = $abs:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            $left,
+            $right,
+            tol = $crate::approx::Tolerance::absolute($abs)
+        )
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_approx_eq!(
+            $left,
+            $right,
+            tol = $crate::approx::Tolerance::relative($rel)
+        )
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, tol = $crate::approx::Tolerance::ulps($ulps))
+    };
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        match (&$left, &$right, $tol) {
+            (left, right, tol) => {
+                if let Some(mismatch) = $crate::approx::ApproxEq::first_mismatch(left, right, tol) {
+                    panic!(
+                        "assertion `{} ≈ {}` failed ({})\n  first mismatch {}",
+                        stringify!($left),
+                        stringify!($right),
+                        tol,
+                        mismatch
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are *not* approximately equal ([`ApproxEq`]).
+/// Takes the same tolerance arguments as [`assert_approx_eq!`].
+///
+/// [`ApproxEq`]: crate::approx::ApproxEq
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, tol = $crate::approx::Tolerance::default())
+    };
+    ($left:expr, $right:expr, abs = $abs:expr $(,)?) => {
+        $crate::assert_approx_ne!(
+            $left,
+            $right,
+            tol = $crate::approx::Tolerance::absolute($abs)
+        )
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_approx_ne!(
+            $left,
+            $right,
+            tol = $crate::approx::Tolerance::relative($rel)
+        )
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        $crate::assert_approx_ne!($left, $right, tol = $crate::approx::Tolerance::ulps($ulps))
+    };
+    ($left:expr, $right:expr, tol = $tol:expr $(,)?) => {
+        match (&$left, &$right, $tol) {
+            (left, right, tol) => {
+                if $crate::approx::ApproxEq::approx_eq(left, right, tol) {
+                    panic!(
+                        "assertion `{} ≉ {}` failed ({})",
+                        stringify!($left),
+                        stringify!($right),
+                        tol
+                    );
+                }
+            }
+        }
+    };
+}
@@ -1,7 +1,8 @@
 //! Crate-wide foundational types.
 //!
 //! Contains definitions shared across all modules: the precision-controlled
-//! floating-point scalar `Real`, and fixed-width integer types `Int` and `UInt`.
+//! floating-point scalar `Real`, its complex counterpart `Complex`, and
+//! fixed-width integer types `Int` and `UInt`.
 
 // ============================================================================
 // Floating-point precision control via feature flag
@@ -18,6 +19,12 @@ pub type Real = f32;
 #[cfg(not(feature = "single-precision"))]
 pub type Real = f64;
 
+/// Complex scalar over `Real`, for frequency-domain and eigen analyses.
+///
+/// nalgebra's re-export of `num_complex::Complex`: `#[repr(C)]` with the real
+/// part first, so `[Complex]` reinterprets as interleaved `[re, im]` pairs.
+pub type Complex = nalgebra::Complex<Real>;
+
 // ============================================================================
 // Integer types (fixed width, no feature flag)
 // ============================================================================
@@ -0,0 +1,216 @@
+//! BLAS level-1 kernels on fields.
+//!
+//! [`BlasOps`] provides `axpy`, `axpby`, `scale`, `dot`, `norm2` and
+//! `norm_inf` for any `Field<T>` with `Real` components. The kernels work on
+//! the [`SolverInterop`] flat view, so a `Vector3Field` behaves like one long
+//! vector: `dot` is `Σᵢ xᵢ · yᵢ` and `norm2` is `sqrt(Σᵢ |xᵢ|²)`.
+//!
+//! ```
+//! use strelitzia::fields::{BlasOps, Summation, Vector3Field};
+//! use strelitzia::multiarray::Vector3;
+//!
+//! let x: Vector3Field = [Vector3::new(1.0, 2.0, 2.0)].into_iter().collect();
+//! let mut y: Vector3Field = [Vector3::new(1.0, 0.0, 0.0)].into_iter().collect();
+//!
+//! y.axpy(2.0, &x); // y = 2 x + y
+//! assert_eq!(y[0], Vector3::new(3.0, 4.0, 4.0));
+//! assert_eq!(x.norm2(), 3.0);
+//! assert_eq!(x.dot_with(&y, Summation::Kahan), 19.0);
+//! ```
+//!
+//! `dot` and `norm2` use pairwise summation; `dot_with` / `norm2_with` choose
+//! the algorithm (see [`Summation`]).
+//!
+//! Only [`LinearElement`] fields qualify: rotations and unit quaternions have
+//! `Real` components too, but scaling or adding them would leave the group.
+//!
+//! ```compile_fail
+//! use strelitzia::fields::{BlasOps, QuaternionField};
+//!
+//! let mut q: QuaternionField = Default::default();
+//! q.scale(2.0); // QuaternionField is not BlasOps
+//! ```
+//!
+//! ```compile_fail
+//! use strelitzia::fields::{BlasOps, Rotation3Field};
+//!
+//! let mut r: Rotation3Field = Default::default();
+//! r.axpy(1.0, &r.clone()); // nor is Rotation3Field
+//! ```
+
+use super::{Field, FieldElement, SolverInterop};
+use crate::common::Real;
+use crate::multiarray::*;
+use nalgebra as na;
+
+// ============================================================================
+// LinearElement marker
+// ============================================================================
+
+/// Field elements whose `Real` components form a vector space, so any linear
+/// combination of components is again a valid element.
+///
+/// Implemented for `Real`, static and native vectors and matrices,
+/// `SymMatrix3`, rank-3/4 tensors and quantities of these. Rotations and
+/// quaternions are deliberately left out.
+pub trait LinearElement: FieldElement<Scalar = Real> {}
+
+impl LinearElement for Real {}
+
+impl<const N: usize> LinearElement for MultiArray<Real, Rank1<N>, na::SVector<Real, N>> {}
+
+impl<const R: usize, const C: usize> LinearElement
+    for MultiArray<Real, Rank2<R, C>, na::SMatrix<Real, R, C>>
+{
+}
+
+impl<const N: usize> LinearElement for NativeVector<Real, N> {}
+
+impl<const R: usize, const C: usize> LinearElement for NativeMatrix<Real, R, C> {}
+
+impl LinearElement for MultiArray<Real, SymRank2<3>, na::SVector<Real, 6>> {}
+
+impl<const D1: usize, const D2: usize, const D3: usize> LinearElement
+    for Tensor3<Real, D1, D2, D3>
+{
+}
+
+impl<const D1: usize, const D2: usize, const D3: usize, const D4: usize> LinearElement
+    for Tensor4<Real, D1, D2, D3, D4>
+{
+}
+
+// ============================================================================
+// BlasOps
+// ============================================================================
+
+/// Summation algorithm for `dot` and `norm2`.
+///
+/// The rounding error of a sum of `n` terms grows like `n ε` for
+/// [`Naive`](Summation::Naive), like `log₂(n) ε` for
+/// [`Pairwise`](Summation::Pairwise), and stays near `2 ε` for
+/// [`Kahan`](Summation::Kahan), at a few times the cost of the naive loop.
+/// Only the accumulation is compensated, not the products.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Summation {
+    /// Left-to-right accumulation, as in reference BLAS.
+    Naive,
+    /// Recursive halving down to blocks of `PAIRWISE_BLOCK` terms.
+    #[default]
+    Pairwise,
+    /// Kahan-Babuška (Neumaier) compensated accumulation.
+    Kahan,
+}
+
+/// Block length below which pairwise summation adds naively.
+pub const PAIRWISE_BLOCK: usize = 128;
+
+/// BLAS level-1 operations on the flat `Real` view of a field.
+///
+/// Operations taking a second field panic if the lengths differ.
+pub trait BlasOps {
+    /// `self = a * x + self`.
+    fn axpy(&mut self, a: Real, x: &Self);
+
+    /// `self = a * x + b * self`.
+    fn axpby(&mut self, a: Real, x: &Self, b: Real);
+
+    /// `self = a * self`.
+    fn scale(&mut self, a: Real);
+
+    /// Inner product `Σ selfₖ otherₖ` over all components, with pairwise
+    /// summation.
+    fn dot(&self, other: &Self) -> Real {
+        self.dot_with(other, Summation::default())
+    }
+
+    /// Inner product with the given summation algorithm.
+    fn dot_with(&self, other: &Self, summation: Summation) -> Real;
+
+    /// Euclidean norm over all components, with pairwise summation.
+    fn norm2(&self) -> Real {
+        self.norm2_with(Summation::default())
+    }
+
+    /// Euclidean norm with the given summation algorithm. The squares are
+    /// not rescaled, so components beyond `sqrt(Real::MAX)` overflow.
+    fn norm2_with(&self, summation: Summation) -> Real {
+        self.dot_with(self, summation).sqrt()
+    }
+
+    /// Largest absolute component, `0` for an empty field. NaN if any
+    /// component is NaN.
+    fn norm_inf(&self) -> Real;
+}
+
+impl<T: LinearElement> BlasOps for Field<T> {
+    fn axpy(&mut self, a: Real, x: &Self) {
+        assert_eq!(self.len(), x.len(), "Fields must have same length");
+        for (yk, xk) in self.as_flat_slice_mut().iter_mut().zip(x.as_flat_slice()) {
+            *yk += a * xk;
+        }
+    }
+
+    fn axpby(&mut self, a: Real, x: &Self, b: Real) {
+        assert_eq!(self.len(), x.len(), "Fields must have same length");
+        for (yk, xk) in self.as_flat_slice_mut().iter_mut().zip(x.as_flat_slice()) {
+            *yk = a * xk + b * *yk;
+        }
+    }
+
+    fn scale(&mut self, a: Real) {
+        for yk in self.as_flat_slice_mut() {
+            *yk *= a;
+        }
+    }
+
+    fn dot_with(&self, other: &Self, summation: Summation) -> Real {
+        assert_eq!(self.len(), other.len(), "Fields must have same length");
+        let (x, y) = (self.as_flat_slice(), other.as_flat_slice());
+        match summation {
+            Summation::Naive => naive_dot(x, y),
+            Summation::Pairwise => pairwise_dot(x, y),
+            Summation::Kahan => kahan_dot(x, y),
+        }
+    }
+
+    fn norm_inf(&self) -> Real {
+        self.as_flat_slice().iter().fold(0.0, |max, x| {
+            if x.abs() > max || x.is_nan() {
+                x.abs()
+            } else {
+                max
+            }
+        })
+    }
+}
+
+fn naive_dot(x: &[Real], y: &[Real]) -> Real {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+fn pairwise_dot(x: &[Real], y: &[Real]) -> Real {
+    if x.len() <= PAIRWISE_BLOCK {
+        return naive_dot(x, y);
+    }
+    let mid = x.len() / 2;
+    let (x0, x1) = x.split_at(mid);
+    let (y0, y1) = y.split_at(mid);
+    pairwise_dot(x0, y0) + pairwise_dot(x1, y1)
+}
+
+fn kahan_dot(x: &[Real], y: &[Real]) -> Real {
+    let (mut sum, mut compensation): (Real, Real) = (0.0, 0.0);
+    for (a, b) in x.iter().zip(y) {
+        let term = a * b;
+        let t = sum + term;
+        // Recover the low-order bits lost in `t` from the smaller operand.
+        compensation += if sum.abs() >= term.abs() {
+            (sum - t) + term
+        } else {
+            (term - t) + sum
+        };
+        sum = t;
+    }
+    sum + compensation
+}
@@ -0,0 +1,335 @@
+//! Lazy field expressions.
+//!
+//! `Field<T>` has no binary operators that allocate a new field (see
+//! [`ops`](super::ops)). Instead, `+`, `-`, unary `-` and scalar `*` / `/` on
+//! `&Field<T>` build a [`FieldExpr`]: a small tree that borrows its operands
+//! and computes nothing yet. Assigning it to, or adding it into, an existing
+//! field evaluates the whole tree in one fused pass, without temporaries:
+//!
+//! ```
+//! use strelitzia::fields::ScalarField;
+//!
+//! let a: ScalarField = [1.0, 2.0, 3.0].into_iter().collect();
+//! let b: ScalarField = [10.0, 20.0, 30.0].into_iter().collect();
+//! let c: ScalarField = [0.5, 0.5, 0.5].into_iter().collect();
+//!
+//! let mut u = ScalarField::from_vec(vec![0.0; 3]);
+//! u.assign(&a + &b * 2.0 - &c); // u[i] = a[i] + 2 b[i] - c[i]
+//! assert_eq!(u.as_slice(), &[20.5, 41.5, 62.5]);
+//!
+//! u -= 0.5 * (&b - &a); // u[i] -= 0.5 (b[i] - a[i])
+//! u += -&a / 2.0;
+//! assert_eq!(u.as_slice(), &[15.5, 31.5, 47.5]);
+//! ```
+//!
+//! Operand lengths are checked when each node is built, and the target length
+//! once per evaluation; the loop itself runs over plain iterators. Elements
+//! combine with their own operators, so a `Vector3Field` expression may be
+//! scaled by `Real` and a complex one by `Complex`. The only allocating
+//! operation is the explicit [`FieldExpr::to_field`].
+
+use super::Field;
+use crate::common::{Complex, Real};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A lazily evaluated, field-sized expression.
+///
+/// Implemented by `&Field<T>` and by the nodes that the operators build.
+pub trait FieldExpr {
+    /// Element type of the result.
+    type Item;
+
+    /// Number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the expression has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The elements, computed on the fly.
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_;
+
+    /// Evaluates the expression into a new field (allocates).
+    fn to_field(&self) -> Field<Self::Item> {
+        self.values().collect()
+    }
+}
+
+impl<T: Copy> FieldExpr for &Field<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        Field::len(self)
+    }
+
+    fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().copied()
+    }
+}
+
+impl<T> Field<T> {
+    /// Overwrites every element with the value of `expr`, in one pass.
+    pub fn assign<E: FieldExpr<Item = T>>(&mut self, expr: E) {
+        assert_eq!(self.len(), expr.len(), "Fields must have same length");
+        for (a, b) in self.iter_mut().zip(expr.values()) {
+            *a = b;
+        }
+    }
+}
+
+// ============================================================================
+// Expression nodes
+// ============================================================================
+
+fn check_same_len(left: &impl FieldExpr, right: &impl FieldExpr) {
+    assert_eq!(left.len(), right.len(), "Fields must have same length");
+}
+
+/// `left + right`, element-wise.
+#[derive(Clone, Copy, Debug)]
+pub struct AddExpr<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L: FieldExpr, R: FieldExpr> AddExpr<L, R> {
+    fn new(left: L, right: R) -> Self {
+        check_same_len(&left, &right);
+        Self { left, right }
+    }
+}
+
+impl<L, R> FieldExpr for AddExpr<L, R>
+where
+    L: FieldExpr,
+    R: FieldExpr,
+    L::Item: Add<R::Item>,
+{
+    type Item = <L::Item as Add<R::Item>>::Output;
+
+    fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_ {
+        self.left
+            .values()
+            .zip(self.right.values())
+            .map(|(a, b)| a + b)
+    }
+}
+
+/// `left - right`, element-wise.
+#[derive(Clone, Copy, Debug)]
+pub struct SubExpr<L, R> {
+    left: L,
+    right: R,
+}
+
+impl<L: FieldExpr, R: FieldExpr> SubExpr<L, R> {
+    fn new(left: L, right: R) -> Self {
+        check_same_len(&left, &right);
+        Self { left, right }
+    }
+}
+
+impl<L, R> FieldExpr for SubExpr<L, R>
+where
+    L: FieldExpr,
+    R: FieldExpr,
+    L::Item: Sub<R::Item>,
+{
+    type Item = <L::Item as Sub<R::Item>>::Output;
+
+    fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_ {
+        self.left
+            .values()
+            .zip(self.right.values())
+            .map(|(a, b)| a - b)
+    }
+}
+
+/// `-expr`, element-wise.
+#[derive(Clone, Copy, Debug)]
+pub struct NegExpr<E> {
+    expr: E,
+}
+
+impl<E> FieldExpr for NegExpr<E>
+where
+    E: FieldExpr,
+    E::Item: Neg,
+{
+    type Item = <E::Item as Neg>::Output;
+
+    fn len(&self) -> usize {
+        self.expr.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_ {
+        self.expr.values().map(|a| -a)
+    }
+}
+
+/// `scalar * expr`, for both `expr * scalar` and `scalar * expr` (as the
+/// scalar compound operators, the scalar is applied from the left).
+#[derive(Clone, Copy, Debug)]
+pub struct ScaleExpr<E, S> {
+    expr: E,
+    scalar: S,
+}
+
+impl<E, S> FieldExpr for ScaleExpr<E, S>
+where
+    E: FieldExpr,
+    S: Mul<E::Item> + Copy,
+{
+    type Item = <S as Mul<E::Item>>::Output;
+
+    fn len(&self) -> usize {
+        self.expr.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_ {
+        let s = self.scalar;
+        self.expr.values().map(move |a| s * a)
+    }
+}
+
+/// `expr / scalar`, element-wise.
+#[derive(Clone, Copy, Debug)]
+pub struct DivExpr<E, S> {
+    expr: E,
+    scalar: S,
+}
+
+impl<E, S> FieldExpr for DivExpr<E, S>
+where
+    E: FieldExpr,
+    E::Item: Div<S>,
+    S: Copy,
+{
+    type Item = <E::Item as Div<S>>::Output;
+
+    fn len(&self) -> usize {
+        self.expr.len()
+    }
+
+    fn values(&self) -> impl Iterator<Item = Self::Item> + '_ {
+        let s = self.scalar;
+        self.expr.values().map(move |a| a / s)
+    }
+}
+
+// ============================================================================
+// Operators building expressions
+// ============================================================================
+
+// Example expansion -- impl_expr_ops!(['a, T] &'a Field<T>;):
+//
+//   impl<'a, T, Rhs: FieldExpr> Add<Rhs> for &'a Field<T>
+//   where
+//       Self: FieldExpr,
+//       <Self as FieldExpr>::Item: Add<Rhs::Item>,
+//   {
+//       type Output = AddExpr<Self, Rhs>;
+//       fn add(self, rhs: Rhs) -> AddExpr<Self, Rhs> { AddExpr::new(self, rhs) }
+//   }
+//   // ... likewise Sub and Neg, then impl_expr_scalar_ops! for Real and Complex
+macro_rules! impl_expr_ops {
+    ($([$($gen:tt)*] $Node:ty;)+) => {
+        $(
+            impl<$($gen)*, Rhs: FieldExpr> Add<Rhs> for $Node
+            where
+                Self: FieldExpr,
+                <Self as FieldExpr>::Item: Add<Rhs::Item>,
+            {
+                type Output = AddExpr<Self, Rhs>;
+                fn add(self, rhs: Rhs) -> AddExpr<Self, Rhs> {
+                    AddExpr::new(self, rhs)
+                }
+            }
+
+            impl<$($gen)*, Rhs: FieldExpr> Sub<Rhs> for $Node
+            where
+                Self: FieldExpr,
+                <Self as FieldExpr>::Item: Sub<Rhs::Item>,
+            {
+                type Output = SubExpr<Self, Rhs>;
+                fn sub(self, rhs: Rhs) -> SubExpr<Self, Rhs> {
+                    SubExpr::new(self, rhs)
+                }
+            }
+
+            impl<$($gen)*> Neg for $Node
+            where
+                Self: FieldExpr,
+                <Self as FieldExpr>::Item: Neg,
+            {
+                type Output = NegExpr<Self>;
+                fn neg(self) -> NegExpr<Self> {
+                    NegExpr { expr: self }
+                }
+            }
+
+            impl_expr_scalar_ops!(Real, [$($gen)*] $Node);
+            impl_expr_scalar_ops!(Complex, [$($gen)*] $Node);
+        )+
+    };
+}
+
+// Example expansion -- impl_expr_scalar_ops!(Real, [E] NegExpr<E>):
+//
+//   impl<E> Mul<Real> for NegExpr<E> { type Output = ScaleExpr<Self, Real>; ... }
+//   impl<E> Mul<NegExpr<E>> for Real { type Output = ScaleExpr<NegExpr<E>, Real>; ... }
+//   impl<E> Div<Real> for NegExpr<E> { type Output = DivExpr<Self, Real>; ... }
+macro_rules! impl_expr_scalar_ops {
+    ($S:ty, [$($gen:tt)*] $Node:ty) => {
+        impl<$($gen)*> Mul<$S> for $Node
+        where
+            Self: FieldExpr,
+            $S: Mul<<Self as FieldExpr>::Item>,
+        {
+            type Output = ScaleExpr<Self, $S>;
+            fn mul(self, scalar: $S) -> ScaleExpr<Self, $S> {
+                ScaleExpr { expr: self, scalar }
+            }
+        }
+
+        impl<$($gen)*> Mul<$Node> for $S
+        where
+            $Node: FieldExpr,
+            $S: Mul<<$Node as FieldExpr>::Item>,
+        {
+            type Output = ScaleExpr<$Node, $S>;
+            fn mul(self, expr: $Node) -> ScaleExpr<$Node, $S> {
+                ScaleExpr { expr, scalar: self }
+            }
+        }
+
+        impl<$($gen)*> Div<$S> for $Node
+        where
+            Self: FieldExpr,
+            <Self as FieldExpr>::Item: Div<$S>,
+        {
+            type Output = DivExpr<Self, $S>;
+            fn div(self, scalar: $S) -> DivExpr<Self, $S> {
+                DivExpr { expr: self, scalar }
+            }
+        }
+    };
+}
+
+impl_expr_ops! {
+    ['a, T] &'a Field<T>;
+    [L, R] AddExpr<L, R>;
+    [L, R] SubExpr<L, R>;
+    [E] NegExpr<E>;
+    [E, S] ScaleExpr<E, S>;
+    [E, S] DivExpr<E, S>;
+}
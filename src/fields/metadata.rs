@@ -0,0 +1,84 @@
+//! Optional field metadata.
+//!
+//! A [`Field<T>`](super::Field) can carry a [`FieldMeta`]: a name, a units
+//! string, the mesh location its values belong to and a simulation time.
+//! Metadata is optional and does not affect any field operation; it is what
+//! a [`FieldSet`](crate::meshgen::field_set::FieldSet) uses to key, validate
+//! and export fields.
+//!
+//! ```
+//! use strelitzia::fields::{FieldMeta, MeshLocation, ScalarField};
+//!
+//! let temperature = ScalarField::from_vec(vec![300.0, 310.0]).with_meta(
+//!     FieldMeta::new("temperature")
+//!         .with_units("K")
+//!         .with_location(MeshLocation::Vertex)
+//!         .with_time(0.5),
+//! );
+//! assert_eq!(temperature.name(), Some("temperature"));
+//! assert_eq!(temperature.meta().unwrap().units.as_deref(), Some("K"));
+//! ```
+
+use crate::common::Real;
+use std::fmt;
+
+/// Mesh entity a field stores one value per.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MeshLocation {
+    Vertex,
+    Cell,
+    Face,
+}
+
+impl fmt::Display for MeshLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshLocation::Vertex => write!(f, "vertex"),
+            MeshLocation::Cell => write!(f, "cell"),
+            MeshLocation::Face => write!(f, "face"),
+        }
+    }
+}
+
+/// Descriptive metadata of a field.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldMeta {
+    /// Name shown in ParaView and used as the key in a `FieldSet`.
+    pub name: String,
+    /// Free-form units, e.g. `"m/s"`; informational only.
+    pub units: Option<String>,
+    /// Mesh entity the values belong to.
+    pub location: Option<MeshLocation>,
+    /// Simulation time of the values.
+    pub time: Option<Real>,
+}
+
+impl FieldMeta {
+    /// Creates metadata with only a name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the units string.
+    pub fn with_units(mut self, units: impl Into<String>) -> Self {
+        self.units = Some(units.into());
+        self
+    }
+
+    /// Sets the mesh location.
+    pub fn with_location(mut self, location: MeshLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the simulation time.
+    pub fn with_time(mut self, time: Real) -> Self {
+        self.time = Some(time);
+        self
+    }
+}
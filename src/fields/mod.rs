@@ -19,10 +19,20 @@
 //! assert_eq!(flat, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
 //! ```
 
+mod blas;
 mod cast;
+mod expr;
+mod metadata;
 mod ops;
+mod parallel;
+mod soa;
 mod storage;
 
+pub use blas::*;
 pub use cast::*;
+pub use expr::*;
+pub use metadata::*;
 pub use ops::*;
+pub use parallel::*;
+pub use soa::*;
 pub use storage::*;
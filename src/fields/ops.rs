@@ -7,7 +7,9 @@
 //!
 //! Only **compound assignment** operators are provided for `Field<T>` to avoid
 //! hidden large allocations. Binary operators that would copy the entire data
-//! array are intentionally omitted.
+//! array are intentionally omitted; binary operators on `&Field<T>` build lazy
+//! expressions instead (see [`FieldExpr`](super::FieldExpr)), which `+=` and
+//! `-=` accept.
 //!
 //! ```rust
 //! use strelitzia::fields::{ScalarField, Vector3Field};
@@ -31,8 +33,9 @@
 //! field1 -= 3.0;                  // Scalar subtraction
 //! ```
 
-use super::Field;
-use crate::common::Real;
+use super::{Field, FieldExpr};
+use crate::common::{Complex, Real};
+use crate::multiarray::{Quaternion, Rotation2, Rotation3, Vector2, Vector3};
 
 /// Generic operations available for all field types with appropriate trait bounds.
 pub trait FieldOps<T> {
@@ -79,28 +82,30 @@ impl<T: Clone> FieldOps<T> for Field<T> {
 
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
-// Field += &Field
-impl<T> AddAssign<&Field<T>> for Field<T>
+// Field += &Field, or += a lazy expression of fields (see `expr`), in one pass
+impl<T, E> AddAssign<E> for Field<T>
 where
-    T: Add<T, Output = T> + Copy,
+    E: FieldExpr,
+    T: Add<E::Item, Output = T> + Copy,
 {
-    fn add_assign(&mut self, other: &Field<T>) {
+    fn add_assign(&mut self, other: E) {
         assert_eq!(self.len(), other.len(), "Fields must have same length");
-        for (a, b) in self.iter_mut().zip(other.iter()) {
-            *a = *a + *b;
+        for (a, b) in self.iter_mut().zip(other.values()) {
+            *a = *a + b;
         }
     }
 }
 
-// Field -= &Field
-impl<T> SubAssign<&Field<T>> for Field<T>
+// Field -= &Field, or -= a lazy expression of fields
+impl<T, E> SubAssign<E> for Field<T>
 where
-    T: Sub<T, Output = T> + Copy,
+    E: FieldExpr,
+    T: Sub<E::Item, Output = T> + Copy,
 {
-    fn sub_assign(&mut self, other: &Field<T>) {
+    fn sub_assign(&mut self, other: E) {
         assert_eq!(self.len(), other.len(), "Fields must have same length");
-        for (a, b) in self.iter_mut().zip(other.iter()) {
-            *a = *a - *b;
+        for (a, b) in self.iter_mut().zip(other.values()) {
+            *a = *a - b;
         }
     }
 }
@@ -154,6 +159,58 @@ where
     }
 }
 
+// Complex scalars, for complex fields (`Field<Complex>`, `Field<Vector3c>`, ...).
+// The `Real` operators above also apply to them.
+
+// Field *= Complex
+impl<T> MulAssign<Complex> for Field<T>
+where
+    Complex: Mul<T, Output = T>,
+    T: Copy,
+{
+    fn mul_assign(&mut self, factor: Complex) {
+        for elem in self.iter_mut() {
+            *elem = factor * *elem;
+        }
+    }
+}
+
+// Field /= Complex
+impl<T> DivAssign<Complex> for Field<T>
+where
+    T: Div<Complex, Output = T> + Copy,
+{
+    fn div_assign(&mut self, divisor: Complex) {
+        for elem in self.iter_mut() {
+            *elem = *elem / divisor;
+        }
+    }
+}
+
+// Field += Complex
+impl<T> AddAssign<Complex> for Field<T>
+where
+    T: Add<Complex, Output = T> + Copy,
+{
+    fn add_assign(&mut self, scalar: Complex) {
+        for elem in self.iter_mut() {
+            *elem = *elem + scalar;
+        }
+    }
+}
+
+// Field -= Complex
+impl<T> SubAssign<Complex> for Field<T>
+where
+    T: Sub<Complex, Output = T> + Copy,
+{
+    fn sub_assign(&mut self, scalar: Complex) {
+        for elem in self.iter_mut() {
+            *elem = *elem - scalar;
+        }
+    }
+}
+
 // ============================================================================
 // Reduction and sum operations
 // ============================================================================
@@ -194,3 +251,43 @@ where
         self.iter().copied().sum()
     }
 }
+
+// ============================================================================
+// Rotation
+// ============================================================================
+
+/// In-place rotation of every element of a vector field.
+///
+/// ```rust
+/// use strelitzia::fields::{RotateOps, Vector3Field};
+/// use strelitzia::multiarray::{Rotation3, Vector3, Z_AXIS3};
+///
+/// let mut field: Vector3Field = [Vector3::new(1.0, 0.0, 0.0)].into_iter().collect();
+/// field.rotate(&Rotation3::from_axis_angle(&Z_AXIS3, std::f64::consts::FRAC_PI_2));
+/// assert!((field[0] - Vector3::new(0.0, 1.0, 0.0)).as_inner().norm() < 1e-12);
+/// ```
+pub trait RotateOps<R> {
+    /// Replace every element `v` by `rotation * v`.
+    fn rotate(&mut self, rotation: &R);
+}
+
+// Example expansion -- impl_rotate_ops!(Rotation3 => Vector3):
+//
+//   impl RotateOps<Rotation3> for Field<Vector3> {
+//       fn rotate(&mut self, rotation: &Rotation3) { /* elem = rotation * elem */ }
+//   }
+macro_rules! impl_rotate_ops {
+    ($($R:ty => $V:ty),+ $(,)?) => {
+        $(
+            impl RotateOps<$R> for Field<$V> {
+                fn rotate(&mut self, rotation: &$R) {
+                    for elem in self.iter_mut() {
+                        *elem = *rotation * *elem;
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_rotate_ops!(Rotation2 => Vector2, Rotation3 => Vector3, Quaternion => Vector3);
@@ -0,0 +1,402 @@
+//! Multi-threaded field operations on scoped `std` threads.
+//!
+//! [`Field::par`] and [`Field::par_mut`] borrow a field in parallel mode. The
+//! borrowed view offers the element-wise operations of [`FieldOps`], the
+//! compound operators, [`SumOps`] and [`ReductionOps`], plus `map`,
+//! `zip_map` and `for_each`. Work is split into contiguous chunks, one per
+//! thread, with [`std::thread::scope`]: there is no thread pool and no global
+//! state, and the calling thread processes the first chunk itself.
+//!
+//! # Determinism
+//!
+//! Element-wise operations give exactly the serial result. Reductions cut the
+//! field into blocks of [`REDUCTION_BLOCK`] elements, with boundaries that do
+//! not depend on the thread count. Each block is reduced left to right, and
+//! the block results are then combined in order, so `sum` is bit-for-bit the
+//! same for any [`ParallelSettings`]. (It may differ in the last bits from
+//! the serial [`SumOps::sum`], which adds left to right.) `min` and `max`
+//! return the same element as their serial counterparts.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::common::Real;
+//! use strelitzia::fields::{ParallelSettings, ScalarField};
+//!
+//! let mut field: ScalarField = (0..100_000).map(|i| i as Real).collect();
+//! let par = ParallelSettings::with_threads(4);
+//!
+//! let mut view = field.par_mut(par);
+//! view *= 2.0;
+//! view.for_each(|x| *x += 1.0);
+//!
+//! let squares = field.par(par).map(|x| x * x);
+//! assert_eq!(squares[3], 49.0);
+//! assert_eq!(
+//!     field.par(par).sum(),
+//!     field.par(ParallelSettings::with_threads(1)).sum()
+//! );
+//! ```
+//!
+//! [`FieldOps`]: super::FieldOps
+//! [`SumOps`]: super::SumOps
+//! [`SumOps::sum`]: super::SumOps::sum
+//! [`ReductionOps`]: super::ReductionOps
+
+use super::Field;
+use crate::common::{Complex, Real};
+use std::mem::MaybeUninit;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Elements per block of a parallel reduction. Fixed so that the result
+/// does not depend on how the blocks are distributed over threads.
+pub const REDUCTION_BLOCK: usize = 4096;
+
+// ============================================================================
+// ParallelSettings
+// ============================================================================
+
+/// How parallel field operations split their work.
+///
+/// ```
+/// use strelitzia::fields::ParallelSettings;
+///
+/// let settings = ParallelSettings {
+///     threads: 8,
+///     min_chunk_len: 1 << 20,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParallelSettings {
+    /// Number of threads, including the calling thread (`0` counts as 1).
+    /// Default is `std::thread::available_parallelism()`.
+    pub threads: usize,
+    /// Smallest number of elements handed to one thread, so that short
+    /// fields do not pay for spawning. Default is 16384.
+    pub min_chunk_len: usize,
+}
+
+impl Default for ParallelSettings {
+    fn default() -> Self {
+        Self {
+            threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            min_chunk_len: 16384,
+        }
+    }
+}
+
+impl ParallelSettings {
+    /// `threads` threads with the default minimum chunk length.
+    pub fn with_threads(threads: usize) -> Self {
+        Self {
+            threads,
+            ..Self::default()
+        }
+    }
+
+    /// Elements per chunk for `len` elements, a multiple of `align`.
+    fn chunk_len(&self, len: usize, align: usize) -> usize {
+        len.div_ceil(self.threads.max(1))
+            .max(self.min_chunk_len)
+            .max(1)
+            .next_multiple_of(align)
+    }
+}
+
+// ============================================================================
+// Chunked execution on scoped threads
+// ============================================================================
+
+/// Runs `f(offset, chunk)` on consecutive chunks of `data`, the first on the
+/// calling thread.
+fn for_each_chunk_mut<T: Send>(
+    data: &mut [T],
+    chunk_len: usize,
+    f: impl Fn(usize, &mut [T]) + Sync,
+) {
+    if data.len() <= chunk_len {
+        f(0, data);
+        return;
+    }
+    let f = &f;
+    std::thread::scope(|s| {
+        let mut chunks = data.chunks_mut(chunk_len).enumerate();
+        let (_, first) = chunks.next().unwrap();
+        let handles: Vec<_> = chunks
+            .map(|(i, chunk)| s.spawn(move || f(i * chunk_len, chunk)))
+            .collect();
+        f(0, first);
+        for handle in handles {
+            join(handle);
+        }
+    });
+}
+
+/// Maps consecutive chunks of `data` to results, returned in chunk order.
+fn map_chunks<T: Sync, A: Send>(
+    data: &[T],
+    chunk_len: usize,
+    f: impl Fn(usize, &[T]) -> A + Sync,
+) -> Vec<A> {
+    if data.len() <= chunk_len {
+        return vec![f(0, data)];
+    }
+    let f = &f;
+    std::thread::scope(|s| {
+        let mut chunks = data.chunks(chunk_len).enumerate();
+        let (_, first) = chunks.next().unwrap();
+        let handles: Vec<_> = chunks
+            .map(|(i, chunk)| s.spawn(move || f(i * chunk_len, chunk)))
+            .collect();
+        let mut results = vec![f(0, first)];
+        results.extend(handles.into_iter().map(join));
+        results
+    })
+}
+
+/// Joins a worker, resuming its panic (with the original payload) on the
+/// calling thread.
+fn join<A>(handle: std::thread::ScopedJoinHandle<'_, A>) -> A {
+    handle
+        .join()
+        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+}
+
+/// Builds a field of `len` elements, `f(offset, slots)` writing every slot
+/// of its chunk.
+fn collect_chunks<U: Send>(
+    len: usize,
+    chunk_len: usize,
+    f: impl Fn(usize, &mut [MaybeUninit<U>]) + Sync,
+) -> Field<U> {
+    let mut data = Vec::with_capacity(len);
+    for_each_chunk_mut(&mut data.spare_capacity_mut()[..len], chunk_len, f);
+    // SAFETY: the chunks cover the first `len` slots and every caller writes
+    // each slot of its chunk; a panic in `f` unwinds before this point.
+    unsafe { data.set_len(len) };
+    Field::from_vec(data)
+}
+
+fn check_lengths(a: usize, b: usize) {
+    assert_eq!(a, b, "Fields must have same length");
+}
+
+// ============================================================================
+// Parallel views
+// ============================================================================
+
+/// A field borrowed for parallel reads. Created by [`Field::par`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParField<'a, T> {
+    data: &'a [T],
+    settings: ParallelSettings,
+}
+
+/// A field borrowed for parallel in-place updates. Created by
+/// [`Field::par_mut`]; takes the compound operators (`+=`, `-=`, `*=`, `/=`)
+/// of `Field<T>`.
+#[derive(Debug)]
+pub struct ParFieldMut<'a, T> {
+    data: &'a mut [T],
+    settings: ParallelSettings,
+}
+
+impl<T> Field<T> {
+    /// Borrows the field for parallel reads (`map`, `zip_map`, reductions).
+    pub fn par(&self, settings: ParallelSettings) -> ParField<'_, T> {
+        ParField {
+            data: self.as_slice(),
+            settings,
+        }
+    }
+
+    /// Borrows the field for parallel in-place updates.
+    pub fn par_mut(&mut self, settings: ParallelSettings) -> ParFieldMut<'_, T> {
+        ParFieldMut {
+            data: self.as_mut_slice(),
+            settings,
+        }
+    }
+}
+
+impl<T: Sync> ParField<'_, T> {
+    /// New field of `f(x)` for every element `x`.
+    pub fn map<U: Send>(&self, f: impl Fn(&T) -> U + Sync) -> Field<U> {
+        let chunk_len = self.settings.chunk_len(self.data.len(), 1);
+        collect_chunks(self.data.len(), chunk_len, |offset, out| {
+            for (slot, x) in out.iter_mut().zip(&self.data[offset..]) {
+                slot.write(f(x));
+            }
+        })
+    }
+
+    /// New field of `f(x, y)` for every pair of matching elements.
+    ///
+    /// Panics if the fields differ in length.
+    pub fn zip_map<U: Sync, V: Send>(
+        &self,
+        other: &Field<U>,
+        f: impl Fn(&T, &U) -> V + Sync,
+    ) -> Field<V> {
+        check_lengths(self.data.len(), other.len());
+        let other = other.as_slice();
+        let chunk_len = self.settings.chunk_len(self.data.len(), 1);
+        collect_chunks(self.data.len(), chunk_len, |offset, out| {
+            for ((slot, x), y) in out
+                .iter_mut()
+                .zip(&self.data[offset..])
+                .zip(&other[offset..])
+            {
+                slot.write(f(x, y));
+            }
+        })
+    }
+
+    /// Calls `f` on every element, in no particular order.
+    pub fn for_each(&self, f: impl Fn(&T) + Sync) {
+        let chunk_len = self.settings.chunk_len(self.data.len(), 1);
+        map_chunks(self.data, chunk_len, |_, chunk| chunk.iter().for_each(&f));
+    }
+
+    /// Reduces every block of [`REDUCTION_BLOCK`] elements with `f`, in
+    /// parallel; the results come back in block order.
+    fn block_partials<A: Send>(&self, f: impl Fn(&[T]) -> A + Sync) -> Vec<A> {
+        let chunk_len = self.settings.chunk_len(self.data.len(), REDUCTION_BLOCK);
+        map_chunks(self.data, chunk_len, |_, chunk| {
+            chunk.chunks(REDUCTION_BLOCK).map(&f).collect::<Vec<_>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Sum of all elements, independent of the thread count.
+    pub fn sum(&self) -> T
+    where
+        T: std::iter::Sum<T> + Copy + Send,
+    {
+        self.block_partials(|block| block.iter().copied().sum())
+            .into_iter()
+            .sum()
+    }
+
+    /// The maximum element (the last one among equals, as
+    /// [`ReductionOps::max`](super::ReductionOps::max)). Returns `None` if
+    /// the field is empty.
+    pub fn max(&self) -> Option<T>
+    where
+        T: PartialOrd + Copy + Send,
+    {
+        let max_of = |it: &mut dyn Iterator<Item = T>| it.max_by(|a, b| a.partial_cmp(b).unwrap());
+        let partials = self.block_partials(|block| max_of(&mut block.iter().copied()));
+        max_of(&mut partials.into_iter().flatten())
+    }
+
+    /// The minimum element (the first one among equals, as
+    /// [`ReductionOps::min`](super::ReductionOps::min)). Returns `None` if
+    /// the field is empty.
+    pub fn min(&self) -> Option<T>
+    where
+        T: PartialOrd + Copy + Send,
+    {
+        let min_of = |it: &mut dyn Iterator<Item = T>| it.min_by(|a, b| a.partial_cmp(b).unwrap());
+        let partials = self.block_partials(|block| min_of(&mut block.iter().copied()));
+        min_of(&mut partials.into_iter().flatten())
+    }
+}
+
+impl<T: Send> ParFieldMut<'_, T> {
+    /// Calls `f` on every element, in no particular order.
+    pub fn for_each(&mut self, f: impl Fn(&mut T) + Sync) {
+        let chunk_len = self.settings.chunk_len(self.data.len(), 1);
+        for_each_chunk_mut(self.data, chunk_len, |_, chunk| {
+            chunk.iter_mut().for_each(&f)
+        });
+    }
+
+    /// Calls `f(x, y)` on every element `x` and the matching element `y` of
+    /// `other`.
+    ///
+    /// Panics if the fields differ in length.
+    pub fn zip_for_each<U: Sync>(&mut self, other: &Field<U>, f: impl Fn(&mut T, &U) + Sync) {
+        check_lengths(self.data.len(), other.len());
+        let other = other.as_slice();
+        let chunk_len = self.settings.chunk_len(self.data.len(), 1);
+        for_each_chunk_mut(self.data, chunk_len, |offset, chunk| {
+            for (x, y) in chunk.iter_mut().zip(&other[offset..]) {
+                f(x, y);
+            }
+        });
+    }
+
+    /// Fill all elements with the given value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone + Sync,
+    {
+        self.for_each(|x| *x = value.clone());
+    }
+}
+
+// ============================================================================
+// Compound assignment operators (mirroring fields::ops)
+// ============================================================================
+
+// ParFieldMut += &Field
+impl<T> AddAssign<&Field<T>> for ParFieldMut<'_, T>
+where
+    T: Add<T, Output = T> + Copy + Send + Sync,
+{
+    fn add_assign(&mut self, other: &Field<T>) {
+        self.zip_for_each(other, |a, b| *a = *a + *b);
+    }
+}
+
+// ParFieldMut -= &Field
+impl<T> SubAssign<&Field<T>> for ParFieldMut<'_, T>
+where
+    T: Sub<T, Output = T> + Copy + Send + Sync,
+{
+    fn sub_assign(&mut self, other: &Field<T>) {
+        self.zip_for_each(other, |a, b| *a = *a - *b);
+    }
+}
+
+// Example expansion -- impl_par_scalar_assign! {
+//     MulAssign, mul_assign, Real, [Real: Mul<T, Output = T>], |a, s| s * a;
+// }:
+//
+//   impl<T: Copy + Send> MulAssign<Real> for ParFieldMut<'_, T>
+//   where
+//       Real: Mul<T, Output = T>,
+//   {
+//       fn mul_assign(&mut self, s: Real) { self.for_each(|elem| *elem = s * *elem) }
+//   }
+macro_rules! impl_par_scalar_assign {
+    ($($Op:ident, $op:ident, $S:ty, [$($bound:tt)*], |$a:ident, $s:ident| $e:expr;)+) => {
+        $(
+            impl<T: Copy + Send> $Op<$S> for ParFieldMut<'_, T>
+            where
+                $($bound)*
+            {
+                fn $op(&mut self, $s: $S) {
+                    self.for_each(|elem| {
+                        let $a = *elem;
+                        *elem = $e;
+                    });
+                }
+            }
+        )+
+    };
+}
+
+impl_par_scalar_assign! {
+    MulAssign, mul_assign, Real, [Real: Mul<T, Output = T>], |a, s| s * a;
+    DivAssign, div_assign, Real, [T: Div<Real, Output = T>], |a, s| a / s;
+    AddAssign, add_assign, Real, [T: Add<Real, Output = T>], |a, s| a + s;
+    SubAssign, sub_assign, Real, [T: Sub<Real, Output = T>], |a, s| a - s;
+    MulAssign, mul_assign, Complex, [Complex: Mul<T, Output = T>], |a, s| s * a;
+    DivAssign, div_assign, Complex, [T: Div<Complex, Output = T>], |a, s| a / s;
+    AddAssign, add_assign, Complex, [T: Add<Complex, Output = T>], |a, s| a + s;
+    SubAssign, sub_assign, Complex, [T: Sub<Complex, Output = T>], |a, s| a - s;
+}
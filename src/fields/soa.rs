@@ -0,0 +1,319 @@
+//! Struct-of-arrays field storage.
+//!
+//! [`SoaField<T>`] holds the same elements as a [`Field<T>`], but stores
+//! component by component: all `x`, then all `y`, then all `z`. Each
+//! component is a contiguous `&[T::Scalar]` slice, as component-wise solvers
+//! and SIMD kernels want. The layout follows [`FieldElement`], so a
+//! `SoaField<Matrix3>` has 9 components in column-major order.
+//!
+//! ```
+//! use strelitzia::fields::{SoaField, Vector3Field};
+//! use strelitzia::multiarray::Vector3;
+//!
+//! let aos: Vector3Field = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]
+//!     .into_iter()
+//!     .collect();
+//! let mut soa = SoaField::from(&aos);
+//! assert_eq!(soa.component(1), &[2.0, 5.0]);
+//!
+//! soa *= 2.0;
+//! for x in soa.component_mut(0) {
+//!     *x += 1.0;
+//! }
+//! assert_eq!(soa.to_field()[1], Vector3::new(9.0, 10.0, 12.0));
+//! ```
+//!
+//! Conversions to and from `Field<T>` copy the data. The compound operators
+//! of `Field<T>` are available with the same bounds, and
+//! [`soa_field_to_vtk_array`](crate::visualiser::soa_field_to_vtk_array)
+//! exports the field to VTK.
+
+use super::{Field, FieldElement};
+use crate::common::{Complex, Real};
+use crate::multiarray::{Matrix3, Vector3};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Field of `T` stored as one contiguous array per component.
+pub struct SoaField<T: FieldElement> {
+    /// Component `c` of element `i` is `data[c * len + i]`.
+    data: Vec<T::Scalar>,
+    len: usize,
+    _element: PhantomData<T>,
+}
+
+impl<T: FieldElement> SoaField<T> {
+    /// Creates a new empty field.
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            len: 0,
+            _element: PhantomData,
+        }
+    }
+
+    /// Copies an array-of-structs field into component arrays.
+    pub fn from_field(field: &Field<T>) -> Self {
+        let mut data = Vec::with_capacity(field.len() * T::COMPONENTS);
+        for c in 0..T::COMPONENTS {
+            data.extend(field.iter().map(|elem| elem.component(c)));
+        }
+        Self {
+            data,
+            len: field.len(),
+            _element: PhantomData,
+        }
+    }
+
+    /// Copies the elements back into an array-of-structs field.
+    pub fn to_field(&self) -> Field<T> {
+        let mut interleaved = Vec::new();
+        self.interleave_into(&mut interleaved);
+        interleaved
+            .chunks_exact(T::COMPONENTS)
+            .map(T::from_scalars)
+            .collect()
+    }
+
+    /// Replaces the contents of `out` with the components of each element
+    /// next to each other: the `SolverInterop` / VTK layout of the matching
+    /// `Field<T>`.
+    pub fn interleave_into(&self, out: &mut Vec<T::Scalar>) {
+        out.clear();
+        out.reserve(self.data.len());
+        for i in 0..self.len {
+            out.extend((0..T::COMPONENTS).map(|c| self.data[c * self.len + i]));
+        }
+    }
+
+    /// Returns the number of elements in the field.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the field contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gathers element `i` from the component arrays.
+    pub fn get(&self, i: usize) -> T {
+        self.check_index(i);
+        let scalars: Vec<T::Scalar> = (0..T::COMPONENTS)
+            .map(|c| self.data[c * self.len + i])
+            .collect();
+        T::from_scalars(&scalars)
+    }
+
+    /// Scatters `value` into element `i` of the component arrays.
+    pub fn set(&mut self, i: usize, value: T) {
+        self.check_index(i);
+        for c in 0..T::COMPONENTS {
+            self.data[c * self.len + i] = value.component(c);
+        }
+    }
+
+    /// Component `c` of every element, e.g. all `y` values of a
+    /// `SoaField<Vector3>` for `c = 1`.
+    pub fn component(&self, c: usize) -> &[T::Scalar] {
+        self.check_component(c);
+        &self.data[c * self.len..(c + 1) * self.len]
+    }
+
+    /// Mutable component `c` of every element.
+    pub fn component_mut(&mut self, c: usize) -> &mut [T::Scalar] {
+        self.check_component(c);
+        &mut self.data[c * self.len..(c + 1) * self.len]
+    }
+
+    /// All `T::COMPONENTS` component slices, in order.
+    pub fn components(&self) -> impl Iterator<Item = &[T::Scalar]> {
+        (0..T::COMPONENTS).map(|c| self.component(c))
+    }
+
+    /// All `T::COMPONENTS` mutable component slices, in order, so that
+    /// kernels can update `x`, `y` and `z` together.
+    pub fn components_mut(&mut self) -> impl Iterator<Item = &mut [T::Scalar]> {
+        let len = self.len;
+        let mut rest = self.data.as_mut_slice();
+        (0..T::COMPONENTS).map(move |_| {
+            let (component, tail) = std::mem::take(&mut rest).split_at_mut(len);
+            rest = tail;
+            component
+        })
+    }
+
+    /// All components back to back, `[x₀, x₁, ..., y₀, y₁, ..., z₀, ...]`.
+    pub fn as_slice(&self) -> &[T::Scalar] {
+        &self.data
+    }
+
+    /// Mutable access to all components back to back.
+    pub fn as_mut_slice(&mut self) -> &mut [T::Scalar] {
+        &mut self.data
+    }
+
+    fn check_index(&self, i: usize) {
+        assert!(
+            i < self.len,
+            "SoaField index {i} out of bounds for length {}",
+            self.len
+        );
+    }
+
+    fn check_component(&self, c: usize) {
+        assert!(
+            c < T::COMPONENTS,
+            "SoaField component {c} out of range for {} components",
+            T::COMPONENTS
+        );
+    }
+
+    fn check_same_len(&self, other: &Self) {
+        assert_eq!(self.len, other.len, "Fields must have same length");
+    }
+}
+
+impl<T: FieldElement> Default for SoaField<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FieldElement> Clone for SoaField<T> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            len: self.len,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T: FieldElement> fmt::Debug for SoaField<T>
+where
+    T::Scalar: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SoaField")
+            .field("len", &self.len)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+impl<T: FieldElement> PartialEq for SoaField<T>
+where
+    T::Scalar: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.data == other.data
+    }
+}
+
+impl<T: FieldElement> From<&Field<T>> for SoaField<T> {
+    fn from(field: &Field<T>) -> Self {
+        Self::from_field(field)
+    }
+}
+
+impl<T: FieldElement> From<&SoaField<T>> for Field<T> {
+    fn from(field: &SoaField<T>) -> Self {
+        field.to_field()
+    }
+}
+
+impl<T: FieldElement> std::iter::FromIterator<T> for SoaField<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_field(&iter.into_iter().collect())
+    }
+}
+
+// ============================================================================
+// Compound assignment operators (mirroring fields::ops)
+// ============================================================================
+
+// Each operator carries the bound of its `Field<T>` counterpart, so it is
+// available for exactly the same element types, and acts on the components
+// (elements of these types add and scale component-wise).
+
+// SoaField += &SoaField
+impl<T> AddAssign<&SoaField<T>> for SoaField<T>
+where
+    T: FieldElement + Add<T, Output = T>,
+    T::Scalar: Add<Output = T::Scalar>,
+{
+    fn add_assign(&mut self, other: &SoaField<T>) {
+        self.check_same_len(other);
+        for (a, b) in self.data.iter_mut().zip(&other.data) {
+            *a = *a + *b;
+        }
+    }
+}
+
+// SoaField -= &SoaField
+impl<T> SubAssign<&SoaField<T>> for SoaField<T>
+where
+    T: FieldElement + Sub<T, Output = T>,
+    T::Scalar: Sub<Output = T::Scalar>,
+{
+    fn sub_assign(&mut self, other: &SoaField<T>) {
+        self.check_same_len(other);
+        for (a, b) in self.data.iter_mut().zip(&other.data) {
+            *a = *a - *b;
+        }
+    }
+}
+
+// Example expansion -- impl_soa_scalar_assign! {
+//     MulAssign, mul_assign, Real, [Real: Mul<T, Output = T> + Mul<T::Scalar, Output = T::Scalar>],
+//     |a, s| s * a;
+// }:
+//
+//   impl<T: FieldElement> MulAssign<Real> for SoaField<T>
+//   where
+//       Real: Mul<T, Output = T> + Mul<T::Scalar, Output = T::Scalar>,
+//   {
+//       fn mul_assign(&mut self, s: Real) { /* every component a = s * a */ }
+//   }
+macro_rules! impl_soa_scalar_assign {
+    ($($Op:ident, $op:ident, $S:ty, [$($bound:tt)*], |$a:ident, $s:ident| $e:expr;)+) => {
+        $(
+            impl<T: FieldElement> $Op<$S> for SoaField<T>
+            where
+                $($bound)*
+            {
+                fn $op(&mut self, $s: $S) {
+                    for elem in self.data.iter_mut() {
+                        let $a = *elem;
+                        *elem = $e;
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_soa_scalar_assign! {
+    MulAssign, mul_assign, Real,
+    [Real: Mul<T, Output = T> + Mul<T::Scalar, Output = T::Scalar>], |a, s| s * a;
+    DivAssign, div_assign, Real,
+    [T: Div<Real, Output = T>, T::Scalar: Div<Real, Output = T::Scalar>], |a, s| a / s;
+    AddAssign, add_assign, Real,
+    [T: Add<Real, Output = T>, T::Scalar: Add<Real, Output = T::Scalar>], |a, s| a + s;
+    SubAssign, sub_assign, Real,
+    [T: Sub<Real, Output = T>, T::Scalar: Sub<Real, Output = T::Scalar>], |a, s| a - s;
+    MulAssign, mul_assign, Complex,
+    [Complex: Mul<T, Output = T> + Mul<T::Scalar, Output = T::Scalar>], |a, s| s * a;
+    DivAssign, div_assign, Complex,
+    [T: Div<Complex, Output = T>, T::Scalar: Div<Complex, Output = T::Scalar>], |a, s| a / s;
+    AddAssign, add_assign, Complex,
+    [T: Add<Complex, Output = T>, T::Scalar: Add<Complex, Output = T::Scalar>], |a, s| a + s;
+    SubAssign, sub_assign, Complex,
+    [T: Sub<Complex, Output = T>, T::Scalar: Sub<Complex, Output = T::Scalar>], |a, s| a - s;
+}
+
+// Type aliases -- {ElementType}SoaField pattern
+pub type Vector3SoaField = SoaField<Vector3>;
+pub type Matrix3SoaField = SoaField<Matrix3>;
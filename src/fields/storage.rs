@@ -3,7 +3,8 @@
 //! Generic Vec-based storage providing a consistent API and future extensibility
 //! (GPU buffers, parallel iteration, metadata).
 
-use crate::common::{Int, Real, UInt};
+use super::FieldMeta;
+use crate::common::{Complex, Int, Real, UInt};
 use crate::multiarray::*;
 use nalgebra as na;
 
@@ -34,6 +35,18 @@ impl FieldElement for Real {
     }
 }
 
+// FieldElement for Complex (scalar)
+impl FieldElement for Complex {
+    type Scalar = Complex;
+    const COMPONENTS: usize = 1;
+    fn component(&self, _i: usize) -> Complex {
+        *self
+    }
+    fn from_scalars(data: &[Complex]) -> Self {
+        data[0]
+    }
+}
+
 // FieldElement for static vectors
 impl<T: na::Scalar + Copy, const N: usize> FieldElement
     for MultiArray<T, Rank1<N>, na::SVector<T, N>>
@@ -62,6 +75,101 @@ impl<T: na::Scalar + Copy, const R: usize, const C: usize> FieldElement
     }
 }
 
+// FieldElement for native-backend static vectors and matrices
+impl<T: Copy + num_traits::Zero, const N: usize> FieldElement for NativeVector<T, N> {
+    type Scalar = T;
+    const COMPONENTS: usize = N;
+    fn component(&self, i: usize) -> T {
+        self.as_slice()[i]
+    }
+    fn from_scalars(data: &[T]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
+impl<T: Copy + num_traits::Zero, const R: usize, const C: usize> FieldElement
+    for NativeMatrix<T, R, C>
+{
+    type Scalar = T;
+    const COMPONENTS: usize = R * C;
+    fn component(&self, i: usize) -> T {
+        self.as_slice()[i]
+    }
+    fn from_scalars(data: &[T]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
+// FieldElement for symmetric 3x3 matrices (6 packed components)
+impl<T: na::Scalar + Copy> FieldElement for MultiArray<T, SymRank2<3>, na::SVector<T, 6>> {
+    type Scalar = T;
+    const COMPONENTS: usize = 6;
+    fn component(&self, i: usize) -> T {
+        self.as_inner()[i]
+    }
+    fn from_scalars(data: &[T]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
+// FieldElement for rotations (column-major matrix components). `from_scalars`
+// trusts the data to be orthonormal; call `renormalize` if it may have drifted.
+impl<const N: usize> FieldElement for MultiArray<Real, RotRank2<N>, na::Rotation<Real, N>> {
+    type Scalar = Real;
+    const COMPONENTS: usize = N * N;
+    fn component(&self, i: usize) -> Real {
+        self.as_inner().matrix().as_slice()[i]
+    }
+    fn from_scalars(data: &[Real]) -> Self {
+        Self::from_inner(na::Rotation::from_matrix_unchecked(
+            na::SMatrix::from_column_slice(data),
+        ))
+    }
+}
+
+// FieldElement for unit quaternions (components `[x, y, z, w]`). Like the
+// rotations, `from_scalars` does not renormalize.
+impl FieldElement for Quaternion {
+    type Scalar = Real;
+    const COMPONENTS: usize = 4;
+    fn component(&self, i: usize) -> Real {
+        self.as_inner().coords[i]
+    }
+    fn from_scalars(data: &[Real]) -> Self {
+        Self::from_inner(na::UnitQuaternion::new_unchecked(
+            na::Quaternion::from_vector(na::Vector4::from_column_slice(data)),
+        ))
+    }
+}
+
+// FieldElement for rank-3 tensors
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize> FieldElement
+    for Tensor3<T, D1, D2, D3>
+{
+    type Scalar = T;
+    const COMPONENTS: usize = D1 * D2 * D3;
+    fn component(&self, i: usize) -> T {
+        self.as_slice()[i]
+    }
+    fn from_scalars(data: &[T]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
+// FieldElement for rank-4 tensors
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    FieldElement for Tensor4<T, D1, D2, D3, D4>
+{
+    type Scalar = T;
+    const COMPONENTS: usize = D1 * D2 * D3 * D4;
+    fn component(&self, i: usize) -> T {
+        self.as_slice()[i]
+    }
+    fn from_scalars(data: &[T]) -> Self {
+        Self::from_slice(data)
+    }
+}
+
 // ============================================================================
 // Field struct
 // ============================================================================
@@ -72,9 +180,15 @@ impl<T: na::Scalar + Copy, const R: usize, const C: usize> FieldElement
 /// - Type safety and semantic clarity
 /// - Consistent API across all field types
 /// - Extensibility without breaking user code
+///
+/// A field may also carry optional [`FieldMeta`] (name, units, location,
+/// time). With the `serde` feature, a field without metadata serializes as a
+/// plain list of elements and one with metadata as `{meta, data}`; both forms
+/// are accepted on input.
 #[derive(Debug, Clone)]
 pub struct Field<T> {
     data: Vec<T>,
+    meta: Option<Box<FieldMeta>>,
 }
 
 impl<T> Default for Field<T> {
@@ -86,14 +200,12 @@ impl<T> Default for Field<T> {
 impl<T> Field<T> {
     /// Creates a new empty field.
     pub fn new() -> Self {
-        Self { data: Vec::new() }
+        Self::from_vec(Vec::new())
     }
 
     /// Creates a new field with pre-allocated capacity.
     pub fn with_capacity(capacity: usize) -> Self {
-        Self {
-            data: Vec::with_capacity(capacity),
-        }
+        Self::from_vec(Vec::with_capacity(capacity))
     }
 
     /// Returns the number of elements in the field.
@@ -156,16 +268,45 @@ impl<T> Field<T> {
 
     /// Creates a field from an existing Vec (zero-copy).
     pub fn from_vec(data: Vec<T>) -> Self {
-        Self { data }
+        Self { data, meta: None }
+    }
+
+    /// Consumes the field, returning the underlying Vec (zero-copy).
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Attaches metadata, replacing any previous metadata.
+    pub fn with_meta(mut self, meta: FieldMeta) -> Self {
+        self.set_meta(Some(meta));
+        self
+    }
+
+    /// Returns the metadata, if any.
+    pub fn meta(&self) -> Option<&FieldMeta> {
+        self.meta.as_deref()
+    }
+
+    /// Returns mutable metadata, if any.
+    pub fn meta_mut(&mut self) -> Option<&mut FieldMeta> {
+        self.meta.as_deref_mut()
+    }
+
+    /// Replaces or removes the metadata.
+    pub fn set_meta(&mut self, meta: Option<FieldMeta>) {
+        self.meta = meta.map(Box::new);
+    }
+
+    /// Returns the name from the metadata, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.meta().map(|meta| meta.name.as_str())
     }
 }
 
 // FromIterator enables .collect::<Field<T>>() in generic code
 impl<T> std::iter::FromIterator<T> for Field<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        Self {
-            data: iter.into_iter().collect(),
-        }
+        Self::from_vec(iter.into_iter().collect())
     }
 }
 
@@ -185,6 +326,79 @@ impl<T> IndexMut<usize> for Field<T> {
     }
 }
 
+// ============================================================================
+// Serde
+// ============================================================================
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Field<T> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::SerializeStruct;
+        match &self.meta {
+            None => self.data.serialize(serializer),
+            Some(meta) => {
+                let mut s = serializer.serialize_struct("Field", 2)?;
+                s.serialize_field("meta", meta)?;
+                s.serialize_field("data", &self.data)?;
+                s.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Field<T> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_any(FieldVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum FieldKey {
+    Meta,
+    Data,
+}
+
+/// Accepts the plain list form and the `{meta, data}` form.
+#[cfg(feature = "serde")]
+struct FieldVisitor<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for FieldVisitor<T> {
+    type Value = Field<T>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a list of field elements or a {meta, data} map")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Field<T>, A::Error> {
+        let mut data = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            data.push(value);
+        }
+        Ok(Field::from_vec(data))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Field<T>, A::Error> {
+        use serde::de::Error;
+        let (mut meta, mut data) = (None, None);
+        while let Some(key) = map.next_key()? {
+            match key {
+                FieldKey::Meta if meta.is_some() => return Err(Error::duplicate_field("meta")),
+                FieldKey::Data if data.is_some() => return Err(Error::duplicate_field("data")),
+                FieldKey::Meta => meta = Some(map.next_value::<Option<FieldMeta>>()?),
+                FieldKey::Data => data = Some(map.next_value::<Vec<T>>()?),
+            }
+        }
+        let data = data.ok_or_else(|| Error::missing_field("data"))?;
+        let mut field = Field::from_vec(data);
+        field.set_meta(meta.flatten());
+        Ok(field)
+    }
+}
+
 // ============================================================================
 // SolverInterop
 // ============================================================================
@@ -198,26 +412,52 @@ pub trait SolverInterop {
     ///
     /// For Vector3: [x₀, y₀, z₀, x₁, y₁, z₁, ...]
     /// For Matrix3: column-major per matrix (nalgebra default)
+    /// For complex elements: interleaved, e.g. Vector3c gives
+    /// [re x₀, im x₀, re y₀, im y₀, re z₀, im z₀, re x₁, ...]
     fn as_flat_slice(&self) -> &[Real];
 
     /// Returns a mutable flat slice view for writing solver results.
     fn as_flat_slice_mut(&mut self) -> &mut [Real];
 }
 
+/// Scalar types that `SolverInterop` can expose as flat `Real` slices.
+///
+/// # Safety
+///
+/// The type must be laid out as exactly `REALS` contiguous `Real` values.
+pub unsafe trait SolverScalar: Copy {
+    /// Number of `Real` values per scalar.
+    const REALS: usize;
+}
+
+// SAFETY: trivially one Real.
+unsafe impl SolverScalar for Real {
+    const REALS: usize = 1;
+}
+
+// SAFETY: `num_complex::Complex` is `#[repr(C)] { re, im }`.
+unsafe impl SolverScalar for Complex {
+    const REALS: usize = 2;
+}
+
 // Generic SolverInterop implementation for any Field<M> where M is a FieldElement
-// with Real scalars.
-impl<M: FieldElement<Scalar = Real>> SolverInterop for Field<M> {
+// with Real (or interleaved Complex) scalars.
+impl<M: FieldElement> SolverInterop for Field<M>
+where
+    M::Scalar: SolverScalar,
+{
     fn as_flat_slice(&self) -> &[Real] {
         if self.is_empty() {
             return &[];
         }
-        // SAFETY: MultiArray is #[repr(transparent)] over nalgebra,
-        // which stores contiguous Real values. FieldElement guarantees
-        // COMPONENTS scalars per element.
+        // SAFETY: MultiArray is #[repr(transparent)] over its backend
+        // (nalgebra, including the single-field `#[repr(C)]` rotation and quaternion
+        // wrappers, or nested arrays), which stores contiguous scalar values. FieldElement
+        // guarantees COMPONENTS scalars per element, and SolverScalar REALS Reals per scalar.
         unsafe {
             std::slice::from_raw_parts(
                 self.as_slice().as_ptr() as *const Real,
-                self.len() * M::COMPONENTS,
+                self.len() * M::COMPONENTS * M::Scalar::REALS,
             )
         }
     }
@@ -229,7 +469,7 @@ impl<M: FieldElement<Scalar = Real>> SolverInterop for Field<M> {
         unsafe {
             std::slice::from_raw_parts_mut(
                 self.as_mut_slice().as_mut_ptr() as *mut Real,
-                self.len() * M::COMPONENTS,
+                self.len() * M::COMPONENTS * M::Scalar::REALS,
             )
         }
     }
@@ -240,6 +480,14 @@ pub type RealField = Field<Real>;
 pub type ScalarField = RealField; // backward-compat synonym
 pub type Vector3Field = Field<Vector3>;
 pub type Matrix3Field = Field<Matrix3>;
+pub type SymMatrix3Field = Field<SymMatrix3>;
+pub type Tensor3333Field = Field<Tensor3333>;
+pub type Rotation3Field = Field<Rotation3>;
+pub type QuaternionField = Field<Quaternion>;
+
+pub type ComplexField = Field<Complex>;
+pub type Vector3cField = Field<Vector3c>;
+pub type Matrix3cField = Field<Matrix3c>;
 
 pub type IntField = Field<Int>;
 pub type UIntField = Field<UInt>;
@@ -3,16 +3,24 @@
 //! Provides core infrastructure for high-performance simulations:
 //!
 //! - [`common`] -- crate-wide types (`Real`, `Int`, `UInt`)
+//! - [`approx`] -- approximate equality (`ApproxEq`, `assert_approx_eq!`)
 //! - [`multiarray`] -- mathematical type system (`Vector3`, `Matrix3`, etc.)
 //! - [`fields`] -- simulation data collections with zero-copy solver interop
 //! - [`geometry`] -- geometry types and operations
 //! - [`meshgen`] -- mesh generation algorithms (CVT)
+//! - [`scalars`] -- alternative element types (dual numbers for automatic differentiation, intervals)
+//! - [`solvers`] -- Krylov linear solvers (CG, BiCGStab, GMRES) on flat slices
+//! - [`units`] -- compile-time SI units for values, vectors and fields
 //! - [`visualiser`] -- VTK export for ParaView visualisation
 
+pub mod approx;
 pub mod common;
 pub mod fields;
 pub mod geometry;
 pub mod meshgen;
 pub mod multiarray;
 pub mod prelude;
+pub mod scalars;
+pub mod solvers;
+pub mod units;
 pub mod visualiser;
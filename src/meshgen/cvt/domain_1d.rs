@@ -1,11 +1,15 @@
 //! 1D domain implementation for CVT.
 //!
 //! Contains [`Domain1D`] (the 1D interval domain), its [`CvtDomain`] implementation,
-//! and 1D-specific helpers (Hessian, Newton, uniform seeds, density conversion).
+//! and 1D-specific helpers (Hessian, Newton, uniform seeds, density conversion),
+//! plus exact derivatives of the energy via dual numbers
+//! ([`cvt_gradient_ad`], [`cvt_hessian_ad`]).
 
 use crate::common::Real;
 use crate::fields::Field;
+use crate::scalars::{Dual, Dual2};
 use gauss_quad::GaussLegendre;
+use num_traits::Float;
 
 use super::{CvtCellData, CvtDomain};
 
@@ -197,6 +201,135 @@ pub fn cvt_hessian<F: Fn(Real) -> Real>(
     hess
 }
 
+// ============================================================================
+// Automatic differentiation of the energy
+// ============================================================================
+
+/// A density that can be evaluated on any `Float` scalar.
+///
+/// Closures only accept `Real`, so densities to be differentiated are written
+/// as a type implementing this trait. Evaluating on a dual number
+/// ([`Dual`] / [`Dual2`]) then yields exact derivatives of the CVT energy,
+/// including the boundary-density terms.
+///
+/// ```
+/// use num_traits::Float;
+/// use strelitzia::common::Real;
+/// use strelitzia::meshgen::cvt::DifferentiableDensity;
+///
+/// struct Bump;
+/// impl DifferentiableDensity for Bump {
+///     fn eval<T: Float>(&self, x: T) -> T {
+///         T::one() + (-x * x).exp()
+///     }
+/// }
+///
+/// let rho = Bump.as_fn();
+/// assert_eq!(rho(0.0), 2.0);
+/// ```
+pub trait DifferentiableDensity {
+    /// Density at `x`.
+    fn eval<T: Float>(&self, x: T) -> T;
+
+    /// The density as a plain `Fn(Real) -> Real`, for the non-differentiated
+    /// CVT functions.
+    fn as_fn(&self) -> impl Fn(Real) -> Real + '_ {
+        move |x| self.eval(x)
+    }
+}
+
+impl Domain1D {
+    /// Energy of cell `i` as a function of its own seed and its neighbours,
+    /// the only seeds that move its boundaries. `seed(k)` returns the scalar
+    /// for local slot `k` (0 = left neighbour, 1 = own seed, 2 = right
+    /// neighbour).
+    fn local_cell_energy<T: Float, D: DifferentiableDensity>(
+        &self,
+        n: usize,
+        i: usize,
+        density: &D,
+        seed: impl Fn(usize) -> T,
+    ) -> T {
+        let half = T::from(0.5).unwrap();
+        let s = seed(1);
+        let left = if i == 0 {
+            T::from(self.min).unwrap()
+        } else {
+            (seed(0) + s) * half
+        };
+        let right = if i == n - 1 {
+            T::from(self.max).unwrap()
+        } else {
+            (s + seed(2)) * half
+        };
+
+        // Gauss-Legendre on [left, right], mapped from [-1, 1].
+        let mid = (left + right) * half;
+        let half_len = (right - left) * half;
+        let sum = self
+            .quad
+            .as_node_weight_pairs()
+            .iter()
+            .fold(T::zero(), |acc, &(xi, w)| {
+                let x = mid + half_len * T::from(xi).unwrap();
+                acc + T::from(w).unwrap() * density.eval(x) * (x - s).powi(2)
+            });
+        sum * half_len
+    }
+}
+
+/// Global seed index of local slot `k` of cell `i`, if it exists.
+fn local_to_global(i: usize, k: usize, n: usize) -> Option<usize> {
+    (i + k).checked_sub(1).filter(|&j| j < n)
+}
+
+/// Exact gradient of the (quadrature) CVT energy with respect to seeds,
+/// by forward-mode differentiation.
+///
+/// Agrees with [`cvt_gradient`](super::cvt_gradient) up to quadrature error,
+/// so it can be used to check it.
+pub fn cvt_gradient_ad<D: DifferentiableDensity>(
+    domain: &Domain1D,
+    seeds: &Field<Real>,
+    density: &D,
+) -> Field<Real> {
+    let n = seeds.len();
+    let mut grad = vec![0.0; n];
+    for i in 0..n {
+        let e =
+            domain.local_cell_energy(n, i, density, |k| Dual::<3>::variable(seeds[i + k - 1], k));
+        for k in 0..3 {
+            if let Some(j) = local_to_global(i, k, n) {
+                grad[j] += e.grad[k];
+            }
+        }
+    }
+    grad.into_iter().collect()
+}
+
+/// Exact Hessian of the (quadrature) CVT energy with respect to seeds,
+/// by forward-mode differentiation. Same layout as [`cvt_hessian`].
+pub fn cvt_hessian_ad<D: DifferentiableDensity>(
+    domain: &Domain1D,
+    seeds: &Field<Real>,
+    density: &D,
+) -> Vec<Vec<Real>> {
+    let n = seeds.len();
+    let mut hess = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        let e =
+            domain.local_cell_energy(n, i, density, |k| Dual2::<3>::variable(seeds[i + k - 1], k));
+        for k in 0..3 {
+            for l in 0..3 {
+                if let (Some(a), Some(b)) = (local_to_global(i, k, n), local_to_global(i, l, n)) {
+                    hess[a][b] += e.hess[k][l];
+                }
+            }
+        }
+    }
+    hess
+}
+
 /// Generate uniformly spaced seeds in the interior of an interval.
 ///
 /// Seeds are placed at positions that would be centroids for uniform density,
@@ -232,10 +365,27 @@ where
 mod tests {
     use super::*;
     use crate::fields::SolverInterop;
-    use crate::meshgen::cvt::{cvt_gradient, lloyd_iter};
+    use crate::meshgen::cvt::{cvt_energy, cvt_gradient, lloyd_iter};
 
     const TOL: Real = 1e-10;
 
+    /// rho(x) = 1 + x / 2: the energy integrand is cubic, so 5-point
+    /// quadrature is exact and the analytic formulas hold to round-off.
+    struct Linear;
+    impl DifferentiableDensity for Linear {
+        fn eval<T: Float>(&self, x: T) -> T {
+            T::one() + x * T::from(0.5).unwrap()
+        }
+    }
+
+    struct Wavy;
+    impl DifferentiableDensity for Wavy {
+        fn eval<T: Float>(&self, x: T) -> T {
+            let two_pi = T::from(2.0 * std::f64::consts::PI).unwrap();
+            T::one() + T::from(0.5).unwrap() * (two_pi * x).sin()
+        }
+    }
+
     #[test]
     fn interval_voronoi_cells() {
         let domain = Domain1D::new(0.0, 1.0);
@@ -501,4 +651,72 @@ mod tests {
             "energy mismatch"
         );
     }
+
+    #[test]
+    fn ad_gradient_checks_cvt_gradient() {
+        let domain = Domain1D::new(0.0, 1.0);
+        let seeds: Field<Real> = [0.1, 0.3, 0.45, 0.7, 0.9].into_iter().collect();
+
+        let analytic = cvt_gradient(&domain, &seeds, &Linear.as_fn());
+        let ad = cvt_gradient_ad(&domain, &seeds, &Linear);
+
+        for i in 0..seeds.len() {
+            assert!(
+                (analytic[i] - ad[i]).abs() < TOL,
+                "gradient mismatch at seed {}: {} vs {}",
+                i,
+                analytic[i],
+                ad[i]
+            );
+        }
+    }
+
+    #[test]
+    fn ad_hessian_matches_analytic_hessian() {
+        let domain = Domain1D::new(0.0, 1.0);
+        let seeds: Field<Real> = [0.1, 0.3, 0.45, 0.7, 0.9].into_iter().collect();
+
+        let analytic = cvt_hessian(&domain, &seeds, &Linear.as_fn());
+        let ad = cvt_hessian_ad(&domain, &seeds, &Linear);
+
+        for i in 0..seeds.len() {
+            for j in 0..seeds.len() {
+                assert!(
+                    (analytic[i][j] - ad[i][j]).abs() < TOL,
+                    "H[{}][{}]: analytic={}, ad={}",
+                    i,
+                    j,
+                    analytic[i][j],
+                    ad[i][j]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ad_gradient_matches_energy_finite_differences() {
+        let domain = Domain1D::new(0.0, 1.0);
+        let seeds: Field<Real> = [0.15, 0.4, 0.6, 0.8].into_iter().collect();
+        let density = Wavy.as_fn();
+
+        let ad = cvt_gradient_ad(&domain, &seeds, &Wavy);
+
+        let eps = 1e-6;
+        for j in 0..seeds.len() {
+            let mut sp = seeds.clone();
+            let mut sm = seeds.clone();
+            sp[j] += eps;
+            sm[j] -= eps;
+            let numerical = (cvt_energy(&domain, &sp, &density)
+                - cvt_energy(&domain, &sm, &density))
+                / (2.0 * eps);
+            assert!(
+                (ad[j] - numerical).abs() < 1e-7,
+                "dE/ds[{}]: ad={}, numerical={}",
+                j,
+                ad[j],
+                numerical
+            );
+        }
+    }
 }
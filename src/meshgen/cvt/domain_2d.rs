@@ -5,7 +5,8 @@
 
 use crate::common::Real;
 use crate::fields::Field;
-use crate::multiarray::Point2;
+use crate::multiarray::{Point2, Vector};
+use crate::scalars::Interval;
 
 use super::{CvtCellData, CvtDomain, CvtPoint};
 
@@ -13,6 +14,7 @@ use spade::handles::VoronoiVertex;
 use spade::{DelaunayTriangulation, HasPosition, Triangulation};
 
 use std::cell::RefCell;
+use std::cmp::Ordering;
 
 // ============================================================================
 // SpadePoint -- adapter for spade's HasPosition trait
@@ -124,36 +126,21 @@ impl Domain2D {
     }
 
     /// Test whether a point lies inside the domain using the winding number algorithm
-    /// with `robust::orient2d` for exact orientation tests.
+    /// with exact orientation tests (interval filter, robust fallback).
     pub fn contains(&self, point: &Point2) -> bool {
-        let px = point[0];
         let py = point[1];
         let mut winding = 0i32;
 
         for &[i, j] in &self.edges {
-            let (ax, ay) = (self.vertices[i][0], self.vertices[i][1]);
-            let (bx, by) = (self.vertices[j][0], self.vertices[j][1]);
+            let (a, b) = (&self.vertices[i], &self.vertices[j]);
+            let (ay, by) = (a[1], b[1]);
 
             if ay <= py {
-                if by > py {
-                    let orient = robust::orient2d(
-                        robust::Coord { x: ax, y: ay },
-                        robust::Coord { x: bx, y: by },
-                        robust::Coord { x: px, y: py },
-                    );
-                    if orient > 0.0 {
-                        winding += 1;
-                    }
-                }
-            } else if by <= py {
-                let orient = robust::orient2d(
-                    robust::Coord { x: ax, y: ay },
-                    robust::Coord { x: bx, y: by },
-                    robust::Coord { x: px, y: py },
-                );
-                if orient < 0.0 {
-                    winding -= 1;
+                if by > py && orient_sign(a, b, point) == Ordering::Greater {
+                    winding += 1;
                 }
+            } else if by <= py && orient_sign(a, b, point) == Ordering::Less {
+                winding -= 1;
             }
         }
 
@@ -396,7 +383,7 @@ where
 
 /// Clip a subject polygon against the domain boundary using Sutherland-Hodgman.
 ///
-/// Uses `robust::orient2d` for exact side tests.
+/// Side tests are exact (see [`orient_sign`]).
 fn clip_polygon_to_domain(
     subject: &[Point2],
     boundary_verts: &[Point2],
@@ -425,19 +412,7 @@ fn clip_against_edge(polygon: &[Point2], edge_a: &Point2, edge_b: &Point2) -> Ve
         return vec![];
     }
 
-    let ca = robust::Coord {
-        x: edge_a[0],
-        y: edge_a[1],
-    };
-    let cb = robust::Coord {
-        x: edge_b[0],
-        y: edge_b[1],
-    };
-
-    let inside = |p: &Point2| -> bool {
-        let cp = robust::Coord { x: p[0], y: p[1] };
-        robust::orient2d(ca, cb, cp) >= 0.0
-    };
+    let inside = |p: &Point2| -> bool { orient_sign(edge_a, edge_b, p) != Ordering::Less };
 
     let mut result = Vec::new();
 
@@ -464,20 +439,60 @@ fn clip_against_edge(polygon: &[Point2], edge_a: &Point2, edge_b: &Point2) -> Ve
     result
 }
 
-/// Compute the intersection of line segments (p1,p2) and (p3,p4).
+/// Compute where segment (p1,p2) crosses the line through (p3,p4).
+///
+/// The segment parameter is evaluated in interval arithmetic. If the sign of
+/// the denominator is uncertain (the lines are parallel or nearly so), it is
+/// recovered from the exact orientations of `p1` and `p2` relative to
+/// (p3,p4) instead; `None` only if both orientations agree. The parameter is
+/// clamped to `[0, 1]`, so the result always lies on the segment.
 fn line_segment_intersect(p1: &Point2, p2: &Point2, p3: &Point2, p4: &Point2) -> Option<Point2> {
-    let d1x = p2[0] - p1[0];
-    let d1y = p2[1] - p1[1];
-    let d2x = p4[0] - p3[0];
-    let d2y = p4[1] - p3[1];
+    let (a, b, c, d) = (interval(p1), interval(p2), interval(p3), interval(p4));
+    let (d1, d2, ac) = (b - a, d - c, c - a);
 
-    let denom = d1x * d2y - d1y * d2x;
-    if denom.abs() < 1e-15 {
-        return None;
+    let denom = d1.x() * d2.y() - d1.y() * d2.x();
+    let t = match denom.sign() {
+        Some(Ordering::Greater | Ordering::Less) => {
+            ((ac.x() * d2.y() - ac.y() * d2.x()) / denom).mid()
+        }
+        _ => {
+            let o1 = robust::orient2d(coord(p3), coord(p4), coord(p1));
+            let o2 = robust::orient2d(coord(p3), coord(p4), coord(p2));
+            if o1 == o2 {
+                return None;
+            }
+            (o1 / (o1 - o2)) as Real
+        }
     }
+    .clamp(0.0, 1.0);
+
+    Some(Point2::new(
+        p1[0] + t * (p2[0] - p1[0]),
+        p1[1] + t * (p2[1] - p1[1]),
+    ))
+}
+
+/// Sign of the orientation of (a, b, p): `Greater` if `p` lies left of a -> b.
+///
+/// Filtered predicate: the determinant is evaluated in interval arithmetic
+/// first, and only near-collinear inputs, where its sign is uncertain, pay
+/// for the adaptive-precision `robust::orient2d`.
+fn orient_sign(a: &Point2, b: &Point2, p: &Point2) -> Ordering {
+    let (ab, ap) = (interval(b) - interval(a), interval(p) - interval(a));
+    let det = ab.x() * ap.y() - ab.y() * ap.x();
+    det.sign().unwrap_or_else(|| {
+        robust::orient2d(coord(a), coord(b), coord(p))
+            .partial_cmp(&0.0)
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+fn interval(p: &Point2) -> Vector<Interval, 2> {
+    Vector::<Interval, 2>::new(p[0].into(), p[1].into())
+}
 
-    let t = ((p3[0] - p1[0]) * d2y - (p3[1] - p1[1]) * d2x) / denom;
-    Some(Point2::new(p1[0] + t * d1x, p1[1] + t * d1y))
+fn coord(p: &Point2) -> robust::Coord<Real> {
+    robust::Coord { x: p[0], y: p[1] }
 }
 
 // ============================================================================
@@ -626,6 +641,40 @@ mod tests {
         assert!(!domain.contains(&Point2::new(2.0, 2.0)));
     }
 
+    #[test]
+    fn clip_keeps_steep_crossings() {
+        // The second edge crosses x = 0 with a direction cross product of
+        // -2e-18: below any fixed parallel tolerance, but its sign is certain.
+        let sliver = [
+            Point2::new(-1e-18, 0.0),
+            Point2::new(1e-18, 1.0),
+            Point2::new(1.0, 1.0),
+        ];
+        let clipped = clip_against_edge(&sliver, &Point2::new(0.0, 1.0), &Point2::new(0.0, 0.0));
+        assert_eq!(clipped.len(), 4);
+        assert!(
+            clipped
+                .iter()
+                .any(|p| p[0].abs() < 1e-30 && (p[1] - 0.5).abs() < TOL)
+        );
+    }
+
+    #[test]
+    fn orient_sign_resolves_near_collinear_points() {
+        let e = Real::EPSILON;
+        let (a, b) = (Point2::new(0.0, 0.0), Point2::new(1.0 + e, 1.0 + 2.0 * e));
+        // Exactly collinear, and exactly left of the line by e² (the naive
+        // determinant rounds both to zero).
+        assert_eq!(
+            orient_sign(&a, &b, &Point2::new(2.0 + 2.0 * e, 2.0 + 4.0 * e)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            orient_sign(&a, &b, &Point2::new(1.0, 1.0 + e)),
+            Ordering::Greater
+        );
+    }
+
     #[test]
     fn bounding_box_rectangle() {
         let domain = Domain2D::rectangle(1.0, 3.0, 2.0, 5.0);
@@ -10,6 +10,8 @@
 //! - [`CvtCellData`] -- integrated quantities returned by `integrate_cells`
 //! - [`Domain1D`] -- 1D domain implementation
 //! - Generic free functions -- `lloyd_step`, `cvt_energy`, `cvt_gradient`, `cvt_residual`, `lloyd_iter`
+//! - [`DifferentiableDensity`] -- densities that accept dual numbers, for exact 1D
+//!   derivatives via [`cvt_gradient_ad`] / [`cvt_hessian_ad`]
 //!
 //! # Example
 //!
@@ -30,10 +32,12 @@ pub mod domain_1d;
 pub mod domain_2d;
 
 pub use domain_1d::{
-    cvt_hessian, density_from_cell_size, uniform_seeds, Domain1D,
+    cvt_gradient_ad, cvt_hessian, cvt_hessian_ad, density_from_cell_size, uniform_seeds,
+    DifferentiableDensity, Domain1D,
 };
 pub use domain_2d::Domain2D;
 
+use crate::approx::{ApproxEq, Mismatch, Tolerance};
 use crate::common::Real;
 use crate::fields::{Field, FieldElement};
 use crate::multiarray::{Point2, Point3};
@@ -198,7 +202,11 @@ pub fn cvt_residual<D: CvtDomain, F: Fn(D::Point) -> Real>(
 ///
 /// Captures the current seed positions, energy, and iteration number.
 /// Returned by Lloyd iteration and other CVT solvers.
+///
+/// With the `serde` feature, diagnostics that were not computed (NaN) are
+/// written as `null`, so checkpoints stay valid JSON.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CvtState<P: CvtPoint> {
     /// Iteration number (0-indexed).
     pub iteration: usize,
@@ -208,15 +216,56 @@ pub struct CvtState<P: CvtPoint> {
     pub energy: Real,
     /// Normalised displacement residual at this iteration.
     /// `f64::NAN` when not computed (argmin-backed solvers, LloydIterator).
+    #[cfg_attr(feature = "serde", serde(with = "nan_as_null"))]
     pub residual: Real,
     /// Maximum Voronoi cell mass across all seeds.
     /// `f64::NAN` when not computed.
+    #[cfg_attr(feature = "serde", serde(with = "nan_as_null"))]
     pub max_mass: Real,
     /// Minimum Voronoi cell mass across all seeds.
     /// `f64::NAN` when not computed.
+    #[cfg_attr(feature = "serde", serde(with = "nan_as_null"))]
     pub min_mass: Real,
 }
 
+/// Serde adapter for "not computed" diagnostics: NaN <-> `null`.
+#[cfg(feature = "serde")]
+mod nan_as_null {
+    use crate::common::Real;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Real, serializer: S) -> Result<S::Ok, S::Error> {
+        (!value.is_nan()).then_some(*value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Real, D::Error> {
+        Ok(Option::<Real>::deserialize(deserializer)?.unwrap_or(Real::NAN))
+    }
+}
+
+/// Iteration numbers must match exactly; seeds and scalar diagnostics are
+/// compared within the tolerance. Diagnostics that are NaN (not computed) in
+/// both states match.
+impl<P: CvtPoint + ApproxEq> ApproxEq for CvtState<P> {
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        if self.iteration != other.iteration {
+            return Some(Mismatch::other(self.iteration, other.iteration).within("iteration"));
+        }
+        if let Some(m) = self.seeds.first_mismatch(&other.seeds, tol) {
+            return Some(m.within("seeds"));
+        }
+        [
+            ("energy", self.energy, other.energy),
+            ("residual", self.residual, other.residual),
+            ("max_mass", self.max_mass, other.max_mass),
+            ("min_mass", self.min_mass, other.min_mass),
+        ]
+        .into_iter()
+        .filter(|(_, a, b)| !(a.is_nan() && b.is_nan()))
+        .find_map(|(name, a, b)| a.first_mismatch(&b, tol).map(|m| m.within(name)))
+    }
+}
+
 /// Internal iterator state for Lloyd iteration.
 struct LloydIterator<D, F>
 where
@@ -58,7 +58,15 @@ use std::time::{Duration, Instant};
 /// `max_iter` is a pure emergency backstop (default: `usize::MAX`).
 /// Under normal operation the residual criterion terminates the solver long
 /// before `max_iter` is reached.
+///
+/// With the `serde` feature, fields missing from a config file take their
+/// defaults, so a config only needs to list the settings it changes.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct CvtSolverSettings {
     /// Emergency backstop: solver aborts if this many iterations are reached.
     /// Default is `usize::MAX` (effectively unlimited — rely on `tol` instead).
@@ -0,0 +1,390 @@
+//! Named fields attached to a mesh.
+//!
+//! A [`FieldSet<P>`] borrows a [`Mesh<P>`] and owns any number of fields of
+//! different element types, keyed by the name in their [`FieldMeta`]. Every
+//! field is checked on insertion: it must have a name that is not yet taken
+//! and a [`MeshLocation`], and its length must match the number of vertices,
+//! cells or faces. The set can then be written as a single VTU file.
+//!
+//! ```no_run
+//! use strelitzia::fields::{FieldMeta, MeshLocation, ScalarField, Vector3Field};
+//! use strelitzia::meshgen::field_set::FieldSet;
+//! use strelitzia::meshgen::mesh::Mesh;
+//! use strelitzia::multiarray::{Point2, Vector3};
+//! use strelitzia::visualiser::Encoding;
+//!
+//! let x: ScalarField = [0.0, 1.0, 2.0].into_iter().collect();
+//! let y: ScalarField = [0.0, 1.0].into_iter().collect();
+//! let mesh = Mesh::<Point2>::tensor_product(&x, &y);
+//!
+//! let mut fields = FieldSet::new(&mesh);
+//! let temperature: ScalarField = (0..6).map(|i| 300.0 + i as f64).collect();
+//! fields.insert(temperature.with_meta(
+//!     FieldMeta::new("temperature").with_units("K").with_location(MeshLocation::Vertex),
+//! ))?;
+//! let flux: Vector3Field = (0..2).map(|_| Vector3::new(1.0, 0.0, 0.0)).collect();
+//! fields.insert(flux.with_meta(FieldMeta::new("flux").with_location(MeshLocation::Cell)))?;
+//!
+//! fields.get_mut::<f64>("temperature").unwrap()[0] = 290.0;
+//! fields.write_vtu("state.vtu", Encoding::Base64)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Fields must have `Real` components (see [`field_to_vtk_array`]). Units and
+//! time are carried along for the caller but not written, and face fields are
+//! skipped by the VTU writer, which has no face data.
+
+use super::mesh::Mesh;
+use crate::common::Real;
+use crate::fields::{Field, FieldElement, FieldMeta, MeshLocation};
+use crate::visualiser::{Encoding, FieldArray, field_to_vtk_array, write_vtu};
+use std::any::Any;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Error returned when a field cannot be added to a [`FieldSet`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldSetError {
+    /// The field has no metadata, or an empty name.
+    Unnamed,
+    /// The field's metadata has no mesh location.
+    NoLocation { name: String },
+    /// A field of this name is already in the set.
+    DuplicateName { name: String },
+    /// The field lives on faces, but the set does not know the face count.
+    UnknownFaceCount { name: String },
+    /// The field's length differs from the number of mesh entities.
+    LengthMismatch {
+        name: String,
+        location: MeshLocation,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for FieldSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldSetError::Unnamed => write!(f, "field has no name"),
+            FieldSetError::NoLocation { name } => {
+                write!(f, "field '{name}' has no mesh location")
+            }
+            FieldSetError::DuplicateName { name } => {
+                write!(f, "a field named '{name}' is already in the set")
+            }
+            FieldSetError::UnknownFaceCount { name } => write!(
+                f,
+                "field '{name}' lives on faces, but the face count is unknown"
+            ),
+            FieldSetError::LengthMismatch {
+                name,
+                location,
+                expected,
+                found,
+            } => {
+                let entities = match location {
+                    MeshLocation::Vertex => "vertices",
+                    MeshLocation::Cell => "cells",
+                    MeshLocation::Face => "faces",
+                };
+                write!(
+                    f,
+                    "field '{name}' has {found} values, but the mesh has {expected} {entities}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldSetError {}
+
+/// Type-erased view of a field stored in a [`FieldSet`].
+trait StoredField: Any {
+    /// Validated on insertion, so always present.
+    fn meta(&self) -> &FieldMeta;
+    fn vtk_array(&self) -> FieldArray<'_>;
+}
+
+impl<T: FieldElement<Scalar = Real> + 'static> StoredField for Field<T> {
+    fn meta(&self) -> &FieldMeta {
+        Field::meta(self).expect("FieldSet fields have metadata")
+    }
+
+    fn vtk_array(&self) -> FieldArray<'_> {
+        field_to_vtk_array(self).expect("FieldSet fields have metadata")
+    }
+}
+
+/// Registry of named fields on a mesh.
+pub struct FieldSet<'m, P> {
+    mesh: &'m Mesh<P>,
+    num_faces: Option<usize>,
+    fields: Vec<Box<dyn StoredField>>,
+}
+
+impl<'m, P> FieldSet<'m, P> {
+    /// Creates an empty set on `mesh`.
+    pub fn new(mesh: &'m Mesh<P>) -> Self {
+        Self {
+            mesh,
+            num_faces: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets the number of faces, which `Mesh` does not track, so that face
+    /// fields can be validated.
+    pub fn with_num_faces(mut self, num_faces: usize) -> Self {
+        self.num_faces = Some(num_faces);
+        self
+    }
+
+    /// Returns the mesh the fields live on.
+    pub fn mesh(&self) -> &'m Mesh<P> {
+        self.mesh
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if the set has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Adds a field after checking its metadata and length.
+    pub fn insert<T>(&mut self, field: Field<T>) -> Result<(), FieldSetError>
+    where
+        T: FieldElement<Scalar = Real> + 'static,
+    {
+        let meta = field.meta().ok_or(FieldSetError::Unnamed)?;
+        let name = meta.name.clone();
+        if name.is_empty() {
+            return Err(FieldSetError::Unnamed);
+        }
+        if self.position(&name).is_some() {
+            return Err(FieldSetError::DuplicateName { name });
+        }
+        let location = meta
+            .location
+            .ok_or_else(|| FieldSetError::NoLocation { name: name.clone() })?;
+        let expected = match location {
+            MeshLocation::Vertex => self.mesh.num_vertices(),
+            MeshLocation::Cell => self.mesh.num_cells(),
+            MeshLocation::Face => self
+                .num_faces
+                .ok_or_else(|| FieldSetError::UnknownFaceCount { name: name.clone() })?,
+        };
+        if field.len() != expected {
+            return Err(FieldSetError::LengthMismatch {
+                name,
+                location,
+                expected,
+                found: field.len(),
+            });
+        }
+        self.fields.push(Box::new(field));
+        Ok(())
+    }
+
+    /// Removes and returns the field `name`, or `None` if there is no such
+    /// field of element type `T`.
+    pub fn remove<T: 'static>(&mut self, name: &str) -> Option<Field<T>> {
+        let index = self.position(name)?;
+        if !self.any(index).is::<Field<T>>() {
+            return None;
+        }
+        let field: Box<dyn Any> = self.fields.remove(index);
+        field.downcast().ok().map(|field| *field)
+    }
+
+    /// Returns the field `name`, or `None` if there is no such field of
+    /// element type `T`.
+    pub fn get<T: 'static>(&self, name: &str) -> Option<&Field<T>> {
+        let index = self.position(name)?;
+        self.any(index).downcast_ref()
+    }
+
+    /// Returns the values of field `name` for updating in place. Only the
+    /// values are exposed, so the length and metadata stay valid.
+    pub fn get_mut<T: 'static>(&mut self, name: &str) -> Option<&mut [T]> {
+        let index = self.position(name)?;
+        let field: &mut dyn Any = self.fields[index].as_mut();
+        field
+            .downcast_mut::<Field<T>>()
+            .map(|field| field.as_mut_slice())
+    }
+
+    /// Returns `true` if the set has a field called `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Returns the metadata of every field, in insertion order.
+    pub fn metas(&self) -> impl Iterator<Item = &FieldMeta> {
+        self.fields.iter().map(|field| field.meta())
+    }
+
+    /// Returns the VTK arrays of the vertex and cell fields, in insertion
+    /// order, ready for [`write_vtu`].
+    pub fn vtk_arrays(&self) -> (Vec<FieldArray<'_>>, Vec<FieldArray<'_>>) {
+        let of = |location| {
+            self.fields
+                .iter()
+                .filter(|field| field.meta().location == Some(location))
+                .map(|field| field.vtk_array())
+                .collect()
+        };
+        (of(MeshLocation::Vertex), of(MeshLocation::Cell))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|field| field.meta().name == name)
+    }
+
+    fn any(&self, index: usize) -> &dyn Any {
+        self.fields[index].as_ref()
+    }
+}
+
+impl<P: FieldElement<Scalar = Real>> FieldSet<'_, P> {
+    /// Writes the mesh with all vertex and cell fields to a VTU file.
+    /// Vertices with fewer than three components are padded with zeros.
+    pub fn write_vtu(&self, path: impl AsRef<Path>, encoding: Encoding) -> io::Result<()> {
+        let points: Vec<[Real; 3]> = self
+            .mesh
+            .vertices
+            .iter()
+            .map(|p| {
+                std::array::from_fn(|c| {
+                    if c < P::COMPONENTS {
+                        p.component(c)
+                    } else {
+                        0.0
+                    }
+                })
+            })
+            .collect();
+        let (point_fields, cell_fields) = self.vtk_arrays();
+        write_vtu::<_, 3>(
+            path,
+            &points,
+            Some(&self.mesh.cells),
+            Some(&self.mesh.cell_types),
+            &point_fields,
+            &cell_fields,
+            encoding,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multiarray::{Point2, Vector3};
+
+    fn mesh() -> Mesh<Point2> {
+        let x: Field<Real> = vec![0.0, 1.0, 2.0].into_iter().collect();
+        let y: Field<Real> = vec![0.0, 1.0].into_iter().collect();
+        Mesh::<Point2>::tensor_product(&x, &y)
+    }
+
+    fn field<T: Copy>(value: T, n: usize, meta: FieldMeta) -> Field<T> {
+        Field::from_vec(vec![value; n]).with_meta(meta)
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mesh = mesh();
+        let mut set = FieldSet::new(&mesh);
+        let vertex = FieldMeta::new("p").with_location(MeshLocation::Vertex);
+        set.insert(field(1.0, 6, vertex)).unwrap();
+        let cell = FieldMeta::new("u").with_location(MeshLocation::Cell);
+        set.insert(field(Vector3::new(1.0, 2.0, 3.0), 2, cell))
+            .unwrap();
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get::<Real>("p").unwrap().len(), 6);
+        // Wrong element type or name.
+        assert!(set.get::<Vector3>("p").is_none());
+        assert!(set.get::<Real>("q").is_none());
+
+        set.get_mut::<Vector3>("u").unwrap()[1] = Vector3::zeros();
+        assert_eq!(set.get::<Vector3>("u").unwrap()[1], Vector3::zeros());
+
+        let (points, cells) = set.vtk_arrays();
+        assert_eq!((points.len(), cells.len()), (1, 1));
+        assert_eq!(cells[0].num_components, 3);
+
+        assert!(set.remove::<Vector3>("p").is_none());
+        let p = set.remove::<Real>("p").unwrap();
+        assert_eq!(p.name(), Some("p"));
+        assert!(!set.contains("p"));
+        assert_eq!(
+            set.metas().map(|m| m.name.as_str()).collect::<Vec<_>>(),
+            ["u"]
+        );
+    }
+
+    #[test]
+    fn test_insert_rejects_invalid_fields() {
+        let mesh = mesh();
+        let mut set = FieldSet::new(&mesh);
+        let vertex = FieldMeta::new("p").with_location(MeshLocation::Vertex);
+
+        assert_eq!(
+            set.insert(Field::from_vec(vec![0.0; 6])),
+            Err(FieldSetError::Unnamed)
+        );
+        assert_eq!(
+            set.insert(field(0.0, 6, FieldMeta::new("p"))),
+            Err(FieldSetError::NoLocation { name: "p".into() })
+        );
+        assert_eq!(
+            set.insert(field(0.0, 5, vertex.clone())),
+            Err(FieldSetError::LengthMismatch {
+                name: "p".into(),
+                location: MeshLocation::Vertex,
+                expected: 6,
+                found: 5,
+            })
+        );
+        set.insert(field(0.0, 6, vertex.clone())).unwrap();
+        assert_eq!(
+            set.insert(field(0.0, 6, vertex)),
+            Err(FieldSetError::DuplicateName { name: "p".into() })
+        );
+
+        let face = FieldMeta::new("flux").with_location(MeshLocation::Face);
+        assert_eq!(
+            set.insert(field(0.0, 7, face.clone())),
+            Err(FieldSetError::UnknownFaceCount {
+                name: "flux".into()
+            })
+        );
+        let mut set = FieldSet::new(&mesh).with_num_faces(7);
+        set.insert(field(0.0, 7, face)).unwrap();
+        // Face fields are not exported.
+        let (points, cells) = set.vtk_arrays();
+        assert!(points.is_empty() && cells.is_empty());
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let err = FieldSetError::LengthMismatch {
+            name: "p".into(),
+            location: MeshLocation::Cell,
+            expected: 2,
+            found: 3,
+        };
+        assert_eq!(
+            err.to_string(),
+            "field 'p' has 3 values, but the mesh has 2 cells"
+        );
+    }
+}
@@ -6,6 +6,7 @@
 //! - `Mesh<Point2>` -- 2D mesh
 //! - `Mesh<Point3>` -- 3D mesh
 
+use crate::approx::{ApproxEq, Mismatch, Tolerance};
 use crate::common::Real;
 use crate::fields::Field;
 use crate::multiarray::{Point2, Point3};
@@ -18,7 +19,15 @@ use spade::{DelaunayTriangulation, HasPosition, Triangulation};
 /// This is the common output of all mesh generators (CVT, tensor product,
 /// Delaunay, etc.) and the common input to downstream consumers (VTK export,
 /// FVM solvers, field interpolation).
+///
+/// With the `serde` feature, deserialization checks that every cell has a
+/// cell type and only references existing vertices.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "MeshParts<P>")
+)]
 pub struct Mesh<P> {
     pub vertices: Field<P>,
     pub cells: Vec<Vec<usize>>,
@@ -35,6 +44,70 @@ impl<P> Mesh<P> {
     }
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MeshParts<P> {
+    vertices: Field<P>,
+    cells: Vec<Vec<usize>>,
+    cell_types: Vec<CellType>,
+}
+
+#[cfg(feature = "serde")]
+impl<P> TryFrom<MeshParts<P>> for Mesh<P> {
+    type Error = String;
+    fn try_from(p: MeshParts<P>) -> Result<Self, String> {
+        if p.cells.len() != p.cell_types.len() {
+            return Err(format!(
+                "Mesh: {} cells but {} cell types",
+                p.cells.len(),
+                p.cell_types.len()
+            ));
+        }
+        let n = p.vertices.len();
+        for (i, cell) in p.cells.iter().enumerate() {
+            if let Some(v) = cell.iter().find(|&&v| v >= n) {
+                return Err(format!(
+                    "Mesh: cell {i} references vertex {v}, but there are {n} vertices"
+                ));
+            }
+        }
+        Ok(Self {
+            vertices: p.vertices,
+            cells: p.cells,
+            cell_types: p.cell_types,
+        })
+    }
+}
+
+/// Vertices are compared within the tolerance; connectivity and cell types
+/// must match exactly.
+impl<P: ApproxEq> ApproxEq for Mesh<P> {
+    fn first_mismatch(&self, other: &Self, tol: Tolerance) -> Option<Mismatch> {
+        if let Some(m) = self.vertices.first_mismatch(&other.vertices, tol) {
+            return Some(m.within("vertices"));
+        }
+        if self.cells.len() != other.cells.len() {
+            return Some(Mismatch::length(self.cells.len(), other.cells.len()).within("cells"));
+        }
+        for (i, (a, b)) in self.cells.iter().zip(&other.cells).enumerate() {
+            if a != b {
+                return Some(Mismatch::other(a, b).within(format_args!("cells[{i}]")));
+            }
+        }
+        if self.cell_types != other.cell_types {
+            let i = (0..self.cell_types.len().min(other.cell_types.len()))
+                .find(|&i| self.cell_types[i] != other.cell_types[i]);
+            return Some(match i {
+                Some(i) => Mismatch::other(self.cell_types[i], other.cell_types[i])
+                    .within(format_args!("cell_types[{i}]")),
+                None => Mismatch::length(self.cell_types.len(), other.cell_types.len())
+                    .within("cell_types"),
+            });
+        }
+        None
+    }
+}
+
 impl Mesh<Real> {
     /// Create a 1D mesh from sorted points.
     ///
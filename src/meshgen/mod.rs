@@ -2,6 +2,7 @@
 //!
 //! This module provides:
 //! - [`mesh`] -- Universal mesh type (`Mesh<P>`) with tensor-product, extrusion, and Delaunay constructors
+//! - [`field_set`] -- Named, validated fields on a mesh (`FieldSet`) with VTU export
 //! - [`cvt`] -- Centroidal Voronoi Tessellation (CVT) algorithms
 //! - [`cvt_solvers`] -- Newton/BFGS/L-BFGS optimization for CVT
 //! - [`cvt_plot`] -- Matplotlib-based visualization for 1D CVT results
@@ -9,6 +10,7 @@
 //! - [`cvt_vtk`] -- VTK/ParaView export for 1D CVT results
 //! - [`cvt_vtk_2d`] -- VTK/ParaView export for 2D CVT results
 
+pub mod field_set;
 pub mod mesh;
 
 pub mod cvt;
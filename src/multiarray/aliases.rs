@@ -2,8 +2,12 @@
 //!
 //! Users interact with these aliases rather than `MultiArray<T, S, B>` directly.
 
+use super::array_storage::*;
+use super::sparse_storage::*;
 use super::types::*;
-use crate::common::{Int, Real, UInt};
+use super::vec_storage::*;
+use super::view_storage::*;
+use crate::common::{Complex, Int, Real, UInt};
 use nalgebra as na;
 
 // ============================================================================
@@ -21,6 +25,65 @@ pub type DynVector<T> = MultiArray<T, DynRank1, na::DVector<T>>;
 /// Dynamic matrix type (heap-allocated, NOT `Copy`).
 pub type DynMatrix<T> = MultiArray<T, DynRank2, na::DMatrix<T>>;
 
+/// Sparse matrix in coordinate (triplet) format -- the assembly builder.
+pub type CooMatrix<T> = MultiArray<T, DynRank2, CooStorage<T>>;
+/// Sparse matrix in compressed sparse row format -- for products and solvers.
+pub type CsrMatrix<T> = MultiArray<T, DynRank2, CsrStorage<T>>;
+
+/// Static rank-3 tensor type (stack-allocated, `Copy`, first index fastest).
+pub type Tensor3<T, const D1: usize, const D2: usize, const D3: usize> =
+    MultiArray<T, Rank3<D1, D2, D3>, TensorStorage3<T, D1, D2, D3>>;
+/// Static rank-4 tensor type (stack-allocated, `Copy`, first index fastest).
+pub type Tensor4<T, const D1: usize, const D2: usize, const D3: usize, const D4: usize> =
+    MultiArray<T, Rank4<D1, D2, D3, D4>, TensorStorage4<T, D1, D2, D3, D4>>;
+
+/// Static vector on the native array backend (stack-allocated, `Copy`).
+/// Unlike `Vector<T, N>`, places no nalgebra bounds on `T`.
+pub type NativeVector<T, const N: usize> = MultiArray<T, Rank1<N>, ArrayStorage<T, N, 1>>;
+/// Static matrix on the native array backend (stack-allocated, `Copy`, column-major).
+pub type NativeMatrix<T, const R: usize, const C: usize> =
+    MultiArray<T, Rank2<R, C>, ArrayStorage<T, R, C>>;
+/// Dynamic vector on the native `Vec` backend (heap-allocated, NOT `Copy`).
+pub type NativeDynVector<T> = MultiArray<T, DynRank1, VecStorage<T>>;
+/// Dynamic matrix on the native `Vec` backend (heap-allocated, NOT `Copy`, column-major).
+pub type NativeDynMatrix<T> = MultiArray<T, DynRank2, VecStorage<T>>;
+
+/// Symmetric 3x3 matrix (stack-allocated, `Copy`), stored as its 6 independent
+/// components in Voigt order `[xx, yy, zz, yz, xz, xy]`.
+pub type SymMatrix3 = MultiArray<Real, SymRank2<3>, na::SVector<Real, 6>>;
+
+/// 2D rotation (stack-allocated, `Copy`), stored as its column-major 2x2 matrix.
+pub type Rotation2 = MultiArray<Real, RotRank2<2>, na::Rotation2<Real>>;
+/// 3D rotation (stack-allocated, `Copy`), stored as its column-major 3x3 matrix.
+pub type Rotation3 = MultiArray<Real, RotRank2<3>, na::Rotation3<Real>>;
+/// Unit quaternion representing a 3D rotation (stack-allocated, `Copy`),
+/// stored as `[x, y, z, w]` (vector part first, as in nalgebra).
+pub type Quaternion = MultiArray<Real, QuatRank1, na::UnitQuaternion<Real>>;
+
+/// Borrowed view of `N` elements of another array (strided, `Copy`), e.g. a
+/// column or row of a `Matrix`.
+pub type VectorView<'a, T, const N: usize> =
+    MultiArray<T, Rank1<N>, StridedStorage<'a, T, na::Const<N>, na::U1>>;
+/// Mutable borrowed view of `N` elements of another array (strided).
+pub type VectorViewMut<'a, T, const N: usize> =
+    MultiArray<T, Rank1<N>, StridedStorageMut<'a, T, na::Const<N>, na::U1>>;
+/// Borrowed `R x C` sub-block of another matrix (strided, `Copy`).
+pub type MatrixView<'a, T, const R: usize, const C: usize> =
+    MultiArray<T, Rank2<R, C>, StridedStorage<'a, T, na::Const<R>, na::Const<C>>>;
+/// Mutable borrowed `R x C` sub-block of another matrix (strided).
+pub type MatrixViewMut<'a, T, const R: usize, const C: usize> =
+    MultiArray<T, Rank2<R, C>, StridedStorageMut<'a, T, na::Const<R>, na::Const<C>>>;
+/// Borrowed view of a run of elements of a dynamic array (strided, `Copy`).
+pub type DynVectorView<'a, T> = MultiArray<T, DynRank1, StridedStorage<'a, T, na::Dyn, na::U1>>;
+/// Mutable borrowed view of a run of elements of a dynamic array (strided).
+pub type DynVectorViewMut<'a, T> =
+    MultiArray<T, DynRank1, StridedStorageMut<'a, T, na::Dyn, na::U1>>;
+/// Borrowed sub-block of a `DynMatrix` (strided, `Copy`).
+pub type DynMatrixView<'a, T> = MultiArray<T, DynRank2, StridedStorage<'a, T, na::Dyn, na::Dyn>>;
+/// Mutable borrowed sub-block of a `DynMatrix` (strided).
+pub type DynMatrixViewMut<'a, T> =
+    MultiArray<T, DynRank2, StridedStorageMut<'a, T, na::Dyn, na::Dyn>>;
+
 // Convenience aliases fixed to Real
 pub type Vector2 = Vector<Real, 2>;
 pub type Vector3 = Vector<Real, 3>;
@@ -29,6 +92,11 @@ pub type Matrix2 = Matrix<Real, 2, 2>;
 pub type Matrix3 = Matrix<Real, 3, 3>;
 pub type Matrix4 = Matrix<Real, 4, 4>;
 
+pub type Tensor222 = Tensor3<Real, 2, 2, 2>;
+pub type Tensor333 = Tensor3<Real, 3, 3, 3>;
+pub type Tensor2222 = Tensor4<Real, 2, 2, 2, 2>;
+pub type Tensor3333 = Tensor4<Real, 3, 3, 3, 3>;
+
 // Signed integer aliases (suffix: i)
 pub type Vector2i = Vector<Int, 2>;
 pub type Vector3i = Vector<Int, 3>;
@@ -45,6 +113,14 @@ pub type Matrix2u = Matrix<UInt, 2, 2>;
 pub type Matrix3u = Matrix<UInt, 3, 3>;
 pub type Matrix4u = Matrix<UInt, 4, 4>;
 
+// Complex aliases (suffix: c) -- Hermitian `VectorOps`, real norms
+pub type Vector2c = Vector<Complex, 2>;
+pub type Vector3c = Vector<Complex, 3>;
+pub type Vector4c = Vector<Complex, 4>;
+pub type Matrix2c = Matrix<Complex, 2, 2>;
+pub type Matrix3c = Matrix<Complex, 3, 3>;
+pub type Matrix4c = Matrix<Complex, 4, 4>;
+
 // Boolean aliases (suffix: b) -- component-wise masks, 1 byte per component
 pub type Vector2b = Vector<bool, 2>;
 pub type Vector3b = Vector<bool, 3>;
@@ -181,6 +257,138 @@ impl<T: na::Scalar + Copy> MultiArray<T, Rank2<3, 3>, na::SMatrix<T, 3, 3>> {
     }
 }
 
+// --- SymMatrix3: constructors, accessors, Voigt / Mandel packing ---
+//
+// Components are kept as tensor values in Voigt order. The packings differ
+// only in how the three shear components are scaled:
+//   Voigt (stress-like)  [xx, yy, zz, yz, xz, xy]
+//   Voigt (strain-like)  [xx, yy, zz, 2yz, 2xz, 2xy]   (engineering shear)
+//   Mandel               [xx, yy, zz, √2yz, √2xz, √2xy] (a : b = a_M . b_M)
+impl<T: na::Scalar + Copy> MultiArray<T, SymRank2<3>, na::SVector<T, 6>> {
+    /// Construct from the six independent components.
+    pub const fn new(xx: T, yy: T, zz: T, yz: T, xz: T, xy: T) -> Self {
+        Self::from_inner(na::SVector::<T, 6>::new(xx, yy, zz, yz, xz, xy))
+    }
+
+    pub fn xx(&self) -> T {
+        self.data[0]
+    }
+    pub fn yy(&self) -> T {
+        self.data[1]
+    }
+    pub fn zz(&self) -> T {
+        self.data[2]
+    }
+    pub fn yz(&self) -> T {
+        self.data[3]
+    }
+    pub fn xz(&self) -> T {
+        self.data[4]
+    }
+    pub fn xy(&self) -> T {
+        self.data[5]
+    }
+
+    /// Construct from the six components in Voigt order (same as `as_slice`).
+    pub fn from_slice(data: &[T]) -> Self {
+        Self::from_inner(na::SVector::from_column_slice(data))
+    }
+}
+
+impl<T: na::RealField + Copy> MultiArray<T, SymRank2<3>, na::SVector<T, 6>> {
+    pub fn zeros() -> Self {
+        Self::from_inner(na::SVector::zeros())
+    }
+
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::new(o, o, o, z, z, z)
+    }
+
+    /// Symmetric part `(A + Aᵀ) / 2` of a full matrix.
+    pub fn from_matrix(m: &Matrix<T, 3, 3>) -> Self {
+        let m = m.as_inner();
+        let half: T = na::convert(0.5);
+        Self::new(
+            m[(0, 0)],
+            m[(1, 1)],
+            m[(2, 2)],
+            (m[(1, 2)] + m[(2, 1)]) * half,
+            (m[(0, 2)] + m[(2, 0)]) * half,
+            (m[(0, 1)] + m[(1, 0)]) * half,
+        )
+    }
+
+    /// Expand to a full 3x3 matrix.
+    pub fn to_matrix(&self) -> Matrix<T, 3, 3> {
+        Matrix::<T, 3, 3>::new(
+            self.xx(),
+            self.xy(),
+            self.xz(),
+            self.xy(),
+            self.yy(),
+            self.yz(),
+            self.xz(),
+            self.yz(),
+            self.zz(),
+        )
+    }
+
+    /// Stress-like Voigt vector `[xx, yy, zz, yz, xz, xy]`.
+    pub fn to_voigt(&self) -> Vector<T, 6> {
+        Vector::from_inner(self.data)
+    }
+
+    /// Inverse of [`to_voigt`](Self::to_voigt).
+    pub fn from_voigt(v: &Vector<T, 6>) -> Self {
+        Self::from_inner(*v.as_inner())
+    }
+
+    /// Strain-like Voigt vector `[xx, yy, zz, 2yz, 2xz, 2xy]` (engineering shear).
+    pub fn to_voigt_strain(&self) -> Vector<T, 6> {
+        self.scaled_shear(na::convert(2.0))
+    }
+
+    /// Inverse of [`to_voigt_strain`](Self::to_voigt_strain).
+    pub fn from_voigt_strain(v: &Vector<T, 6>) -> Self {
+        Self::from_voigt(v).unscaled_shear(na::convert(2.0))
+    }
+
+    /// Mandel vector `[xx, yy, zz, √2 yz, √2 xz, √2 xy]`. The Euclidean dot
+    /// product of two Mandel vectors equals the double contraction `A : B`.
+    pub fn to_mandel(&self) -> Vector<T, 6> {
+        self.scaled_shear(na::convert(std::f64::consts::SQRT_2))
+    }
+
+    /// Inverse of [`to_mandel`](Self::to_mandel).
+    pub fn from_mandel(v: &Vector<T, 6>) -> Self {
+        Self::from_voigt(v).unscaled_shear(na::convert(std::f64::consts::SQRT_2))
+    }
+
+    fn scaled_shear(&self, factor: T) -> Vector<T, 6> {
+        let mut v = self.data;
+        for s in v.iter_mut().skip(3) {
+            *s *= factor;
+        }
+        Vector::from_inner(v)
+    }
+
+    fn unscaled_shear(mut self, factor: T) -> Self {
+        for s in self.data.iter_mut().skip(3) {
+            *s /= factor;
+        }
+        self
+    }
+}
+
+impl<T: na::RealField + Copy> From<MultiArray<T, SymRank2<3>, na::SVector<T, 6>>>
+    for Matrix<T, 3, 3>
+{
+    fn from(s: MultiArray<T, SymRank2<3>, na::SVector<T, 6>>) -> Self {
+        s.to_matrix()
+    }
+}
+
 // --- Vector<T, 1>: to_scalar convenience ---
 impl<T: Copy> MultiArray<T, Rank1<1>, na::SVector<T, 1>> {
     /// Extract the single element as a scalar.
@@ -225,3 +433,369 @@ impl<T: na::Scalar + Copy + num_traits::Zero> DynMatrix<T> {
         Self::from_inner(na::DMatrix::from_column_slice(nrows, ncols, data))
     }
 }
+
+// --- CooMatrix: triplet builder ---
+impl<T: Copy> CooMatrix<T> {
+    /// Empty `nrows x ncols` matrix with no stored entries.
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        Self::from_inner(CooStorage::new(nrows, ncols))
+    }
+
+    /// Empty matrix with room for `capacity` triplets.
+    pub fn with_capacity(nrows: usize, ncols: usize, capacity: usize) -> Self {
+        let mut coo = Self::new(nrows, ncols);
+        coo.data.rows.reserve(capacity);
+        coo.data.cols.reserve(capacity);
+        coo.data.values.reserve(capacity);
+        coo
+    }
+
+    /// Append the triplet `(row, col, value)`. Duplicates are allowed and are
+    /// summed on conversion to CSR. Panics if the entry is out of bounds.
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        self.data.push(row, col, value);
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.data.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols
+    }
+
+    /// Iterate over the stored triplets in insertion order.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        let d = &self.data;
+        (0..d.values.len()).map(move |e| (d.rows[e], d.cols[e], d.values[e]))
+    }
+}
+
+impl<T: Copy + std::ops::AddAssign> CooMatrix<T> {
+    /// Compress into CSR, summing duplicate entries.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_inner(CsrStorage::from_coo(&self.data))
+    }
+}
+
+impl<T: Copy + std::ops::AddAssign> From<CooMatrix<T>> for CsrMatrix<T> {
+    fn from(coo: CooMatrix<T>) -> Self {
+        coo.to_csr()
+    }
+}
+
+// --- CsrMatrix ---
+impl<T: Copy> CsrMatrix<T> {
+    pub fn nrows(&self) -> usize {
+        self.data.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols
+    }
+
+    /// Row pointer array of length `nrows + 1`.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.data.row_offsets
+    }
+
+    /// Column index of every stored entry, sorted within each row.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.data.col_indices
+    }
+
+    /// Stored values, aligned with `col_indices`.
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// Mutable stored values. The sparsity pattern is fixed, which allows
+    /// re-assembling a system with the same connectivity in place.
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.data.values
+    }
+
+    /// Column indices and values of row `i`.
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.data.row_offsets[i]..self.data.row_offsets[i + 1];
+        (
+            &self.data.col_indices[range.clone()],
+            &self.data.values[range],
+        )
+    }
+}
+
+impl<T: Copy + num_traits::Zero> CsrMatrix<T> {
+    /// Entry `(i, j)`, or zero if it is not stored.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let (cols, vals) = self.row(i);
+        cols.binary_search(&j).map_or(T::zero(), |e| vals[e])
+    }
+}
+
+impl<T: Copy + num_traits::Zero + std::ops::Mul<Output = T>> CsrMatrix<T> {
+    /// `y = A x` on flat slices, e.g. `Field::as_flat_slice` /
+    /// `as_flat_slice_mut`. Panics on length mismatch.
+    pub fn mul_vec_into(&self, x: &[T], y: &mut [T]) {
+        self.data.mul_vec_into(x, y);
+    }
+}
+
+impl<T: na::Scalar + Copy + num_traits::Zero + std::ops::Mul<Output = T>> CsrMatrix<T> {
+    /// Matrix-vector product `A x`. Panics if `x.dim() != ncols`.
+    pub fn mul_vec(&self, x: &DynVector<T>) -> DynVector<T> {
+        let mut y = DynVector::zeros(self.nrows());
+        self.data
+            .mul_vec_into(x.as_inner().as_slice(), y.data.as_mut_slice());
+        y
+    }
+
+    /// Expand to a dense matrix.
+    pub fn to_dense(&self) -> DynMatrix<T> {
+        let mut m = na::DMatrix::zeros(self.nrows(), self.ncols());
+        for i in 0..self.nrows() {
+            let (cols, vals) = self.row(i);
+            for (&j, &v) in cols.iter().zip(vals) {
+                m[(i, j)] = v;
+            }
+        }
+        DynMatrix::from_inner(m)
+    }
+}
+
+// --- NativeVector ---
+impl<T: Copy, const N: usize> NativeVector<T, N> {
+    pub const fn from_array(data: [T; N]) -> Self {
+        Self::from_inner(ArrayStorage([data]))
+    }
+
+    pub fn dim(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Copy + num_traits::Zero, const N: usize> NativeVector<T, N> {
+    pub fn zeros() -> Self {
+        Self::from_inner(ArrayStorage::splat(T::zero()))
+    }
+
+    /// Build a vector by evaluating `f(i)` for every index.
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self::from_array(std::array::from_fn(f))
+    }
+
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut v = Self::zeros();
+        v.data.as_mut_slice().copy_from_slice(data);
+        v
+    }
+}
+
+// --- NativeMatrix ---
+impl<T: Copy + num_traits::Zero, const R: usize, const C: usize> NativeMatrix<T, R, C> {
+    pub fn zeros() -> Self {
+        Self::from_inner(ArrayStorage::splat(T::zero()))
+    }
+
+    /// Build a matrix by evaluating `f(i, j)` for every index.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        Self::from_inner(ArrayStorage(std::array::from_fn(|j| {
+            std::array::from_fn(|i| f(i, j))
+        })))
+    }
+
+    /// Construct from elements in column-major order (the same order as
+    /// `as_slice`).
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut m = Self::zeros();
+        m.data.as_mut_slice().copy_from_slice(data);
+        m
+    }
+
+    pub fn nrows(&self) -> usize {
+        R
+    }
+
+    pub fn ncols(&self) -> usize {
+        C
+    }
+}
+
+impl<T: Copy + num_traits::Zero + num_traits::One, const N: usize> NativeMatrix<T, N, N> {
+    pub fn identity() -> Self {
+        Self::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+// --- NativeDynVector ---
+impl<T: Copy> NativeDynVector<T> {
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let n = data.len();
+        Self::from_inner(VecStorage::from_vec(data, n, 1))
+    }
+
+    pub fn from_slice(data: &[T]) -> Self {
+        Self::from_vec(data.to_vec())
+    }
+
+    pub fn dim(&self) -> usize {
+        self.data.nrows
+    }
+}
+
+impl<T: Copy + num_traits::Zero> NativeDynVector<T> {
+    pub fn zeros(n: usize) -> Self {
+        Self::from_inner(VecStorage::splat(T::zero(), n, 1))
+    }
+}
+
+// --- NativeDynMatrix ---
+impl<T: Copy> NativeDynMatrix<T> {
+    /// Construct from elements in column-major order. Panics if
+    /// `data.len() != nrows * ncols`.
+    pub fn from_slice(data: &[T], nrows: usize, ncols: usize) -> Self {
+        Self::from_inner(VecStorage::from_vec(data.to_vec(), nrows, ncols))
+    }
+
+    /// Build a matrix by evaluating `f(i, j)` for every index.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(nrows: usize, ncols: usize, mut f: F) -> Self {
+        let data = (0..ncols)
+            .flat_map(|j| (0..nrows).map(move |i| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self::from_inner(VecStorage::from_vec(data, nrows, ncols))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.data.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols
+    }
+}
+
+impl<T: Copy + num_traits::Zero> NativeDynMatrix<T> {
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        Self::from_inner(VecStorage::splat(T::zero(), nrows, ncols))
+    }
+}
+
+impl<T: Copy + num_traits::Zero + num_traits::One> NativeDynMatrix<T> {
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+// --- Tensor3 ---
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize>
+    Tensor3<T, D1, D2, D3>
+{
+    pub fn zeros() -> Self {
+        Self::from_inner(TensorStorage3::splat(T::zero()))
+    }
+
+    /// Build a tensor by evaluating `f(i, j, k)` for every index.
+    pub fn from_fn<F: FnMut(usize, usize, usize) -> T>(mut f: F) -> Self {
+        let mut t = Self::zeros();
+        for k in 0..D3 {
+            for j in 0..D2 {
+                for i in 0..D1 {
+                    t.data.0[k][j][i] = f(i, j, k);
+                }
+            }
+        }
+        t
+    }
+
+    /// Construct from elements in first-index-fastest order
+    /// (the same order as `as_slice`).
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut t = Self::zeros();
+        t.data.as_mut_slice().copy_from_slice(data);
+        t
+    }
+
+    pub fn dims(&self) -> [usize; 3] {
+        [D1, D2, D3]
+    }
+}
+
+// --- Tensor333: permutation symbol ---
+impl<T: na::RealField + Copy> Tensor3<T, 3, 3, 3> {
+    /// Levi-Civita symbol `ε_ijk`: `+1` for even permutations of `(0, 1, 2)`,
+    /// `-1` for odd ones, `0` if an index repeats.
+    pub fn levi_civita() -> Self {
+        Self::from_fn(|i, j, k| {
+            let sign = (j as i32 - i as i32) * (k as i32 - i as i32) * (k as i32 - j as i32);
+            na::convert(sign.signum() as f64)
+        })
+    }
+}
+
+// --- Tensor4 ---
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    Tensor4<T, D1, D2, D3, D4>
+{
+    pub fn zeros() -> Self {
+        Self::from_inner(TensorStorage4::splat(T::zero()))
+    }
+
+    /// Build a tensor by evaluating `f(i, j, k, l)` for every index.
+    pub fn from_fn<F: FnMut(usize, usize, usize, usize) -> T>(mut f: F) -> Self {
+        let mut t = Self::zeros();
+        for l in 0..D4 {
+            for k in 0..D3 {
+                for j in 0..D2 {
+                    for i in 0..D1 {
+                        t.data.0[l][k][j][i] = f(i, j, k, l);
+                    }
+                }
+            }
+        }
+        t
+    }
+
+    /// Construct from elements in first-index-fastest order
+    /// (the same order as `as_slice`).
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut t = Self::zeros();
+        t.data.as_mut_slice().copy_from_slice(data);
+        t
+    }
+
+    pub fn dims(&self) -> [usize; 4] {
+        [D1, D2, D3, D4]
+    }
+}
+
+// --- Square Tensor4: identities and isotropic stiffness ---
+impl<T: na::RealField + Copy, const D: usize> Tensor4<T, D, D, D, D> {
+    /// Fourth-order identity `I_ijkl = δ_ik δ_jl`, so that `I : A = A`.
+    pub fn identity() -> Self {
+        let d = kronecker::<T>;
+        Self::from_fn(|i, j, k, l| d(i, k) * d(j, l))
+    }
+
+    /// Symmetric fourth-order identity `I_ijkl = (δ_ik δ_jl + δ_il δ_jk) / 2`,
+    /// so that `I : A = sym(A)`.
+    pub fn symmetric_identity() -> Self {
+        let d = kronecker::<T>;
+        let half: T = na::convert(0.5);
+        Self::from_fn(|i, j, k, l| half * (d(i, k) * d(j, l) + d(i, l) * d(j, k)))
+    }
+
+    /// Isotropic elasticity tensor from the Lamé parameters:
+    /// `C_ijkl = λ δ_ij δ_kl + μ (δ_ik δ_jl + δ_il δ_jk)`.
+    pub fn isotropic(lambda: T, mu: T) -> Self {
+        let d = kronecker::<T>;
+        Self::from_fn(|i, j, k, l| {
+            lambda * d(i, j) * d(k, l) + mu * (d(i, k) * d(j, l) + d(i, l) * d(j, k))
+        })
+    }
+}
+
+/// Kronecker delta.
+fn kronecker<T: num_traits::Zero + num_traits::One>(i: usize, j: usize) -> T {
+    if i == j { T::one() } else { T::zero() }
+}
@@ -0,0 +1,256 @@
+//! Fixed-size nested-array backends.
+//!
+//! nalgebra only provides vectors and matrices, so rank-3 and rank-4 tensors
+//! are stored in plain nested Rust arrays. [`ArrayStorage`] does the same for
+//! vectors and matrices, for element types that do not satisfy nalgebra's
+//! `Scalar` bounds (e.g. dual numbers or intervals).
+//!
+//! The nesting is reversed so that the first index varies fastest, matching
+//! nalgebra's column-major layout: element `(i, j, k)` of a `D1 x D2 x D3`
+//! tensor sits at flat index `i + D1 * (j + D2 * k)`. Nested arrays are
+//! contiguous, so these backends implement `DenseRawStorage` and are `Copy`
+//! like the static nalgebra types.
+//!
+//! Each backend implements the same `std::ops` traits as nalgebra's static
+//! matrices, so the blanket operator impls in `operators.rs` apply unchanged.
+
+use super::types::*;
+use nalgebra as na;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Backend for `NativeVector<T, N>` (`C = 1`) and `NativeMatrix<T, R, C>`:
+/// a column-major `[[T; R]; C]` array.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrayStorage<T, const R: usize, const C: usize>(pub [[T; R]; C]);
+
+/// Backend for `Tensor3<T, D1, D2, D3>`: a `[[[T; D1]; D2]; D3]` array.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TensorStorage3<T, const D1: usize, const D2: usize, const D3: usize>(
+    pub [[[T; D1]; D2]; D3],
+);
+
+/// Backend for `Tensor4<T, D1, D2, D3, D4>`: a `[[[[T; D1]; D2]; D3]; D4]` array.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TensorStorage4<T, const D1: usize, const D2: usize, const D3: usize, const D4: usize>(
+    pub [[[[T; D1]; D2]; D3]; D4],
+);
+
+impl<T: Copy, const R: usize, const C: usize> ArrayStorage<T, R, C> {
+    /// Storage with every element set to `value`.
+    pub const fn splat(value: T) -> Self {
+        Self([[value; R]; C])
+    }
+}
+
+impl<T: Copy, const D1: usize, const D2: usize, const D3: usize> TensorStorage3<T, D1, D2, D3> {
+    /// Storage with every element set to `value`.
+    pub const fn splat(value: T) -> Self {
+        Self([[[value; D1]; D2]; D3])
+    }
+}
+
+impl<T: Copy, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    TensorStorage4<T, D1, D2, D3, D4>
+{
+    /// Storage with every element set to `value`.
+    pub const fn splat(value: T) -> Self {
+        Self([[[[value; D1]; D2]; D3]; D4])
+    }
+}
+
+// Maps every element of a nested array, one `array::map` per dimension
+// (innermost first, so elements are visited in storage order).
+macro_rules! map_nested {
+    ($a:expr, $f:ident, $D:ident) => {
+        $a.map(&mut $f)
+    };
+    ($a:expr, $f:ident, $D:ident, $($rest:ident),+) => {
+        $a.map(|inner| map_nested!(inner, $f, $($rest),+))
+    };
+}
+
+// Builds a nested array from `next()`, called once per element in storage
+// order (the inverse of `map_nested!`).
+#[cfg(feature = "serde")]
+macro_rules! nested_from_fn {
+    ($next:ident, $D:ident) => {
+        std::array::from_fn(|_| $next())
+    };
+    ($next:ident, $D:ident, $($rest:ident),+) => {
+        std::array::from_fn(|_| nested_from_fn!($next, $($rest),+))
+    };
+}
+
+// Storage traits and element-wise std::ops for one nested-array backend.
+//
+// Example expansion --
+// impl_nested_array_storage!(TensorStorage3, [D1, D2, D3], [as_flattened], [as_flattened_mut]):
+//
+//   impl<T, const D1: usize, ...> RawStorage<T> for TensorStorage3<T, D1, D2, D3> { ... }
+//   impl<T, const D1: usize, ...> DenseRawStorage<T> for TensorStorage3<T, D1, D2, D3> { ... }
+//   impl<T: Copy, const D1: usize, ...> MapStorage<T> for TensorStorage3<T, D1, D2, D3> { ... }
+//   impl<T: Copy + Add<Output = T>, ...> Add for TensorStorage3<T, D1, D2, D3> { ... }
+//   ... (Sub, Neg, Mul<T>, Div<T>, compound assigns, Zero)
+macro_rules! impl_nested_array_storage {
+    ($Name:ident, [$($D:ident),+], [$($flat:ident),*], [$($flat_mut:ident),*]) => {
+        impl<T, $(const $D: usize),+> RawStorage<T> for $Name<T, $($D),+> {
+            fn len(&self) -> usize {
+                1 $(* $D)+
+            }
+        }
+
+        impl<T, $(const $D: usize),+> DenseRawStorage<T> for $Name<T, $($D),+> {
+            fn as_slice(&self) -> &[T] {
+                self.0.as_flattened()$(.$flat())*
+            }
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                self.0.as_flattened_mut()$(.$flat_mut())*
+            }
+        }
+
+        impl<T: Copy, $(const $D: usize),+> MapStorage<T> for $Name<T, $($D),+> {
+            type Mapped<U: na::Scalar + Copy> = $Name<U, $($D),+>;
+            fn map_storage<U: na::Scalar + Copy>(
+                &self,
+                mut f: impl FnMut(T) -> U,
+            ) -> $Name<U, $($D),+> {
+                $Name(map_nested!(self.0, f, $($D),+))
+            }
+        }
+
+        // Serialized as a flat sequence in storage order; the length is
+        // checked against the static dimensions on deserialization.
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize, $(const $D: usize),+> serde::Serialize for $Name<T, $($D),+> {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                serializer: Ser,
+            ) -> Result<Ser::Ok, Ser::Error> {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: serde::Deserialize<'de>, $(const $D: usize),+> serde::Deserialize<'de>
+            for $Name<T, $($D),+>
+        {
+            fn deserialize<De: serde::Deserializer<'de>>(
+                deserializer: De,
+            ) -> Result<Self, De::Error> {
+                let data = Vec::<T>::deserialize(deserializer)?;
+                let expected = 1 $(* $D)+;
+                if data.len() != expected {
+                    return Err(serde::de::Error::invalid_length(
+                        data.len(),
+                        &format!("{expected} components").as_str(),
+                    ));
+                }
+                let mut data = data.into_iter();
+                let mut next = || data.next().unwrap();
+                Ok($Name(nested_from_fn!(next, $($D),+)))
+            }
+        }
+
+        impl<T: Copy + num_traits::Zero, $(const $D: usize),+> num_traits::Zero
+            for $Name<T, $($D),+>
+        {
+            fn zero() -> Self {
+                Self::splat(T::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.as_slice().iter().all(T::is_zero)
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>, $(const $D: usize),+> Add for $Name<T, $($D),+> {
+            type Output = Self;
+            fn add(mut self, rhs: Self) -> Self {
+                self += rhs;
+                self
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>, $(const $D: usize),+> Sub for $Name<T, $($D),+> {
+            type Output = Self;
+            fn sub(mut self, rhs: Self) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl<T: Copy + Neg<Output = T>, $(const $D: usize),+> Neg for $Name<T, $($D),+> {
+            type Output = Self;
+            fn neg(mut self) -> Self {
+                for a in self.as_mut_slice() {
+                    *a = -*a;
+                }
+                self
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>, $(const $D: usize),+> Mul<T> for $Name<T, $($D),+> {
+            type Output = Self;
+            fn mul(mut self, scalar: T) -> Self {
+                self *= scalar;
+                self
+            }
+        }
+
+        impl<T: Copy + Div<Output = T>, $(const $D: usize),+> Div<T> for $Name<T, $($D),+> {
+            type Output = Self;
+            fn div(mut self, scalar: T) -> Self {
+                self /= scalar;
+                self
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>, $(const $D: usize),+> AddAssign for $Name<T, $($D),+> {
+            fn add_assign(&mut self, rhs: Self) {
+                for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                    *a = *a + *b;
+                }
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>, $(const $D: usize),+> SubAssign for $Name<T, $($D),+> {
+            fn sub_assign(&mut self, rhs: Self) {
+                for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                    *a = *a - *b;
+                }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>, $(const $D: usize),+> MulAssign<T> for $Name<T, $($D),+> {
+            fn mul_assign(&mut self, scalar: T) {
+                for a in self.as_mut_slice() {
+                    *a = *a * scalar;
+                }
+            }
+        }
+
+        impl<T: Copy + Div<Output = T>, $(const $D: usize),+> DivAssign<T> for $Name<T, $($D),+> {
+            fn div_assign(&mut self, scalar: T) {
+                for a in self.as_mut_slice() {
+                    *a = *a / scalar;
+                }
+            }
+        }
+    };
+}
+
+impl_nested_array_storage!(ArrayStorage, [R, C], [], []);
+impl_nested_array_storage!(
+    TensorStorage3,
+    [D1, D2, D3],
+    [as_flattened],
+    [as_flattened_mut]
+);
+impl_nested_array_storage!(
+    TensorStorage4,
+    [D1, D2, D3, D4],
+    [as_flattened, as_flattened],
+    [as_flattened_mut, as_flattened_mut]
+);
@@ -0,0 +1,229 @@
+//! Tensor contractions between vectors, matrices and higher-rank tensors.
+//!
+//! - [`SingleContraction`] sums over the last index of `self` and the first
+//!   index of the right-hand side (`A_ijk v_k`, `v_i A_ijk`, ...).
+//! - [`DoubleContraction`] sums over the last two indices of `self` and the
+//!   first two of the right-hand side (`A : B`, `C_ijkl ε_kl`, ...).
+//!
+//! Shapes are checked at compile time through the const dimension parameters.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::{Matrix3, Tensor3333};
+//! use strelitzia::multiarray::linalg::DoubleContraction;
+//!
+//! let c = Tensor3333::isotropic(1.0, 0.5);
+//! let strain = Matrix3::new(1e-3, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+//!
+//! let stress: Matrix3 = c.ddot(&strain);
+//! assert!((stress[0] - 2e-3).abs() < 1e-15); // (λ + 2μ) ε_xx
+//! ```
+
+use crate::multiarray::{Matrix, MultiArray, SymRank2, Tensor3, Tensor4, Vector};
+use nalgebra as na;
+
+/// Single contraction over one shared index.
+pub trait SingleContraction<Rhs> {
+    type Output;
+    fn contract(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Double contraction over two shared indices (`A : B`).
+pub trait DoubleContraction<Rhs> {
+    type Output;
+    fn ddot(&self, rhs: &Rhs) -> Self::Output;
+}
+
+// ============================================================================
+// SingleContraction
+// ============================================================================
+
+// Tensor3<D1, D2, D3> . Vector<D3> -> Matrix<D1, D2>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize>
+    SingleContraction<Vector<T, D3>> for Tensor3<T, D1, D2, D3>
+{
+    type Output = Matrix<T, D1, D2>;
+    fn contract(&self, rhs: &Vector<T, D3>) -> Matrix<T, D1, D2> {
+        Matrix::from_inner(na::SMatrix::from_fn(|i, j| {
+            (0..D3).fold(T::zero(), |acc, k| acc + self[(i, j, k)] * rhs[k])
+        }))
+    }
+}
+
+// Vector<D1> . Tensor3<D1, D2, D3> -> Matrix<D2, D3>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize>
+    SingleContraction<Tensor3<T, D1, D2, D3>> for Vector<T, D1>
+{
+    type Output = Matrix<T, D2, D3>;
+    fn contract(&self, rhs: &Tensor3<T, D1, D2, D3>) -> Matrix<T, D2, D3> {
+        Matrix::from_inner(na::SMatrix::from_fn(|j, k| {
+            (0..D1).fold(T::zero(), |acc, i| acc + self[i] * rhs[(i, j, k)])
+        }))
+    }
+}
+
+// Tensor3<D1, D2, D3> . Matrix<D3, E> -> Tensor3<D1, D2, E>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize, const E: usize>
+    SingleContraction<Matrix<T, D3, E>> for Tensor3<T, D1, D2, D3>
+{
+    type Output = Tensor3<T, D1, D2, E>;
+    fn contract(&self, rhs: &Matrix<T, D3, E>) -> Tensor3<T, D1, D2, E> {
+        let b = rhs.as_inner();
+        Tensor3::from_fn(|i, j, e| {
+            (0..D3).fold(T::zero(), |acc, k| acc + self[(i, j, k)] * b[(k, e)])
+        })
+    }
+}
+
+// Matrix<R, D1> . Tensor3<D1, D2, D3> -> Tensor3<R, D2, D3>
+impl<T: na::RealField + Copy, const R: usize, const D1: usize, const D2: usize, const D3: usize>
+    SingleContraction<Tensor3<T, D1, D2, D3>> for Matrix<T, R, D1>
+{
+    type Output = Tensor3<T, R, D2, D3>;
+    fn contract(&self, rhs: &Tensor3<T, D1, D2, D3>) -> Tensor3<T, R, D2, D3> {
+        let a = self.as_inner();
+        Tensor3::from_fn(|r, j, k| {
+            (0..D1).fold(T::zero(), |acc, i| acc + a[(r, i)] * rhs[(i, j, k)])
+        })
+    }
+}
+
+// Tensor4<D1, D2, D3, D4> . Vector<D4> -> Tensor3<D1, D2, D3>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    SingleContraction<Vector<T, D4>> for Tensor4<T, D1, D2, D3, D4>
+{
+    type Output = Tensor3<T, D1, D2, D3>;
+    fn contract(&self, rhs: &Vector<T, D4>) -> Tensor3<T, D1, D2, D3> {
+        Tensor3::from_fn(|i, j, k| {
+            (0..D4).fold(T::zero(), |acc, l| acc + self[(i, j, k, l)] * rhs[l])
+        })
+    }
+}
+
+// ============================================================================
+// DoubleContraction
+// ============================================================================
+
+// Matrix<R, C> : Matrix<R, C> -> T   (A_ij B_ij)
+impl<T: na::RealField + Copy, const R: usize, const C: usize> DoubleContraction<Matrix<T, R, C>>
+    for Matrix<T, R, C>
+{
+    type Output = T;
+    fn ddot(&self, rhs: &Matrix<T, R, C>) -> T {
+        self.as_inner().dot(rhs.as_inner())
+    }
+}
+
+// SymMatrix3 : SymMatrix3 -> T   (shear terms counted twice)
+impl<T: na::RealField + Copy> DoubleContraction<MultiArray<T, SymRank2<3>, na::SVector<T, 6>>>
+    for MultiArray<T, SymRank2<3>, na::SVector<T, 6>>
+{
+    type Output = T;
+    fn ddot(&self, rhs: &Self) -> T {
+        let (a, b) = (self.as_inner(), rhs.as_inner());
+        let two: T = na::convert(2.0);
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + two * (a[3] * b[3] + a[4] * b[4] + a[5] * b[5])
+    }
+}
+
+// Tensor3<D1, D2, D3> : Matrix<D2, D3> -> Vector<D1>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize>
+    DoubleContraction<Matrix<T, D2, D3>> for Tensor3<T, D1, D2, D3>
+{
+    type Output = Vector<T, D1>;
+    fn ddot(&self, rhs: &Matrix<T, D2, D3>) -> Vector<T, D1> {
+        let b = rhs.as_inner();
+        Vector::from_inner(na::SVector::from_fn(|i, _| {
+            let mut acc = T::zero();
+            for k in 0..D3 {
+                for j in 0..D2 {
+                    acc += self[(i, j, k)] * b[(j, k)];
+                }
+            }
+            acc
+        }))
+    }
+}
+
+// Matrix<D1, D2> : Tensor3<D1, D2, D3> -> Vector<D3>
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize>
+    DoubleContraction<Tensor3<T, D1, D2, D3>> for Matrix<T, D1, D2>
+{
+    type Output = Vector<T, D3>;
+    fn ddot(&self, rhs: &Tensor3<T, D1, D2, D3>) -> Vector<T, D3> {
+        let a = self.as_inner();
+        Vector::from_inner(na::SVector::from_fn(|k, _| {
+            let mut acc = T::zero();
+            for j in 0..D2 {
+                for i in 0..D1 {
+                    acc += a[(i, j)] * rhs[(i, j, k)];
+                }
+            }
+            acc
+        }))
+    }
+}
+
+// Tensor4<D1, D2, D3, D4> : Matrix<D3, D4> -> Matrix<D1, D2>   (C_ijkl ε_kl)
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    DoubleContraction<Matrix<T, D3, D4>> for Tensor4<T, D1, D2, D3, D4>
+{
+    type Output = Matrix<T, D1, D2>;
+    fn ddot(&self, rhs: &Matrix<T, D3, D4>) -> Matrix<T, D1, D2> {
+        let b = rhs.as_inner();
+        Matrix::from_inner(na::SMatrix::from_fn(|i, j| {
+            let mut acc = T::zero();
+            for l in 0..D4 {
+                for k in 0..D3 {
+                    acc += self[(i, j, k, l)] * b[(k, l)];
+                }
+            }
+            acc
+        }))
+    }
+}
+
+// Matrix<D1, D2> : Tensor4<D1, D2, D3, D4> -> Matrix<D3, D4>   (ε_ij C_ijkl)
+impl<T: na::RealField + Copy, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    DoubleContraction<Tensor4<T, D1, D2, D3, D4>> for Matrix<T, D1, D2>
+{
+    type Output = Matrix<T, D3, D4>;
+    fn ddot(&self, rhs: &Tensor4<T, D1, D2, D3, D4>) -> Matrix<T, D3, D4> {
+        let a = self.as_inner();
+        Matrix::from_inner(na::SMatrix::from_fn(|k, l| {
+            let mut acc = T::zero();
+            for j in 0..D2 {
+                for i in 0..D1 {
+                    acc += a[(i, j)] * rhs[(i, j, k, l)];
+                }
+            }
+            acc
+        }))
+    }
+}
+
+// Tensor4<D1, D2, M, N> : Tensor4<M, N, D3, D4> -> Tensor4<D1, D2, D3, D4>
+impl<
+    T: na::RealField + Copy,
+    const D1: usize,
+    const D2: usize,
+    const M: usize,
+    const N: usize,
+    const D3: usize,
+    const D4: usize,
+> DoubleContraction<Tensor4<T, M, N, D3, D4>> for Tensor4<T, D1, D2, M, N>
+{
+    type Output = Tensor4<T, D1, D2, D3, D4>;
+    fn ddot(&self, rhs: &Tensor4<T, M, N, D3, D4>) -> Tensor4<T, D1, D2, D3, D4> {
+        Tensor4::from_fn(|i, j, k, l| {
+            let mut acc = T::zero();
+            for n in 0..N {
+                for m in 0..M {
+                    acc += self[(i, j, m, n)] * rhs[(m, n, k, l)];
+                }
+            }
+            acc
+        })
+    }
+}
@@ -0,0 +1,240 @@
+//! Matrix decompositions for small static matrices.
+//!
+//! Provides symmetric eigendecomposition, singular value decomposition and
+//! polar decomposition for `Matrix<T, 2, 2>` and `Matrix<T, 3, 3>`. Results
+//! are returned as plain structs of `Vector` / `Matrix` aliases, never as
+//! nalgebra types.
+//!
+//! The 2x2 cases use closed-form expressions (a single Jacobi rotation for
+//! the eigendecomposition, two rotations for the SVD). The 3x3 cases delegate
+//! to nalgebra's iterative solvers.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::Matrix3;
+//! use strelitzia::multiarray::linalg::SymmetricEigen;
+//!
+//! let stress = Matrix3::new(2.0, 0.0, 0.0, 0.0, 3.0, 4.0, 0.0, 4.0, 9.0);
+//! let eig = stress.symmetric_eigen();
+//!
+//! // Principal stresses, ascending
+//! assert!((eig.eigenvalues[0] - 1.0).abs() < 1e-10);
+//! assert!((eig.eigenvalues[1] - 2.0).abs() < 1e-10);
+//! assert!((eig.eigenvalues[2] - 11.0).abs() < 1e-10);
+//! ```
+
+use crate::multiarray::{Matrix, Vector};
+use nalgebra as na;
+
+// ============================================================================
+// Result types
+// ============================================================================
+
+/// Eigendecomposition of a symmetric matrix: `A = Q diag(λ) Qᵀ`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EigenDecomposition<T, const N: usize> {
+    /// Eigenvalues in ascending order.
+    pub eigenvalues: Vector<T, N>,
+    /// Orthonormal eigenvectors stored as columns, in the same order as
+    /// `eigenvalues`.
+    pub eigenvectors: Matrix<T, N, N>,
+}
+
+/// Singular value decomposition: `A = U diag(σ) Vᵀ`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Svd<T, const N: usize> {
+    /// Left singular vectors (columns).
+    pub u: Matrix<T, N, N>,
+    /// Non-negative singular values in descending order.
+    pub singular_values: Vector<T, N>,
+    /// Transposed right singular vectors (rows).
+    pub v_t: Matrix<T, N, N>,
+}
+
+/// Right polar decomposition: `F = R U`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Polar<T, const N: usize> {
+    /// Orthogonal factor `R`. A proper rotation when `det(F) > 0`.
+    pub rotation: Matrix<T, N, N>,
+    /// Symmetric positive semi-definite right stretch tensor `U`.
+    pub stretch: Matrix<T, N, N>,
+}
+
+impl<T: na::RealField + Copy, const N: usize> Polar<T, N> {
+    /// Left stretch tensor `V = R U Rᵀ`, so that `F = V R`.
+    pub fn left_stretch(&self) -> Matrix<T, N, N> {
+        let r = self.rotation.as_inner();
+        Matrix::from_inner(r * self.stretch.as_inner() * r.transpose())
+    }
+}
+
+// ============================================================================
+// Extension traits
+// ============================================================================
+
+/// Eigendecomposition of a symmetric matrix.
+///
+/// Only the lower triangle is read; the upper triangle is assumed to mirror it.
+pub trait SymmetricEigen {
+    type Output;
+    fn symmetric_eigen(&self) -> Self::Output;
+}
+
+/// Singular value decomposition.
+pub trait SingularValueDecomposition {
+    type Output;
+    fn svd(&self) -> Self::Output;
+}
+
+/// Right polar decomposition `F = R U`, computed from the SVD as
+/// `R = U_svd Vᵀ` and `U = V diag(σ) Vᵀ`.
+pub trait PolarDecomposition {
+    type Output;
+    fn polar(&self) -> Self::Output;
+}
+
+// ============================================================================
+// 2x2 -- closed form
+// ============================================================================
+
+/// Rotation matrix `[[c, -s], [s, c]]` for the angle `theta`.
+fn rotation2<T: na::RealField + Copy>(theta: T) -> na::SMatrix<T, 2, 2> {
+    let (s, c) = theta.sin_cos();
+    na::SMatrix::<T, 2, 2>::new(c, -s, s, c)
+}
+
+impl<T: na::RealField + Copy> SymmetricEigen for Matrix<T, 2, 2> {
+    type Output = EigenDecomposition<T, 2>;
+    fn symmetric_eigen(&self) -> EigenDecomposition<T, 2> {
+        let m = self.as_inner();
+        let (a, b, d) = (m[(0, 0)], m[(1, 0)], m[(1, 1)]);
+        let half: T = na::convert(0.5);
+
+        // One Jacobi rotation diagonalises a symmetric 2x2 matrix exactly.
+        let mean = (a + d) * half;
+        let diff = (a - d) * half;
+        let radius = (diff * diff + b * b).sqrt();
+        let theta = (b + b).atan2(a - d) * half;
+        let (s, c) = theta.sin_cos();
+
+        // (c, s) belongs to the larger eigenvalue; (s, -c) completes a
+        // right-handed basis.
+        EigenDecomposition {
+            eigenvalues: Vector::<T, 2>::new(mean - radius, mean + radius),
+            eigenvectors: Matrix::from_inner(na::SMatrix::<T, 2, 2>::new(s, c, -c, s)),
+        }
+    }
+}
+
+impl<T: na::RealField + Copy> SingularValueDecomposition for Matrix<T, 2, 2> {
+    type Output = Svd<T, 2>;
+    fn svd(&self) -> Svd<T, 2> {
+        let m = self.as_inner();
+        let half: T = na::convert(0.5);
+
+        // A = Rot(phi) diag(sx, sy) Rot(theta)
+        let e = (m[(0, 0)] + m[(1, 1)]) * half;
+        let f = (m[(0, 0)] - m[(1, 1)]) * half;
+        let g = (m[(1, 0)] + m[(0, 1)]) * half;
+        let h = (m[(1, 0)] - m[(0, 1)]) * half;
+        let q = (e * e + h * h).sqrt();
+        let r = (f * f + g * g).sqrt();
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+
+        let sx = q + r;
+        let mut sy = q - r;
+        let mut u = rotation2((a2 + a1) * half);
+        let v_t = rotation2((a2 - a1) * half);
+
+        // A negative determinant shows up as sy < 0: fold the sign into U.
+        if sy < T::zero() {
+            sy = -sy;
+            u.set_column(1, &(-u.column(1)));
+        }
+
+        Svd {
+            u: Matrix::from_inner(u),
+            singular_values: Vector::<T, 2>::new(sx, sy),
+            v_t: Matrix::from_inner(v_t),
+        }
+    }
+}
+
+// ============================================================================
+// 3x3 -- nalgebra iterative solvers
+// ============================================================================
+
+impl<T: na::RealField + Copy> SymmetricEigen for Matrix<T, 3, 3> {
+    type Output = EigenDecomposition<T, 3>;
+    fn symmetric_eigen(&self) -> EigenDecomposition<T, 3> {
+        let eig = na::SymmetricEigen::new(self.into_inner());
+
+        // nalgebra returns eigenpairs unordered; sort ascending.
+        let mut order = [0usize, 1, 2];
+        order.sort_by(|&i, &j| {
+            eig.eigenvalues[i]
+                .partial_cmp(&eig.eigenvalues[j])
+                .expect("SymmetricEigen: eigenvalue is NaN")
+        });
+
+        let mut values = na::SVector::<T, 3>::zeros();
+        let mut vectors = na::SMatrix::<T, 3, 3>::zeros();
+        for (k, &i) in order.iter().enumerate() {
+            values[k] = eig.eigenvalues[i];
+            vectors.set_column(k, &eig.eigenvectors.column(i));
+        }
+
+        EigenDecomposition {
+            eigenvalues: Vector::from_inner(values),
+            eigenvectors: Matrix::from_inner(vectors),
+        }
+    }
+}
+
+impl<T: na::RealField + Copy> SingularValueDecomposition for Matrix<T, 3, 3> {
+    type Output = Svd<T, 3>;
+    fn svd(&self) -> Svd<T, 3> {
+        // `SVD::new` sorts singular values in descending order.
+        let svd = na::SVD::new(self.into_inner(), true, true);
+        Svd {
+            u: Matrix::from_inner(svd.u.expect("SVD: U was requested")),
+            singular_values: Vector::from_inner(svd.singular_values),
+            v_t: Matrix::from_inner(svd.v_t.expect("SVD: Vᵀ was requested")),
+        }
+    }
+}
+
+// ============================================================================
+// Polar decomposition -- shared via SVD
+// ============================================================================
+
+// Example expansion -- impl_polar!(3):
+//
+//   impl<T: na::RealField + Copy> PolarDecomposition for Matrix<T, 3, 3> {
+//       type Output = Polar<T, 3>;
+//       fn polar(&self) -> Polar<T, 3> { /* from self.svd() */ }
+//   }
+macro_rules! impl_polar {
+    ($dim:literal) => {
+        impl<T: na::RealField + Copy> PolarDecomposition for Matrix<T, $dim, $dim> {
+            type Output = Polar<T, $dim>;
+            fn polar(&self) -> Polar<T, $dim> {
+                let svd = self.svd();
+                let u = svd.u.as_inner();
+                let v_t = svd.v_t.as_inner();
+                let v = v_t.transpose();
+                let sigma =
+                    na::SMatrix::<T, $dim, $dim>::from_diagonal(svd.singular_values.as_inner());
+                Polar {
+                    rotation: Matrix::from_inner(u * v_t),
+                    stretch: Matrix::from_inner(v * sigma * v_t),
+                }
+            }
+        }
+    };
+}
+
+impl_polar!(2);
+impl_polar!(3);
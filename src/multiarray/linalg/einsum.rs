@@ -0,0 +1,601 @@
+//! Einstein-summation contractions in index notation.
+//!
+//! [`einsum!`](crate::einsum) evaluates a product of tensors written as a
+//! NumPy-style specification: one lowercase or uppercase letter per axis,
+//! operands separated by commas, and the free indices after `->`. Indices
+//! missing from the output are summed over.
+//!
+//! ```text
+//! "ij,jk->ik"     A_ij B_jk          matrix product
+//! "ijk,j,k->i"    ε_ijk a_j b_k      cross product
+//! "ijkl,kl->ij"   C_ijkl ε_kl        stiffness times strain
+//! "ii->"          A_ii               trace
+//! ```
+//!
+//! The specification is parsed at compile time. For static shapes (`Vector`,
+//! `Matrix`, `SymMatrix3`, `Tensor3`, `Tensor4`) the ranks and every shared
+//! dimension are checked at compile time too, so a mismatch is a build error.
+//! Dynamic operands (`DynVector`, `DynMatrix`) are checked at runtime and
+//! panic on a mismatch. The result type is taken from the binding and can be
+//! any static alias, a dynamic alias, or a scalar for a full contraction.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::einsum;
+//! use strelitzia::multiarray::{Matrix3, Tensor333, Vector3};
+//! use strelitzia::multiarray::linalg::CrossProduct;
+//!
+//! let a = Vector3::new(1.0, 2.0, 3.0);
+//! let b = Vector3::new(-1.0, 0.5, 2.0);
+//! let eps = Tensor333::levi_civita();
+//!
+//! let c: Vector3 = einsum!("ijk,j,k->i", eps, a, b);
+//! assert_eq!(c, a.cross(&b));
+//!
+//! let m = Matrix3::new(1.0, 2.0, 0.0, 0.0, 1.0, 0.0, 3.0, 0.0, 1.0);
+//! let trace: f64 = einsum!("ii->", m);
+//! assert_eq!(trace, 3.0);
+//! ```
+//!
+//! Mismatched static shapes do not compile:
+//!
+//! ```compile_fail
+//! use strelitzia::einsum;
+//! use strelitzia::multiarray::{Matrix2, Vector3};
+//!
+//! let m = Matrix2::identity();
+//! let v = Vector3::new(1.0, 2.0, 3.0);
+//! let w: Vector3 = einsum!("ij,j->i", m, v);
+//! ```
+
+use crate::multiarray::{DynMatrix, DynVector, Matrix, SymMatrix3, Tensor3, Tensor4, Vector};
+use nalgebra as na;
+use std::ops::Mul;
+
+/// Highest supported rank of an operand or result.
+pub const MAX_RANK: usize = 4;
+/// Highest supported number of operands.
+pub const MAX_OPERANDS: usize = 4;
+/// Dimension placeholder for an axis whose length is only known at runtime.
+pub const DYN: usize = usize::MAX;
+
+const MAX_LABELS: usize = MAX_RANK * MAX_OPERANDS;
+
+// ============================================================================
+// Operand and result traits
+// ============================================================================
+
+/// A tensor that can appear on the right-hand side of [`einsum!`](crate::einsum).
+pub trait EinsumOperand {
+    type Scalar: Copy;
+    /// Tensor rank.
+    const RANK: usize;
+    /// Axis lengths, [`DYN`] where only known at runtime; unused axes are 1.
+    const DIMS: [usize; MAX_RANK];
+    /// Axis lengths of this value.
+    fn dims(&self) -> [usize; MAX_RANK];
+    /// Element at a multi-index (unused trailing entries are 0).
+    fn get(&self, index: [usize; MAX_RANK]) -> Self::Scalar;
+}
+
+/// A tensor or scalar that can receive the result of [`einsum!`](crate::einsum).
+pub trait EinsumOutput<T>: Sized {
+    /// Tensor rank (0 for a scalar).
+    const RANK: usize;
+    /// Axis lengths, [`DYN`] where only known at runtime; unused axes are 1.
+    const DIMS: [usize; MAX_RANK];
+    /// Builds the result with axis lengths `dims` from `f(multi-index)`.
+    fn from_fn(dims: [usize; MAX_RANK], f: impl FnMut([usize; MAX_RANK]) -> T) -> Self;
+}
+
+impl<A: EinsumOperand> EinsumOperand for &A {
+    type Scalar = A::Scalar;
+    const RANK: usize = A::RANK;
+    const DIMS: [usize; MAX_RANK] = A::DIMS;
+    fn dims(&self) -> [usize; MAX_RANK] {
+        (*self).dims()
+    }
+    fn get(&self, index: [usize; MAX_RANK]) -> A::Scalar {
+        (*self).get(index)
+    }
+}
+
+impl<T: na::Scalar + Copy, const N: usize> EinsumOperand for Vector<T, N> {
+    type Scalar = T;
+    const RANK: usize = 1;
+    const DIMS: [usize; MAX_RANK] = [N, 1, 1, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        <Self as EinsumOperand>::DIMS
+    }
+    fn get(&self, [i, ..]: [usize; MAX_RANK]) -> T {
+        self.as_inner()[i]
+    }
+}
+
+impl<T: na::Scalar + Copy, const R: usize, const C: usize> EinsumOperand for Matrix<T, R, C> {
+    type Scalar = T;
+    const RANK: usize = 2;
+    const DIMS: [usize; MAX_RANK] = [R, C, 1, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        <Self as EinsumOperand>::DIMS
+    }
+    fn get(&self, [i, j, ..]: [usize; MAX_RANK]) -> T {
+        self.as_inner()[(i, j)]
+    }
+}
+
+impl EinsumOperand for SymMatrix3 {
+    type Scalar = crate::common::Real;
+    const RANK: usize = 2;
+    const DIMS: [usize; MAX_RANK] = [3, 3, 1, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        <Self as EinsumOperand>::DIMS
+    }
+    fn get(&self, [i, j, ..]: [usize; MAX_RANK]) -> Self::Scalar {
+        self[(i, j)]
+    }
+}
+
+impl<T: Copy, const D1: usize, const D2: usize, const D3: usize> EinsumOperand
+    for Tensor3<T, D1, D2, D3>
+{
+    type Scalar = T;
+    const RANK: usize = 3;
+    const DIMS: [usize; MAX_RANK] = [D1, D2, D3, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        <Self as EinsumOperand>::DIMS
+    }
+    fn get(&self, [i, j, k, _]: [usize; MAX_RANK]) -> T {
+        self[(i, j, k)]
+    }
+}
+
+impl<T: Copy, const D1: usize, const D2: usize, const D3: usize, const D4: usize> EinsumOperand
+    for Tensor4<T, D1, D2, D3, D4>
+{
+    type Scalar = T;
+    const RANK: usize = 4;
+    const DIMS: [usize; MAX_RANK] = [D1, D2, D3, D4];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        <Self as EinsumOperand>::DIMS
+    }
+    fn get(&self, [i, j, k, l]: [usize; MAX_RANK]) -> T {
+        self[(i, j, k, l)]
+    }
+}
+
+impl<T: na::Scalar + Copy> EinsumOperand for DynVector<T> {
+    type Scalar = T;
+    const RANK: usize = 1;
+    const DIMS: [usize; MAX_RANK] = [DYN, 1, 1, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        [self.as_inner().nrows(), 1, 1, 1]
+    }
+    fn get(&self, [i, ..]: [usize; MAX_RANK]) -> T {
+        self.as_inner()[i]
+    }
+}
+
+impl<T: na::Scalar + Copy> EinsumOperand for DynMatrix<T> {
+    type Scalar = T;
+    const RANK: usize = 2;
+    const DIMS: [usize; MAX_RANK] = [DYN, DYN, 1, 1];
+    fn dims(&self) -> [usize; MAX_RANK] {
+        let (r, c) = self.as_inner().shape();
+        [r, c, 1, 1]
+    }
+    fn get(&self, [i, j, ..]: [usize; MAX_RANK]) -> T {
+        self.as_inner()[(i, j)]
+    }
+}
+
+macro_rules! impl_scalar_output {
+    ($($t:ty),*) => {$(
+        impl EinsumOutput<$t> for $t {
+            const RANK: usize = 0;
+            const DIMS: [usize; MAX_RANK] = [1; MAX_RANK];
+            fn from_fn(_: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> $t) -> $t {
+                f([0; MAX_RANK])
+            }
+        }
+    )*};
+}
+
+impl_scalar_output!(f32, f64);
+
+impl<T: na::Scalar + Copy, const N: usize> EinsumOutput<T> for Vector<T, N> {
+    const RANK: usize = 1;
+    const DIMS: [usize; MAX_RANK] = [N, 1, 1, 1];
+    fn from_fn(_: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_inner(na::SVector::from_fn(|i, _| f([i, 0, 0, 0])))
+    }
+}
+
+impl<T: na::Scalar + Copy, const R: usize, const C: usize> EinsumOutput<T> for Matrix<T, R, C> {
+    const RANK: usize = 2;
+    const DIMS: [usize; MAX_RANK] = [R, C, 1, 1];
+    fn from_fn(_: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_inner(na::SMatrix::from_fn(|i, j| f([i, j, 0, 0])))
+    }
+}
+
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize> EinsumOutput<T>
+    for Tensor3<T, D1, D2, D3>
+{
+    const RANK: usize = 3;
+    const DIMS: [usize; MAX_RANK] = [D1, D2, D3, 1];
+    fn from_fn(_: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_fn(|i, j, k| f([i, j, k, 0]))
+    }
+}
+
+impl<T: Copy + num_traits::Zero, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    EinsumOutput<T> for Tensor4<T, D1, D2, D3, D4>
+{
+    const RANK: usize = 4;
+    const DIMS: [usize; MAX_RANK] = [D1, D2, D3, D4];
+    fn from_fn(_: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_fn(|i, j, k, l| f([i, j, k, l]))
+    }
+}
+
+impl<T: na::Scalar + Copy> EinsumOutput<T> for DynVector<T> {
+    const RANK: usize = 1;
+    const DIMS: [usize; MAX_RANK] = [DYN, 1, 1, 1];
+    fn from_fn(dims: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_inner(na::DVector::from_fn(dims[0], |i, _| f([i, 0, 0, 0])))
+    }
+}
+
+impl<T: na::Scalar + Copy> EinsumOutput<T> for DynMatrix<T> {
+    const RANK: usize = 2;
+    const DIMS: [usize; MAX_RANK] = [DYN, DYN, 1, 1];
+    fn from_fn(dims: [usize; MAX_RANK], mut f: impl FnMut([usize; MAX_RANK]) -> T) -> Self {
+        Self::from_inner(na::DMatrix::from_fn(dims[0], dims[1], |i, j| {
+            f([i, j, 0, 0])
+        }))
+    }
+}
+
+// ============================================================================
+// Operand tuples (used by the macro)
+// ============================================================================
+
+/// A tuple of operand references with a common scalar type.
+#[doc(hidden)]
+pub trait EinsumOperands {
+    type Scalar: Copy;
+    const COUNT: usize;
+    const RANKS: [usize; MAX_OPERANDS];
+    const DIMS: [[usize; MAX_RANK]; MAX_OPERANDS];
+    fn dims(&self) -> [[usize; MAX_RANK]; MAX_OPERANDS];
+    /// Product of the operands' elements at their multi-indices.
+    fn product(&self, index: &[[usize; MAX_RANK]; MAX_OPERANDS]) -> Self::Scalar;
+}
+
+macro_rules! impl_operand_tuple {
+    ($count:expr; $first:ident $(, $rest:ident $k:expr)*) => {
+        impl<$first: EinsumOperand $(, $rest: EinsumOperand<Scalar = $first::Scalar>)*> EinsumOperands
+            for ($first, $($rest,)*)
+        where
+            $first::Scalar: Mul<Output = $first::Scalar>,
+        {
+            type Scalar = $first::Scalar;
+            const COUNT: usize = $count;
+            const RANKS: [usize; MAX_OPERANDS] = {
+                let mut ranks = [0; MAX_OPERANDS];
+                ranks[0] = $first::RANK;
+                $(ranks[$k] = $rest::RANK;)*
+                ranks
+            };
+            const DIMS: [[usize; MAX_RANK]; MAX_OPERANDS] = {
+                let mut dims = [[1; MAX_RANK]; MAX_OPERANDS];
+                dims[0] = $first::DIMS;
+                $(dims[$k] = $rest::DIMS;)*
+                dims
+            };
+            #[allow(non_snake_case)]
+            fn dims(&self) -> [[usize; MAX_RANK]; MAX_OPERANDS] {
+                let ($first, $($rest,)*) = self;
+                let mut dims = [[1; MAX_RANK]; MAX_OPERANDS];
+                dims[0] = $first.dims();
+                $(dims[$k] = $rest.dims();)*
+                dims
+            }
+            #[allow(non_snake_case)]
+            fn product(&self, index: &[[usize; MAX_RANK]; MAX_OPERANDS]) -> Self::Scalar {
+                let ($first, $($rest,)*) = self;
+                $first.get(index[0]) $(* $rest.get(index[$k]))*
+            }
+        }
+    };
+}
+
+impl_operand_tuple!(1; A);
+impl_operand_tuple!(2; A, B 1);
+impl_operand_tuple!(3; A, B 1, C 2);
+impl_operand_tuple!(4; A, B 1, C 2, D 3);
+
+// ============================================================================
+// Specification
+// ============================================================================
+
+/// A parsed specification string such as `"ij,jk->ik"`.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct Spec {
+    /// Distinct index letters, in order of first appearance.
+    labels: [u8; MAX_LABELS],
+    n_labels: usize,
+    /// Label id of each axis of each operand.
+    operand_axes: [[usize; MAX_RANK]; MAX_OPERANDS],
+    operand_ranks: [usize; MAX_OPERANDS],
+    n_operands: usize,
+    /// Label id of each axis of the result.
+    output_axes: [usize; MAX_RANK],
+    output_rank: usize,
+}
+
+impl Spec {
+    /// Parses a specification; panics (at compile time in a `const`) if it
+    /// is malformed.
+    pub const fn parse(spec: &str) -> Self {
+        let bytes = spec.as_bytes();
+        let mut s = Spec {
+            labels: [0; MAX_LABELS],
+            n_labels: 0,
+            operand_axes: [[0; MAX_RANK]; MAX_OPERANDS],
+            operand_ranks: [0; MAX_OPERANDS],
+            n_operands: 1,
+            output_axes: [0; MAX_RANK],
+            output_rank: 0,
+        };
+        let mut in_output = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if c == b' ' {
+                // ignored
+            } else if c == b',' {
+                assert!(!in_output, "einsum: `,` after `->`");
+                assert!(s.n_operands < MAX_OPERANDS, "einsum: more than 4 operands");
+                s.n_operands += 1;
+            } else if c == b'-' {
+                assert!(
+                    !in_output && i + 1 < bytes.len() && bytes[i + 1] == b'>',
+                    "einsum: expected a single `->`"
+                );
+                in_output = true;
+                i += 1;
+            } else {
+                assert!(
+                    c.is_ascii_alphabetic(),
+                    "einsum: indices must be ASCII letters"
+                );
+                let mut id = 0;
+                while id < s.n_labels && s.labels[id] != c {
+                    id += 1;
+                }
+                if in_output {
+                    assert!(
+                        id < s.n_labels,
+                        "einsum: output index does not appear in any operand"
+                    );
+                    let mut a = 0;
+                    while a < s.output_rank {
+                        assert!(s.output_axes[a] != id, "einsum: output index repeated");
+                        a += 1;
+                    }
+                    assert!(s.output_rank < MAX_RANK, "einsum: result rank above 4");
+                    s.output_axes[s.output_rank] = id;
+                    s.output_rank += 1;
+                } else {
+                    if id == s.n_labels {
+                        s.labels[id] = c;
+                        s.n_labels += 1;
+                    }
+                    let k = s.n_operands - 1;
+                    assert!(
+                        s.operand_ranks[k] < MAX_RANK,
+                        "einsum: operand rank above 4"
+                    );
+                    s.operand_axes[k][s.operand_ranks[k]] = id;
+                    s.operand_ranks[k] += 1;
+                }
+            }
+            i += 1;
+        }
+        assert!(in_output, "einsum: missing `->` before the output indices");
+        s
+    }
+
+    /// Compile-time shape check for static dimensions. [`DYN`] entries are
+    /// skipped and checked by [`Spec::dims`] at runtime instead.
+    pub const fn check_static(
+        &self,
+        count: usize,
+        ranks: [usize; MAX_OPERANDS],
+        dims: [[usize; MAX_RANK]; MAX_OPERANDS],
+        output_rank: usize,
+        output_dims: [usize; MAX_RANK],
+    ) {
+        assert!(
+            count == self.n_operands,
+            "einsum: number of operands does not match the specification"
+        );
+        assert!(
+            output_rank == self.output_rank,
+            "einsum: result rank does not match the output indices"
+        );
+        let mut label_dims = [DYN; MAX_LABELS];
+        let mut k = 0;
+        while k < count {
+            assert!(
+                ranks[k] == self.operand_ranks[k],
+                "einsum: operand rank does not match its number of indices"
+            );
+            let mut a = 0;
+            while a < ranks[k] {
+                let id = self.operand_axes[k][a];
+                let d = dims[k][a];
+                if d != DYN {
+                    assert!(
+                        label_dims[id] == DYN || label_dims[id] == d,
+                        "einsum: index used with different dimensions"
+                    );
+                    label_dims[id] = d;
+                }
+                a += 1;
+            }
+            k += 1;
+        }
+        let mut a = 0;
+        while a < output_rank {
+            let expected = label_dims[self.output_axes[a]];
+            let d = output_dims[a];
+            assert!(
+                d == DYN || expected == DYN || d == expected,
+                "einsum: result dimension does not match its index"
+            );
+            a += 1;
+        }
+    }
+
+    /// Runtime dimension of every label and of the result; panics on a mismatch.
+    fn dims(
+        &self,
+        dims: &[[usize; MAX_RANK]; MAX_OPERANDS],
+        output_dims: &[usize; MAX_RANK],
+    ) -> ([usize; MAX_LABELS], [usize; MAX_RANK]) {
+        let mut label_dims = [DYN; MAX_LABELS];
+        let operands = self.operand_axes.iter().zip(dims).zip(self.operand_ranks);
+        for (k, ((axes, dims), rank)) in operands.enumerate().take(self.n_operands) {
+            for (&id, &d) in axes.iter().zip(dims).take(rank) {
+                assert!(
+                    label_dims[id] == DYN || label_dims[id] == d,
+                    "einsum: index `{}` has dimension {} in operand {k}, expected {}",
+                    self.labels[id] as char,
+                    d,
+                    label_dims[id]
+                );
+                label_dims[id] = d;
+            }
+        }
+        let mut result = [1; MAX_RANK];
+        for a in 0..self.output_rank {
+            let id = self.output_axes[a];
+            assert!(
+                output_dims[a] == DYN || output_dims[a] == label_dims[id],
+                "einsum: result axis {a} has dimension {}, index `{}` has {}",
+                output_dims[a],
+                self.labels[id] as char,
+                label_dims[id]
+            );
+            result[a] = label_dims[id];
+        }
+        (label_dims, result)
+    }
+}
+
+/// Carries a compile-time [`Spec`] (one unit struct per macro call site).
+#[doc(hidden)]
+pub trait EinsumSpec {
+    const SPEC: Spec;
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+/// Evaluates the contraction described by `S::SPEC`.
+#[doc(hidden)]
+pub fn evaluate<S, Ops, O>(operands: Ops) -> O
+where
+    S: EinsumSpec,
+    Ops: EinsumOperands,
+    Ops::Scalar: num_traits::Zero + Mul<Output = Ops::Scalar>,
+    O: EinsumOutput<Ops::Scalar>,
+{
+    const {
+        S::SPEC.check_static(Ops::COUNT, Ops::RANKS, Ops::DIMS, O::RANK, O::DIMS);
+    }
+    let spec = &S::SPEC;
+    let (label_dims, output_dims) = spec.dims(&operands.dims(), &O::DIMS);
+
+    // Labels absent from the output are summed over.
+    let mut summed = [0; MAX_LABELS];
+    let mut n_summed = 0;
+    for id in 0..spec.n_labels {
+        if !spec.output_axes[..spec.output_rank].contains(&id) {
+            summed[n_summed] = id;
+            n_summed += 1;
+        }
+    }
+    let summed = &summed[..n_summed];
+
+    O::from_fn(output_dims, |out| {
+        let mut values = [0; MAX_LABELS];
+        for a in 0..spec.output_rank {
+            values[spec.output_axes[a]] = out[a];
+        }
+        if summed.iter().any(|&id| label_dims[id] == 0) {
+            return num_traits::Zero::zero();
+        }
+
+        let mut sum = num_traits::Zero::zero();
+        loop {
+            let mut index = [[0; MAX_RANK]; MAX_OPERANDS];
+            for (k, idx) in index.iter_mut().enumerate().take(spec.n_operands) {
+                for a in 0..spec.operand_ranks[k] {
+                    idx[a] = values[spec.operand_axes[k][a]];
+                }
+            }
+            sum = sum + operands.product(&index);
+
+            // Advance the summed indices like an odometer.
+            let mut carry = true;
+            for &id in summed {
+                values[id] += 1;
+                if values[id] < label_dims[id] {
+                    carry = false;
+                    break;
+                }
+                values[id] = 0;
+            }
+            if carry {
+                return sum;
+            }
+        }
+    })
+}
+
+/// Evaluates a tensor contraction in Einstein index notation.
+///
+/// `einsum!("spec", a, b, ...)` multiplies the operands element-wise over the
+/// indices named in `spec` and sums over every index not listed after `->`.
+/// See [`multiarray::linalg::einsum`](crate::multiarray::linalg::einsum) for
+/// the supported types and how shapes are checked.
+///
+/// ```
+/// use strelitzia::einsum;
+/// use strelitzia::multiarray::{Matrix3, Tensor3333};
+///
+/// let c = Tensor3333::isotropic(1.0, 0.5);
+/// let strain = Matrix3::new(1e-3, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+/// let stress: Matrix3 = einsum!("ijkl,kl->ij", c, strain);
+/// assert!((stress[0] - 2e-3).abs() < 1e-15);
+/// ```
+#[macro_export]
+macro_rules! einsum {
+    ($spec:literal, $($operand:expr),+ $(,)?) => {{
+        struct EinsumSpecAtCallSite;
+        impl $crate::multiarray::linalg::einsum::EinsumSpec for EinsumSpecAtCallSite {
+            const SPEC: $crate::multiarray::linalg::einsum::Spec =
+                $crate::multiarray::linalg::einsum::Spec::parse($spec);
+        }
+        $crate::multiarray::linalg::einsum::evaluate::<EinsumSpecAtCallSite, _, _>(
+            ($(&$operand,)+),
+        )
+    }};
+}
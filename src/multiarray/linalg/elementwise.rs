@@ -0,0 +1,335 @@
+//! Element-wise math, comparisons and selection on dense arrays.
+//!
+//! [`ElementwiseMath`] applies scalar functions (`abs`, `sqrt`, `exp`, `min`,
+//! `max`, `clamp`) component by component, and compares arrays component by
+//! component into boolean masks of the same shape (`Vector3` -> `Vector3b`).
+//! Every binary method has a `_scalar` variant that broadcasts one scalar
+//! against all components. [`select`] blends two arrays through a mask.
+//!
+//! The methods carry an `elem_` prefix because the bare `exp` and `sqrt` on
+//! square matrices are the matrix functions of
+//! [`MatrixFunctions`](super::MatrixFunctions).
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::{Vector3, Vector3b, linalg::{ElementwiseMath, select}};
+//!
+//! let v = Vector3::new(-1.0, 4.0, 9.0);
+//! assert_eq!(v.elem_abs(), Vector3::new(1.0, 4.0, 9.0));
+//!
+//! let positive = v.elem_gt_scalar(0.0);
+//! assert_eq!(positive, Vector3b::new(false, true, true));
+//! assert_eq!(
+//!     select(&positive, &v.elem_sqrt(), &Vector3::zeros()),
+//!     Vector3::new(0.0, 2.0, 3.0)
+//! );
+//! ```
+
+use super::super::types::*;
+use nalgebra as na;
+use num_traits::Float;
+
+/// Element-wise functions and comparisons. Implemented for every dense
+/// `MultiArray` (static, dynamic and native backends).
+///
+/// Binary methods on dynamic arrays panic if the operand shapes differ.
+/// `min`, `max` and `clamp` compare with `PartialOrd`, so an unordered pair
+/// (a NaN on either side) keeps the component of `self`.
+pub trait ElementwiseMath<T: Copy>: Sized {
+    /// The same shape holding `U` instead of `T`; `Mapped<bool>` is the mask
+    /// type (`Vector3b` for `Vector3`, `Matrix3b` for `Matrix3`, ...).
+    type Mapped<U: na::Scalar + Copy>;
+
+    /// Applies `f` to every component.
+    fn map_elements<U: na::Scalar + Copy>(&self, f: impl FnMut(T) -> U) -> Self::Mapped<U>;
+    /// Applies `f` to every pair of matching components.
+    fn zip_map_elements<U: na::Scalar + Copy>(
+        &self,
+        other: &Self,
+        f: impl FnMut(T, T) -> U,
+    ) -> Self::Mapped<U>;
+
+    /// Absolute value of every component.
+    fn elem_abs(&self) -> Self
+    where
+        T: Float;
+    /// Square root of every component (NaN for negative components).
+    fn elem_sqrt(&self) -> Self
+    where
+        T: Float;
+    /// Exponential of every component.
+    fn elem_exp(&self) -> Self
+    where
+        T: Float;
+
+    /// Component-wise minimum.
+    fn elem_min(&self, other: &Self) -> Self
+    where
+        T: PartialOrd;
+    /// Component-wise maximum.
+    fn elem_max(&self, other: &Self) -> Self
+    where
+        T: PartialOrd;
+    /// Clamps every component to `[lo, hi]`, component-wise bounds.
+    fn elem_clamp(&self, lo: &Self, hi: &Self) -> Self
+    where
+        T: PartialOrd;
+    /// Minimum of every component and `s`.
+    fn elem_min_scalar(&self, s: T) -> Self
+    where
+        T: PartialOrd;
+    /// Maximum of every component and `s`.
+    fn elem_max_scalar(&self, s: T) -> Self
+    where
+        T: PartialOrd;
+    /// Clamps every component to `[lo, hi]`.
+    fn elem_clamp_scalar(&self, lo: T, hi: T) -> Self
+    where
+        T: PartialOrd;
+
+    /// Mask of `self[i] < other[i]`.
+    fn elem_lt(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.zip_map_elements(other, |a, b| a < b)
+    }
+    /// Mask of `self[i] <= other[i]`.
+    fn elem_le(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.zip_map_elements(other, |a, b| a <= b)
+    }
+    /// Mask of `self[i] > other[i]`.
+    fn elem_gt(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.zip_map_elements(other, |a, b| a > b)
+    }
+    /// Mask of `self[i] >= other[i]`.
+    fn elem_ge(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.zip_map_elements(other, |a, b| a >= b)
+    }
+    /// Mask of `self[i] == other[i]`.
+    fn elem_eq(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialEq,
+    {
+        self.zip_map_elements(other, |a, b| a == b)
+    }
+    /// Mask of `self[i] != other[i]`.
+    fn elem_ne(&self, other: &Self) -> Self::Mapped<bool>
+    where
+        T: PartialEq,
+    {
+        self.zip_map_elements(other, |a, b| a != b)
+    }
+
+    /// Mask of `self[i] < s`.
+    fn elem_lt_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.map_elements(|a| a < s)
+    }
+    /// Mask of `self[i] <= s`.
+    fn elem_le_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.map_elements(|a| a <= s)
+    }
+    /// Mask of `self[i] > s`.
+    fn elem_gt_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.map_elements(|a| a > s)
+    }
+    /// Mask of `self[i] >= s`.
+    fn elem_ge_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialOrd,
+    {
+        self.map_elements(|a| a >= s)
+    }
+    /// Mask of `self[i] == s`.
+    fn elem_eq_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialEq,
+    {
+        self.map_elements(|a| a == s)
+    }
+    /// Mask of `self[i] != s`.
+    fn elem_ne_scalar(&self, s: T) -> Self::Mapped<bool>
+    where
+        T: PartialEq,
+    {
+        self.map_elements(|a| a != s)
+    }
+}
+
+fn min_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+fn max_of<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+fn check_dims(a: (usize, usize), b: (usize, usize)) {
+    assert_eq!(a, b, "Element-wise operation: operand shapes differ");
+}
+
+impl<T, S, B> MultiArray<T, S, B>
+where
+    T: Copy,
+    S: Shape,
+    B: MapStorage<T> + Clone,
+{
+    fn apply(&self, f: impl Fn(T) -> T) -> Self {
+        let mut result = self.clone();
+        for a in result.data.as_mut_slice() {
+            *a = f(*a);
+        }
+        result
+    }
+
+    fn zip_apply(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        check_dims(self.data.matrix_dims(), other.data.matrix_dims());
+        let mut result = self.clone();
+        for (a, b) in result
+            .data
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.data.as_slice())
+        {
+            *a = f(*a, *b);
+        }
+        result
+    }
+}
+
+// Single blanket implementation over every dense backend (same approach as
+// Hadamard in linalg/mod.rs).
+impl<T, S, B> ElementwiseMath<T> for MultiArray<T, S, B>
+where
+    T: Copy,
+    S: Shape,
+    B: MapStorage<T> + Clone,
+{
+    type Mapped<U: na::Scalar + Copy> = MultiArray<U, S, B::Mapped<U>>;
+
+    fn map_elements<U: na::Scalar + Copy>(&self, f: impl FnMut(T) -> U) -> Self::Mapped<U> {
+        MultiArray::from_inner(self.data.map_storage(f))
+    }
+
+    fn zip_map_elements<U: na::Scalar + Copy>(
+        &self,
+        other: &Self,
+        mut f: impl FnMut(T, T) -> U,
+    ) -> Self::Mapped<U> {
+        check_dims(self.data.matrix_dims(), other.data.matrix_dims());
+        let mut rhs = other.data.as_slice().iter();
+        self.map_elements(|a| f(a, *rhs.next().unwrap()))
+    }
+
+    fn elem_abs(&self) -> Self
+    where
+        T: Float,
+    {
+        self.apply(T::abs)
+    }
+
+    fn elem_sqrt(&self) -> Self
+    where
+        T: Float,
+    {
+        self.apply(T::sqrt)
+    }
+
+    fn elem_exp(&self) -> Self
+    where
+        T: Float,
+    {
+        self.apply(T::exp)
+    }
+
+    fn elem_min(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_apply(other, min_of)
+    }
+
+    fn elem_max(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_apply(other, max_of)
+    }
+
+    fn elem_clamp(&self, lo: &Self, hi: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.elem_max(lo).elem_min(hi)
+    }
+
+    fn elem_min_scalar(&self, s: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.apply(|a| min_of(a, s))
+    }
+
+    fn elem_max_scalar(&self, s: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.apply(|a| max_of(a, s))
+    }
+
+    fn elem_clamp_scalar(&self, lo: T, hi: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.apply(|a| min_of(max_of(a, lo), hi))
+    }
+}
+
+/// Picks `a[i]` where `mask[i]` is true and `b[i]` elsewhere.
+///
+/// Panics if the operand shapes differ (dynamic arrays only).
+pub fn select<T, S, B>(
+    mask: &MultiArray<bool, S, B::Mapped<bool>>,
+    a: &MultiArray<T, S, B>,
+    b: &MultiArray<T, S, B>,
+) -> MultiArray<T, S, B>
+where
+    T: Copy,
+    S: Shape,
+    B: MapStorage<T> + Clone,
+{
+    check_dims(mask.data.matrix_dims(), a.data.matrix_dims());
+    check_dims(a.data.matrix_dims(), b.data.matrix_dims());
+    let mut result = a.clone();
+    for ((r, &m), &y) in result
+        .data
+        .as_mut_slice()
+        .iter_mut()
+        .zip(mask.data.as_slice())
+        .zip(b.data.as_slice())
+    {
+        if !m {
+            *r = y;
+        }
+    }
+    result
+}
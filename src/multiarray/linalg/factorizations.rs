@@ -0,0 +1,676 @@
+//! Dense factorizations of `DynMatrix` with reusable factor objects.
+//!
+//! [`DenseFactorizations`] factors a matrix once; the returned factor object
+//! implements [`Factorization`] and solves any number of right-hand sides:
+//!
+//! - `lu` -- `PA = LU` with partial pivoting, for general square matrices.
+//! - `qr` -- Householder `A = QR`, for square or tall matrices (least squares).
+//! - `cholesky` -- `A = LLᵀ`, for symmetric positive definite matrices.
+//! - `ldlt` -- `A = LDLᵀ` without pivoting, for symmetric (possibly
+//!   indefinite) matrices such as saddle-point or shifted Hessian systems.
+//!
+//! Cholesky and LDLᵀ read only the lower triangle. A pivot below
+//! `n ε max|aᵢⱼ|` is treated as zero and reported as a [`FactorizationError`].
+//!
+//! `condition_estimate` returns the 1-norm condition number `‖A‖₁ ‖A⁻¹‖₁`,
+//! with `‖A⁻¹‖₁` estimated by Hager's method from a few extra solves instead
+//! of forming the inverse.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::{DynMatrix, DynVector};
+//! use strelitzia::multiarray::linalg::{DenseFactorizations, Factorization, FactorizationError};
+//!
+//! let a: DynMatrix<f64> =
+//!     DynMatrix::from_slice(&[4.0, 1.0, 0.0, 1.0, 4.0, 1.0, 0.0, 1.0, 4.0], 3, 3);
+//! let chol = a.cholesky().unwrap();
+//!
+//! let x = chol.solve(&DynVector::from_slice(&[5.0, 6.0, 5.0]));
+//! assert!((x.as_inner()[1] - 1.0).abs() < 1e-14);
+//! assert!((chol.determinant() - 56.0).abs() < 1e-12);
+//!
+//! // Solve in place on a flat slice, reusing the factors.
+//! let mut b = [4.0_f64, 1.0, 0.0];
+//! chol.solve_in_place(&mut b);
+//! assert!((b[0] - 1.0).abs() < 1e-14);
+//!
+//! let indefinite: DynMatrix<f64> = DynMatrix::from_slice(&[1.0, 2.0, 2.0, 1.0], 2, 2);
+//! assert_eq!(
+//!     indefinite.cholesky().unwrap_err(),
+//!     FactorizationError::NotPositiveDefinite { pivot: 1 }
+//! );
+//! assert!(indefinite.ldlt().is_ok());
+//! ```
+
+use crate::multiarray::{DynMatrix, DynVector};
+use nalgebra as na;
+use std::fmt;
+
+/// Iteration cap for the condition estimator (it usually stops after 2–3).
+const MAX_CONDITION_ITER: usize = 5;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Why a factorization could not be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorizationError {
+    /// The matrix has the wrong shape: LU, Cholesky and LDLᵀ need a square
+    /// matrix, QR needs at least as many rows as columns.
+    InvalidShape { nrows: usize, ncols: usize },
+    /// Zero pivot at step `pivot`: the matrix is singular (rank deficient for
+    /// QR). For LDLᵀ this also covers a singular leading block, which needs
+    /// pivoting; use LU instead.
+    Singular { pivot: usize },
+    /// Non-positive pivot at step `pivot` in Cholesky: the matrix is
+    /// indefinite or only semi-definite.
+    NotPositiveDefinite { pivot: usize },
+}
+
+impl fmt::Display for FactorizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidShape { nrows, ncols } => {
+                write!(f, "unsupported matrix shape {nrows}x{ncols}")
+            }
+            Self::Singular { pivot } => write!(f, "matrix is singular (zero pivot at {pivot})"),
+            Self::NotPositiveDefinite { pivot } => {
+                write!(f, "matrix is not positive definite (pivot {pivot})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FactorizationError {}
+
+// ============================================================================
+// Extension traits
+// ============================================================================
+
+/// Dense factorizations of a dynamic matrix.
+pub trait DenseFactorizations<T: na::RealField + Copy> {
+    /// LU decomposition with partial pivoting.
+    fn lu(&self) -> Result<Lu<T>, FactorizationError>;
+    /// Householder QR decomposition; `nrows >= ncols`.
+    fn qr(&self) -> Result<Qr<T>, FactorizationError>;
+    /// Cholesky decomposition of a symmetric positive definite matrix.
+    fn cholesky(&self) -> Result<Cholesky<T>, FactorizationError>;
+    /// LDLᵀ decomposition of a symmetric matrix, without pivoting.
+    fn ldlt(&self) -> Result<Ldlt<T>, FactorizationError>;
+}
+
+/// A factored matrix `A` that can solve `A x = b` repeatedly.
+pub trait Factorization<T: na::RealField + Copy> {
+    /// Number of rows of the factored matrix.
+    fn nrows(&self) -> usize;
+    /// Number of columns of the factored matrix.
+    fn ncols(&self) -> usize;
+
+    /// Overwrites `b` (length `nrows`) with the solution of `A x = b`. For a
+    /// tall QR the least-squares solution is in `b[..ncols]`.
+    fn solve_in_place(&self, b: &mut [T]);
+
+    /// Solution of `A x = b` (least squares for a tall QR).
+    fn solve(&self, b: &DynVector<T>) -> DynVector<T> {
+        let mut x = b.as_inner().as_slice().to_vec();
+        self.solve_in_place(&mut x);
+        DynVector::from_slice(&x[..self.ncols()])
+    }
+
+    /// Determinant of `A`. Panics for a non-square QR.
+    fn determinant(&self) -> T;
+
+    /// Estimate of the 1-norm condition number `‖A‖₁ ‖A⁻¹‖₁`.
+    fn condition_estimate(&self) -> T;
+}
+
+impl<T: na::RealField + Copy> DenseFactorizations<T> for DynMatrix<T> {
+    fn lu(&self) -> Result<Lu<T>, FactorizationError> {
+        Lu::new(self.as_inner())
+    }
+
+    fn qr(&self) -> Result<Qr<T>, FactorizationError> {
+        Qr::new(self.as_inner())
+    }
+
+    fn cholesky(&self) -> Result<Cholesky<T>, FactorizationError> {
+        Cholesky::new(self.as_inner())
+    }
+
+    fn ldlt(&self) -> Result<Ldlt<T>, FactorizationError> {
+        Ldlt::new(self.as_inner())
+    }
+}
+
+// ============================================================================
+// LU
+// ============================================================================
+
+/// `PA = LU` factors from [`DenseFactorizations::lu`].
+#[derive(Debug, Clone)]
+pub struct Lu<T> {
+    /// Unit lower `L` below the diagonal, `U` on and above it.
+    lu: na::DMatrix<T>,
+    /// Row `i` of `PA` is row `perm[i]` of `A`.
+    perm: Vec<usize>,
+    /// Number of row swaps.
+    swaps: usize,
+    norm1: T,
+}
+
+impl<T: na::RealField + Copy> Lu<T> {
+    fn new(a: &na::DMatrix<T>) -> Result<Self, FactorizationError> {
+        let n = square_dim(a)?;
+        let tol = pivot_tolerance(a);
+        let mut lu = a.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let p = (k + 1..n).fold(k, |p, i| {
+                if lu[(i, k)].abs() > lu[(p, k)].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if lu[(p, k)].abs() <= tol {
+                return Err(FactorizationError::Singular { pivot: k });
+            }
+            if p != k {
+                lu.swap_rows(p, k);
+                perm.swap(p, k);
+                swaps += 1;
+            }
+            let pivot = lu[(k, k)];
+            for i in k + 1..n {
+                lu[(i, k)] /= pivot;
+            }
+            for j in k + 1..n {
+                let ukj = lu[(k, j)];
+                for i in k + 1..n {
+                    let lik = lu[(i, k)];
+                    lu[(i, j)] -= lik * ukj;
+                }
+            }
+        }
+
+        Ok(Self {
+            lu,
+            perm,
+            swaps,
+            norm1: norm1(a),
+        })
+    }
+
+    /// Solves `Aᵀ x = b` in place: `Uᵀ Lᵀ P x = b`.
+    fn solve_transpose_in_place(&self, b: &mut [T]) {
+        let n = self.lu.nrows();
+        forward_substitute_transposed(&self.lu, b, false);
+        backward_substitute_transposed(&self.lu, b, true);
+        let w = b.to_vec();
+        for i in 0..n {
+            b[self.perm[i]] = w[i];
+        }
+    }
+}
+
+impl<T: na::RealField + Copy> Factorization<T> for Lu<T> {
+    fn nrows(&self) -> usize {
+        self.lu.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.lu.ncols()
+    }
+
+    fn solve_in_place(&self, b: &mut [T]) {
+        check_rhs(b, self.lu.nrows());
+        let permuted: Vec<T> = self.perm.iter().map(|&p| b[p]).collect();
+        b.copy_from_slice(&permuted);
+        forward_substitute(&self.lu, b, true);
+        backward_substitute(&self.lu, b, false);
+    }
+
+    fn determinant(&self) -> T {
+        let det = self.lu.diagonal().iter().fold(T::one(), |acc, &d| acc * d);
+        if self.swaps.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    fn condition_estimate(&self) -> T {
+        self.norm1
+            * inverse_norm1_estimate(
+                self.lu.nrows(),
+                |x| self.solve_in_place(x),
+                |x| self.solve_transpose_in_place(x),
+            )
+    }
+}
+
+// ============================================================================
+// QR
+// ============================================================================
+
+/// `A = QR` factors from [`DenseFactorizations::qr`].
+#[derive(Debug, Clone)]
+pub struct Qr<T> {
+    /// `R` on and above the diagonal, Householder vectors (with implicit
+    /// leading 1) below it.
+    qr: na::DMatrix<T>,
+    /// Householder coefficients: `Hₖ = I - τₖ vₖ vₖᵀ`; zero means `Hₖ = I`.
+    tau: Vec<T>,
+}
+
+impl<T: na::RealField + Copy> Qr<T> {
+    fn new(a: &na::DMatrix<T>) -> Result<Self, FactorizationError> {
+        let (m, n) = a.shape();
+        if m < n {
+            return Err(FactorizationError::InvalidShape { nrows: m, ncols: n });
+        }
+        let tol = pivot_tolerance(a);
+        let mut qr = a.clone();
+        let mut tau = vec![T::zero(); n];
+
+        for k in 0..n {
+            let norm = (k..m)
+                .fold(T::zero(), |acc, i| acc + qr[(i, k)] * qr[(i, k)])
+                .sqrt();
+            if norm > T::zero() {
+                // Reflect x onto alpha e₁, with the sign chosen to avoid cancellation.
+                let akk = qr[(k, k)];
+                let alpha = if akk >= T::zero() { -norm } else { norm };
+                let v0 = akk - alpha;
+                for i in k + 1..m {
+                    qr[(i, k)] /= v0;
+                }
+                qr[(k, k)] = alpha;
+                tau[k] = -v0 / alpha;
+
+                for j in k + 1..n {
+                    let w = (k + 1..m).fold(qr[(k, j)], |acc, i| acc + qr[(i, k)] * qr[(i, j)]);
+                    let tw = tau[k] * w;
+                    qr[(k, j)] -= tw;
+                    for i in k + 1..m {
+                        let vi = qr[(i, k)];
+                        qr[(i, j)] -= tw * vi;
+                    }
+                }
+            }
+            if qr[(k, k)].abs() <= tol {
+                return Err(FactorizationError::Singular { pivot: k });
+            }
+        }
+
+        Ok(Self { qr, tau })
+    }
+
+    /// `b ← Qᵀ b`.
+    fn apply_qt(&self, b: &mut [T]) {
+        let m = self.qr.nrows();
+        for (k, &tau) in self.tau.iter().enumerate() {
+            if tau == T::zero() {
+                continue;
+            }
+            // Column-major: column k below the diagonal.
+            let v = &self.qr.as_slice()[k * m + k + 1..(k + 1) * m];
+            let (head, tail) = b[k..].split_first_mut().unwrap();
+            let w = v
+                .iter()
+                .zip(tail.iter())
+                .fold(*head, |acc, (&vi, &bi)| acc + vi * bi);
+            let tw = tau * w;
+            *head -= tw;
+            for (bi, &vi) in tail.iter_mut().zip(v) {
+                *bi -= tw * vi;
+            }
+        }
+    }
+}
+
+impl<T: na::RealField + Copy> Factorization<T> for Qr<T> {
+    fn nrows(&self) -> usize {
+        self.qr.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.qr.ncols()
+    }
+
+    fn solve_in_place(&self, b: &mut [T]) {
+        check_rhs(b, self.qr.nrows());
+        self.apply_qt(b);
+        backward_substitute(&self.qr, &mut b[..self.qr.ncols()], false);
+    }
+
+    fn determinant(&self) -> T {
+        assert!(
+            self.qr.is_square(),
+            "Factorization::determinant: QR of a non-square matrix"
+        );
+        // Each non-trivial Householder reflection has determinant -1.
+        let reflections = self.tau.iter().filter(|&&t| t != T::zero()).count();
+        let det = self.qr.diagonal().iter().fold(T::one(), |acc, &d| acc * d);
+        if reflections.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Estimate of `κ₁(R)`. `Q` is orthogonal, so this is within a factor `n`
+    /// of `κ₂(A) = κ₂(R)`, and is defined for tall matrices too.
+    fn condition_estimate(&self) -> T {
+        let n = self.qr.ncols();
+        let r = self.qr.view((0, 0), (n, n));
+        let r_norm1 = (0..n)
+            .map(|j| (0..=j).fold(T::zero(), |acc, i| acc + r[(i, j)].abs()))
+            .fold(T::zero(), |acc, s| acc.max(s));
+        r_norm1
+            * inverse_norm1_estimate(
+                n,
+                |x| backward_substitute(&r, x, false),
+                |x| forward_substitute_transposed(&r, x, false),
+            )
+    }
+}
+
+// ============================================================================
+// Cholesky
+// ============================================================================
+
+/// `A = LLᵀ` factors from [`DenseFactorizations::cholesky`].
+#[derive(Debug, Clone)]
+pub struct Cholesky<T> {
+    /// `L` on and below the diagonal; the upper triangle is unused.
+    l: na::DMatrix<T>,
+    norm1: T,
+}
+
+impl<T: na::RealField + Copy> Cholesky<T> {
+    fn new(a: &na::DMatrix<T>) -> Result<Self, FactorizationError> {
+        let n = square_dim(a)?;
+        let tol = pivot_tolerance(a);
+        let mut l = a.clone();
+
+        for j in 0..n {
+            let d = (0..j).fold(l[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+            if d <= tol {
+                return Err(FactorizationError::NotPositiveDefinite { pivot: j });
+            }
+            let ljj = d.sqrt();
+            l[(j, j)] = ljj;
+            for i in j + 1..n {
+                let s = (0..j).fold(l[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = s / ljj;
+            }
+        }
+
+        Ok(Self {
+            l,
+            norm1: symmetric_norm1(a),
+        })
+    }
+}
+
+impl<T: na::RealField + Copy> Factorization<T> for Cholesky<T> {
+    fn nrows(&self) -> usize {
+        self.l.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.l.ncols()
+    }
+
+    fn solve_in_place(&self, b: &mut [T]) {
+        check_rhs(b, self.l.nrows());
+        forward_substitute(&self.l, b, false);
+        backward_substitute_transposed(&self.l, b, false);
+    }
+
+    fn determinant(&self) -> T {
+        self.l
+            .diagonal()
+            .iter()
+            .fold(T::one(), |acc, &d| acc * d * d)
+    }
+
+    fn condition_estimate(&self) -> T {
+        // A is symmetric, so the transposed solve is the same solve.
+        self.norm1
+            * inverse_norm1_estimate(
+                self.l.nrows(),
+                |x| self.solve_in_place(x),
+                |x| self.solve_in_place(x),
+            )
+    }
+}
+
+// ============================================================================
+// LDLᵀ
+// ============================================================================
+
+/// `A = LDLᵀ` factors from [`DenseFactorizations::ldlt`].
+#[derive(Debug, Clone)]
+pub struct Ldlt<T> {
+    /// Unit lower `L` below the diagonal; the rest is unused.
+    l: na::DMatrix<T>,
+    d: Vec<T>,
+    norm1: T,
+}
+
+impl<T: na::RealField + Copy> Ldlt<T> {
+    fn new(a: &na::DMatrix<T>) -> Result<Self, FactorizationError> {
+        let n = square_dim(a)?;
+        let tol = pivot_tolerance(a);
+        let mut l = a.clone();
+        let mut d = vec![T::zero(); n];
+
+        for j in 0..n {
+            let dj = (0..j).fold(l[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)] * d[k]);
+            if dj.abs() <= tol {
+                return Err(FactorizationError::Singular { pivot: j });
+            }
+            d[j] = dj;
+            for i in j + 1..n {
+                let s = (0..j).fold(l[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)] * d[k]);
+                l[(i, j)] = s / dj;
+            }
+        }
+
+        Ok(Self {
+            l,
+            d,
+            norm1: symmetric_norm1(a),
+        })
+    }
+
+    /// Numbers of positive and negative eigenvalues of `A` (Sylvester's law
+    /// of inertia: the signs of `D`).
+    pub fn inertia(&self) -> (usize, usize) {
+        let positive = self.d.iter().filter(|&&d| d > T::zero()).count();
+        (positive, self.d.len() - positive)
+    }
+}
+
+impl<T: na::RealField + Copy> Factorization<T> for Ldlt<T> {
+    fn nrows(&self) -> usize {
+        self.l.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.l.ncols()
+    }
+
+    fn solve_in_place(&self, b: &mut [T]) {
+        check_rhs(b, self.l.nrows());
+        forward_substitute(&self.l, b, true);
+        for (x, &d) in b.iter_mut().zip(&self.d) {
+            *x /= d;
+        }
+        backward_substitute_transposed(&self.l, b, true);
+    }
+
+    fn determinant(&self) -> T {
+        self.d.iter().fold(T::one(), |acc, &d| acc * d)
+    }
+
+    fn condition_estimate(&self) -> T {
+        self.norm1
+            * inverse_norm1_estimate(
+                self.l.nrows(),
+                |x| self.solve_in_place(x),
+                |x| self.solve_in_place(x),
+            )
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn square_dim<T: na::Scalar>(a: &na::DMatrix<T>) -> Result<usize, FactorizationError> {
+    let (nrows, ncols) = a.shape();
+    if nrows == ncols {
+        Ok(nrows)
+    } else {
+        Err(FactorizationError::InvalidShape { nrows, ncols })
+    }
+}
+
+/// Pivots at or below `max(m, n) ε max|aᵢⱼ|` count as zero.
+fn pivot_tolerance<T: na::RealField + Copy>(a: &na::DMatrix<T>) -> T {
+    let n: T = na::convert(a.nrows().max(a.ncols()) as f64);
+    T::default_epsilon() * n * a.amax()
+}
+
+fn check_rhs<T>(b: &[T], n: usize) {
+    assert_eq!(
+        b.len(),
+        n,
+        "Factorization::solve_in_place: right-hand side has {} entries, expected {n}",
+        b.len()
+    );
+}
+
+/// Maximum absolute column sum.
+fn norm1<T: na::RealField + Copy>(a: &na::DMatrix<T>) -> T {
+    a.column_iter()
+        .map(|c| c.iter().fold(T::zero(), |acc, &x| acc + x.abs()))
+        .fold(T::zero(), |acc, s| acc.max(s))
+}
+
+/// `‖A‖₁` of the symmetric matrix whose lower triangle is stored in `a`.
+fn symmetric_norm1<T: na::RealField + Copy>(a: &na::DMatrix<T>) -> T {
+    let n = a.nrows();
+    (0..n)
+        .map(|j| {
+            (0..n).fold(T::zero(), |acc, i| {
+                acc + if i >= j { a[(i, j)] } else { a[(j, i)] }.abs()
+            })
+        })
+        .fold(T::zero(), |acc, s| acc.max(s))
+}
+
+/// Solves `L x = b` with the lower triangle of `m` (unit diagonal if `unit`).
+fn forward_substitute<T, S>(m: &na::Matrix<T, na::Dyn, na::Dyn, S>, b: &mut [T], unit: bool)
+where
+    T: na::RealField + Copy,
+    S: na::RawStorage<T, na::Dyn, na::Dyn>,
+{
+    for i in 0..b.len() {
+        let s = (0..i).fold(b[i], |acc, k| acc - m[(i, k)] * b[k]);
+        b[i] = if unit { s } else { s / m[(i, i)] };
+    }
+}
+
+/// Solves `U x = b` with the upper triangle of `m` (unit diagonal if `unit`).
+fn backward_substitute<T, S>(m: &na::Matrix<T, na::Dyn, na::Dyn, S>, b: &mut [T], unit: bool)
+where
+    T: na::RealField + Copy,
+    S: na::RawStorage<T, na::Dyn, na::Dyn>,
+{
+    let n = b.len();
+    for i in (0..n).rev() {
+        let s = (i + 1..n).fold(b[i], |acc, k| acc - m[(i, k)] * b[k]);
+        b[i] = if unit { s } else { s / m[(i, i)] };
+    }
+}
+
+/// Solves `Uᵀ x = b` with the upper triangle of `m` (unit diagonal if `unit`).
+fn forward_substitute_transposed<T, S>(
+    m: &na::Matrix<T, na::Dyn, na::Dyn, S>,
+    b: &mut [T],
+    unit: bool,
+) where
+    T: na::RealField + Copy,
+    S: na::RawStorage<T, na::Dyn, na::Dyn>,
+{
+    for i in 0..b.len() {
+        let s = (0..i).fold(b[i], |acc, k| acc - m[(k, i)] * b[k]);
+        b[i] = if unit { s } else { s / m[(i, i)] };
+    }
+}
+
+/// Solves `Lᵀ x = b` with the lower triangle of `m` (unit diagonal if `unit`).
+fn backward_substitute_transposed<T, S>(
+    m: &na::Matrix<T, na::Dyn, na::Dyn, S>,
+    b: &mut [T],
+    unit: bool,
+) where
+    T: na::RealField + Copy,
+    S: na::RawStorage<T, na::Dyn, na::Dyn>,
+{
+    let n = b.len();
+    for i in (0..n).rev() {
+        let s = (i + 1..n).fold(b[i], |acc, k| acc - m[(k, i)] * b[k]);
+        b[i] = if unit { s } else { s / m[(i, i)] };
+    }
+}
+
+/// Hager's estimate of `‖A⁻¹‖₁` from solves with `A` and `Aᵀ`.
+fn inverse_norm1_estimate<T: na::RealField + Copy>(
+    n: usize,
+    solve: impl Fn(&mut [T]),
+    solve_transpose: impl Fn(&mut [T]),
+) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+    let mut x = vec![T::one() / na::convert::<f64, T>(n as f64); n];
+    let mut estimate = T::zero();
+    for iter in 0..MAX_CONDITION_ITER {
+        let mut y = x.clone();
+        solve(&mut y);
+        let norm = y.iter().fold(T::zero(), |acc, v| acc + v.abs());
+        if iter > 0 && norm <= estimate {
+            break;
+        }
+        estimate = norm;
+
+        // The gradient of ‖A⁻¹x‖₁ is A⁻ᵀ sign(y); move to its largest entry.
+        let mut z: Vec<T> = y
+            .iter()
+            .map(|&v| if v >= T::zero() { T::one() } else { -T::one() })
+            .collect();
+        solve_transpose(&mut z);
+        let (j, z_max) = z.iter().enumerate().fold((0, T::zero()), |(j, m), (i, v)| {
+            if v.abs() > m { (i, v.abs()) } else { (j, m) }
+        });
+        let z_dot_x = z
+            .iter()
+            .zip(&x)
+            .fold(T::zero(), |acc, (a, b)| acc + *a * *b);
+        if iter > 0 && z_max <= z_dot_x {
+            break;
+        }
+        x.iter_mut().for_each(|v| *v = T::zero());
+        x[j] = T::one();
+    }
+    estimate
+}
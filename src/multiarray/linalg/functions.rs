@@ -0,0 +1,299 @@
+//! Primary matrix functions for small static square matrices.
+//!
+//! Provides the exponential, principal logarithm, principal square root and
+//! powers of `Matrix<T, N, N>` (in practice `Matrix2`, `Matrix3`, `Matrix4`):
+//!
+//! - `exp` -- scaling and squaring with a diagonal [6/6] Padé approximant
+//!   (Golub & Van Loan, Algorithm 9.3.1).
+//! - `sqrt` -- Denman–Beavers iteration with determinant scaling.
+//! - `log` -- inverse scaling and squaring: repeated square roots until the
+//!   matrix is close to the identity, then the series
+//!   `log(B) = 2 atanh((B - I)(B + I)⁻¹)`.
+//! - `powf` / `powi` -- `exp(p log A)` and binary powering.
+//!
+//! The logarithm and square root exist (as real principal branches) only when
+//! no eigenvalue lies on the closed negative real axis; outside that domain the
+//! `try_*` methods return `None` and the plain methods panic, like
+//! [`SquareMatrixOps::inverse`](super::SquareMatrixOps::inverse).
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::Matrix2;
+//! use strelitzia::multiarray::linalg::MatrixFunctions;
+//!
+//! // exp of a skew-symmetric generator is a rotation
+//! let w = Matrix2::from_slice(&[0.0, 0.5, -0.5, 0.0]);
+//! let r = w.exp();
+//! assert!((r.as_inner()[(1, 0)] - 0.5_f64.sin()).abs() < 1e-14);
+//! assert!((r.log() - w).as_inner().amax() < 1e-12);
+//!
+//! // log is undefined for a negative eigenvalue
+//! let reflect = Matrix2::from_slice(&[-1.0, 0.0, 0.0, 1.0]);
+//! assert!(reflect.try_log().is_none());
+//! ```
+
+use crate::multiarray::Matrix;
+use nalgebra as na;
+
+/// Padé degree for `exp`; with `‖A / 2ˢ‖∞ ≤ 1/2` the truncation error is
+/// below `10⁻¹⁶`.
+const PADE_DEGREE: usize = 6;
+
+/// Upper bound on scaling steps, so non-finite input cannot loop forever.
+const MAX_SQUARINGS: u32 = 1100;
+
+/// Iteration cap for Denman–Beavers (convergence is quadratic, typically < 10).
+const MAX_SQRT_ITER: usize = 100;
+
+/// Square roots taken by `log` until `‖B - I‖_F` falls below this.
+const LOG_SERIES_RADIUS: f64 = 0.25;
+
+/// Maximum number of square roots taken by `log`.
+const MAX_LOG_SQRTS: usize = 64;
+
+// ============================================================================
+// Extension trait
+// ============================================================================
+
+/// Exponential, logarithm, square root and powers of a square matrix.
+pub trait MatrixFunctions<T>: Sized {
+    /// Matrix exponential `e^A`.
+    fn exp(&self) -> Self;
+
+    /// Principal logarithm. Returns `None` if the matrix is singular or has
+    /// an eigenvalue on the negative real axis.
+    fn try_log(&self) -> Option<Self>;
+    /// Principal logarithm. Panics outside the domain of [`try_log`](Self::try_log).
+    fn log(&self) -> Self {
+        self.try_log()
+            .expect("MatrixFunctions::log: matrix is singular or has a negative real eigenvalue")
+    }
+
+    /// Principal square root (all eigenvalues in the right half-plane).
+    /// Returns `None` if the matrix is singular or has an eigenvalue on the
+    /// negative real axis.
+    fn try_sqrt(&self) -> Option<Self>;
+    /// Principal square root. Panics outside the domain of [`try_sqrt`](Self::try_sqrt).
+    fn sqrt(&self) -> Self {
+        self.try_sqrt()
+            .expect("MatrixFunctions::sqrt: matrix is singular or has a negative real eigenvalue")
+    }
+
+    /// Real power `A^p = exp(p log A)`. Same domain as [`try_log`](Self::try_log).
+    fn try_powf(&self, p: T) -> Option<Self>;
+    /// Real power. Panics outside the domain of [`try_log`](Self::try_log).
+    fn powf(&self, p: T) -> Self {
+        self.try_powf(p)
+            .expect("MatrixFunctions::powf: matrix is singular or has a negative real eigenvalue")
+    }
+
+    /// Integer power by repeated squaring. Negative powers use the inverse and
+    /// return `None` for a singular matrix.
+    fn try_powi(&self, n: i32) -> Option<Self>;
+    /// Integer power. Panics if `n < 0` and the matrix is singular.
+    fn powi(&self, n: i32) -> Self {
+        self.try_powi(n)
+            .expect("MatrixFunctions::powi: negative power of a singular matrix")
+    }
+}
+
+// Matrix<T, N, N> -- the `DimMin` bound is needed for the LU-based inverse (see
+// `SquareMatrixOps`); every concrete `N` satisfies it.
+impl<T: na::RealField + Copy, const N: usize> MatrixFunctions<T> for Matrix<T, N, N>
+where
+    na::Const<N>: na::DimMin<na::Const<N>, Output = na::Const<N>>,
+{
+    fn exp(&self) -> Self {
+        Self::from_inner(expm(self.as_inner()))
+    }
+
+    fn try_log(&self) -> Option<Self> {
+        logm(self.as_inner()).map(Self::from_inner)
+    }
+
+    fn try_sqrt(&self) -> Option<Self> {
+        sqrtm(self.as_inner()).map(Self::from_inner)
+    }
+
+    fn try_powf(&self, p: T) -> Option<Self> {
+        logm(self.as_inner()).map(|l| Self::from_inner(expm(&(l * p))))
+    }
+
+    fn try_powi(&self, n: i32) -> Option<Self> {
+        let base = if n < 0 {
+            self.as_inner().try_inverse()?
+        } else {
+            *self.as_inner()
+        };
+        let mut result = na::SMatrix::<T, N, N>::identity();
+        let mut square = base;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= square;
+            }
+            square = square * square;
+            k >>= 1;
+        }
+        Some(Self::from_inner(result))
+    }
+}
+
+// ============================================================================
+// Algorithms on nalgebra matrices
+// ============================================================================
+
+/// Maximum absolute row sum.
+fn norm_inf<T: na::RealField + Copy, const N: usize>(m: &na::SMatrix<T, N, N>) -> T {
+    m.row_iter()
+        .map(|row| row.iter().fold(T::zero(), |acc, &x| acc + x.abs()))
+        .fold(T::zero(), |acc, s| acc.max(s))
+}
+
+fn expm<T: na::RealField + Copy, const N: usize>(a: &na::SMatrix<T, N, N>) -> na::SMatrix<T, N, N>
+where
+    na::Const<N>: na::DimMin<na::Const<N>, Output = na::Const<N>>,
+{
+    let one = T::one();
+    let half: T = na::convert(0.5);
+    let two: T = na::convert(2.0);
+
+    // Scale so that ‖X‖∞ ≤ 1/2.
+    let mut norm = norm_inf(a);
+    let mut squarings = 0;
+    let mut scale = one;
+    while norm > half && squarings < MAX_SQUARINGS {
+        norm *= half;
+        scale *= half;
+        squarings += 1;
+    }
+    let x = a * scale;
+
+    // N(X) = Σ c_k X^k, D(X) = Σ (-1)^k c_k X^k
+    let id = na::SMatrix::<T, N, N>::identity();
+    let mut c = half;
+    let mut numer = id + x * c;
+    let mut denom = id - x * c;
+    let mut power = x;
+    let q: T = na::convert(PADE_DEGREE as f64);
+    for k in 2..=PADE_DEGREE {
+        let kt: T = na::convert(k as f64);
+        c = c * (q - kt + one) / (kt * (two * q - kt + one));
+        power = x * power;
+        numer += power * c;
+        if k % 2 == 0 {
+            denom += power * c;
+        } else {
+            denom -= power * c;
+        }
+    }
+
+    // ‖X‖∞ ≤ 1/2 keeps D(X) well conditioned.
+    let mut f = denom
+        .lu()
+        .solve(&numer)
+        .expect("MatrixFunctions::exp: Padé denominator is singular");
+    for _ in 0..squarings {
+        f = f * f;
+    }
+    f
+}
+
+fn sqrtm<T: na::RealField + Copy, const N: usize>(
+    a: &na::SMatrix<T, N, N>,
+) -> Option<na::SMatrix<T, N, N>>
+where
+    na::Const<N>: na::DimMin<na::Const<N>, Output = na::Const<N>>,
+{
+    if !a.iter().all(|x| x.is_finite()) {
+        return None;
+    }
+    let half: T = na::convert(0.5);
+    let eps = T::default_epsilon();
+    let tol = eps.sqrt();
+    let n: T = na::convert(N as f64);
+
+    // Y → A^{1/2}, Z → A^{-1/2}
+    let mut y = *a;
+    let mut z = na::SMatrix::<T, N, N>::identity();
+    let mut converged = false;
+    for _ in 0..MAX_SQRT_ITER {
+        let y_inv = y.try_inverse()?;
+        let z_inv = z.try_inverse()?;
+
+        // Determinant scaling speeds up the early iterations; μ → 1 at convergence.
+        let det = (y.determinant() * z.determinant()).abs();
+        let mu = if det > T::zero() && det.is_finite() {
+            det.powf(-T::one() / (n + n))
+        } else {
+            T::one()
+        };
+
+        let y_next = (y * mu + z_inv / mu) * half;
+        let z_next = (z * mu + y_inv / mu) * half;
+        let change = (y_next - y).norm();
+        y = y_next;
+        z = z_next;
+        if !change.is_finite() {
+            return None;
+        }
+        if converged {
+            break;
+        }
+        // Quadratic convergence: one more step after reaching √ε.
+        converged = change <= tol * y.norm();
+    }
+    if !converged {
+        return None;
+    }
+
+    // A negative real eigenvalue has no real principal root: the iteration
+    // can settle on a non-root, so confirm Y² = A.
+    let residual = (y * y - a).norm();
+    (residual <= tol * (y.norm() * y.norm())).then_some(y)
+}
+
+fn logm<T: na::RealField + Copy, const N: usize>(
+    a: &na::SMatrix<T, N, N>,
+) -> Option<na::SMatrix<T, N, N>>
+where
+    na::Const<N>: na::DimMin<na::Const<N>, Output = na::Const<N>>,
+{
+    let id = na::SMatrix::<T, N, N>::identity();
+    let radius: T = na::convert(LOG_SERIES_RADIUS);
+    let two: T = na::convert(2.0);
+
+    // B = A^{1/2^k} close to I, then log(A) = 2^k log(B).
+    let mut b = *a;
+    let mut factor = T::one();
+    let mut sqrts = 0;
+    while (b - id).norm() > radius {
+        if sqrts == MAX_LOG_SQRTS {
+            return None;
+        }
+        b = sqrtm(&b)?;
+        factor *= two;
+        sqrts += 1;
+    }
+    if !b.iter().all(|x| x.is_finite()) {
+        return None;
+    }
+
+    // log(B) = 2 Σ Z^{2j+1} / (2j+1), Z = (B - I)(B + I)⁻¹, ‖Z‖ ≲ 1/7
+    let z = (b - id) * (b + id).try_inverse()?;
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = z;
+    let eps = T::default_epsilon();
+    for j in 1..64 {
+        term *= z2;
+        let denom: T = na::convert((2 * j + 1) as f64);
+        let contribution = term / denom;
+        sum += contribution;
+        if contribution.norm() <= eps * sum.norm() {
+            break;
+        }
+    }
+    Some(sum * (two * factor))
+}
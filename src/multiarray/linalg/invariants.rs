@@ -0,0 +1,107 @@
+//! Scalar invariants of second-order tensors.
+//!
+//! [`TensorInvariants`] provides the principal invariants `I1`, `I2`, `I3`,
+//! the deviatoric invariant `J2` and the von Mises equivalent value for
+//! `Matrix<T, 3, 3>` and `SymMatrix3`.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::SymMatrix3;
+//! use strelitzia::multiarray::linalg::TensorInvariants;
+//!
+//! // Uniaxial stress: von Mises equals the axial stress.
+//! let stress = SymMatrix3::new(250.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+//! assert!((stress.von_mises() - 250.0).abs() < 1e-10);
+//! ```
+
+use crate::multiarray::{Matrix, MultiArray, SymRank2};
+use nalgebra as na;
+
+/// Invariants of a 3x3 second-order tensor `A`.
+pub trait TensorInvariants<T>: Sized {
+    /// First invariant `I1 = tr A`.
+    fn i1(&self) -> T;
+    /// Second invariant `I2 = ((tr A)² - tr(A²)) / 2`.
+    fn i2(&self) -> T;
+    /// Third invariant `I3 = det A`.
+    fn i3(&self) -> T;
+    /// Deviatoric part `s = A - (tr A / 3) I`.
+    fn deviatoric(&self) -> Self;
+    /// Second deviatoric invariant `J2 = tr(s²) / 2`.
+    fn j2(&self) -> T;
+    /// von Mises equivalent value `sqrt(3 J2)`.
+    fn von_mises(&self) -> T;
+}
+
+impl<T: na::RealField + Copy> TensorInvariants<T> for Matrix<T, 3, 3> {
+    fn i1(&self) -> T {
+        self.as_inner().trace()
+    }
+    fn i2(&self) -> T {
+        let m = self.as_inner();
+        let half: T = na::convert(0.5);
+        let tr = m.trace();
+        (tr * tr - (m * m).trace()) * half
+    }
+    fn i3(&self) -> T {
+        self.as_inner().determinant()
+    }
+    fn deviatoric(&self) -> Self {
+        let third: T = na::convert(1.0 / 3.0);
+        let mean = self.i1() * third;
+        Self::from_inner(self.as_inner() - na::SMatrix::<T, 3, 3>::identity() * mean)
+    }
+    fn j2(&self) -> T {
+        let s = self.deviatoric();
+        let s = s.as_inner();
+        let half: T = na::convert(0.5);
+        (s * s).trace() * half
+    }
+    fn von_mises(&self) -> T {
+        let three: T = na::convert(3.0);
+        (three * self.j2()).sqrt()
+    }
+}
+
+impl<T: na::RealField + Copy> TensorInvariants<T>
+    for MultiArray<T, SymRank2<3>, na::SVector<T, 6>>
+{
+    fn i1(&self) -> T {
+        self.xx() + self.yy() + self.zz()
+    }
+    fn i2(&self) -> T {
+        let (xx, yy, zz) = (self.xx(), self.yy(), self.zz());
+        let (yz, xz, xy) = (self.yz(), self.xz(), self.xy());
+        xx * yy + yy * zz + zz * xx - yz * yz - xz * xz - xy * xy
+    }
+    fn i3(&self) -> T {
+        let (xx, yy, zz) = (self.xx(), self.yy(), self.zz());
+        let (yz, xz, xy) = (self.yz(), self.xz(), self.xy());
+        let two: T = na::convert(2.0);
+        xx * yy * zz + two * xy * yz * xz - xx * yz * yz - yy * xz * xz - zz * xy * xy
+    }
+    fn deviatoric(&self) -> Self {
+        let third: T = na::convert(1.0 / 3.0);
+        let mean = self.i1() * third;
+        let mut s = *self;
+        for d in s.data.iter_mut().take(3) {
+            *d -= mean;
+        }
+        s
+    }
+    fn j2(&self) -> T {
+        // Computed from the deviator directly rather than as I1²/3 - I2,
+        // which cancels badly under large hydrostatic pressure.
+        let s = self.deviatoric();
+        let half: T = na::convert(0.5);
+        (s.xx() * s.xx() + s.yy() * s.yy() + s.zz() * s.zz()) * half
+            + s.yz() * s.yz()
+            + s.xz() * s.xz()
+            + s.xy() * s.xy()
+    }
+    fn von_mises(&self) -> T {
+        let three: T = na::convert(3.0);
+        (three * self.j2()).sqrt()
+    }
+}
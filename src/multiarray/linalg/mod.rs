@@ -0,0 +1,401 @@
+//! Extension traits for domain-specific linear algebra operations.
+//!
+//! These traits provide method syntax for operations like `dot`, `cross`, `norm`,
+//! etc. without polluting the core `MultiArray` type. Users must `use` the
+//! trait to access its methods (like `use itertools::Itertools`).
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::{Vector3, linalg::{VectorOps, CrossProduct}};
+//!
+//! let v = Vector3::new(1.0, 0.0, 0.0);
+//! let w = Vector3::new(0.0, 1.0, 0.0);
+//!
+//! assert_eq!(v.dot(&w), 0.0);           // orthogonal
+//! assert_eq!(v.cross(&w), Vector3::new(0.0, 0.0, 1.0));
+//! assert!((v.norm() - 1.0).abs() < 1e-10);
+//! ```
+//!
+//! Matrix decompositions (`SymmetricEigen`, `SingularValueDecomposition`,
+//! `PolarDecomposition`) live in [`decompositions`], tensor contractions
+//! (`SingleContraction`, `DoubleContraction`) in [`contraction`], tensor
+//! invariants (`TensorInvariants`) in [`invariants`], matrix functions
+//! (`MatrixFunctions`: `exp`, `log`, `sqrt`, powers) in [`functions`], and
+//! dense `DynMatrix` factorizations (`DenseFactorizations`: LU, QR, Cholesky,
+//! LDLᵀ) in [`factorizations`], and element-wise math, comparison masks and
+//! `select` (`ElementwiseMath`) in [`elementwise`]. All are re-exported here. Index-notation
+//! contractions (`einsum!("ij,jk->ik", a, b)`) live in [`einsum`]; only its
+//! operand traits are re-exported.
+
+pub mod contraction;
+pub mod decompositions;
+pub mod einsum;
+pub mod elementwise;
+pub mod factorizations;
+pub mod functions;
+pub mod invariants;
+
+pub use contraction::*;
+pub use decompositions::*;
+pub use einsum::{EinsumOperand, EinsumOutput};
+pub use elementwise::*;
+pub use factorizations::*;
+pub use functions::*;
+pub use invariants::*;
+
+use super::aliases::*;
+use super::array_storage::ArrayStorage;
+use super::types::*;
+use nalgebra as na;
+use std::ops::Mul;
+
+// ============================================================================
+// VectorOps -- implemented for Vector<T, N> (any dimension)
+// ============================================================================
+
+/// Vector-specific operations. Implemented for rank-1 types only.
+///
+/// Complex vectors (`Vector3c`, ...) use the Hermitian inner product and
+/// moduli, so norms are real (`Self::Norm`) while `dot` stays complex.
+pub trait VectorOps<T> {
+    /// Owned vector type returned by `normalised` (`Self` for owned vectors,
+    /// the matching owned vector for views).
+    type Output;
+    /// Type of the norms: `T` for real vectors, `Real` for complex ones.
+    type Norm;
+    /// Inner product, conjugating `self` for complex vectors: `Σ conj(aᵢ) bᵢ`.
+    fn dot(&self, other: &Self) -> T;
+    /// L1 norm (sum of absolute values).
+    fn l1_norm(&self) -> Self::Norm;
+    /// L2 (Euclidean) norm.
+    fn l2_norm(&self) -> Self::Norm;
+    /// L-infinity norm (max absolute value).
+    fn linf_norm(&self) -> Self::Norm;
+    /// General Lp norm.
+    fn lp_norm(&self, p: i32) -> Self::Norm;
+    /// Convenience: forwards to `l2_norm()`.
+    fn norm(&self) -> Self::Norm {
+        self.l2_norm()
+    }
+    /// Squared L2 norm (avoids sqrt, useful for comparisons).
+    fn norm_squared(&self) -> Self::Norm;
+    /// Returns a new unit vector (does not modify self). Panics if zero.
+    fn normalised(&self) -> Self::Output;
+}
+
+// `dotc` and `camax` reduce to `dot` and `amax` for real scalars.
+impl<T: na::ComplexField + Copy, const N: usize> VectorOps<T> for Vector<T, N> {
+    type Output = Self;
+    type Norm = T::RealField;
+    fn dot(&self, other: &Self) -> T {
+        self.as_inner().dotc(other.as_inner())
+    }
+    fn l1_norm(&self) -> T::RealField {
+        self.as_inner().lp_norm(1)
+    }
+    fn l2_norm(&self) -> T::RealField {
+        self.as_inner().norm()
+    }
+    fn linf_norm(&self) -> T::RealField {
+        self.as_inner().camax()
+    }
+    fn lp_norm(&self, p: i32) -> T::RealField {
+        self.as_inner().lp_norm(p)
+    }
+    fn norm_squared(&self) -> T::RealField {
+        self.as_inner().norm_squared()
+    }
+    fn normalised(&self) -> Self {
+        Self::from_inner(self.as_inner().normalize())
+    }
+}
+
+// DynVector: as above, with a runtime length check in `dot`.
+impl<T: na::ComplexField + Copy> VectorOps<T> for DynVector<T> {
+    type Output = Self;
+    type Norm = T::RealField;
+    fn dot(&self, other: &Self) -> T {
+        assert_eq!(
+            self.as_inner().len(),
+            other.as_inner().len(),
+            "DynVector dot: lengths differ"
+        );
+        self.as_inner().dotc(other.as_inner())
+    }
+    fn l1_norm(&self) -> T::RealField {
+        self.as_inner().lp_norm(1)
+    }
+    fn l2_norm(&self) -> T::RealField {
+        self.as_inner().norm()
+    }
+    fn linf_norm(&self) -> T::RealField {
+        self.as_inner().camax()
+    }
+    fn lp_norm(&self, p: i32) -> T::RealField {
+        self.as_inner().lp_norm(p)
+    }
+    fn norm_squared(&self) -> T::RealField {
+        self.as_inner().norm_squared()
+    }
+    fn normalised(&self) -> Self {
+        Self::from_inner(self.as_inner().normalize())
+    }
+}
+
+// ============================================================================
+// CrossProduct -- implemented for 2-vectors and 3-vectors
+// ============================================================================
+
+/// Cross product. Return type varies by dimension.
+pub trait CrossProduct<T> {
+    type Output;
+    fn cross(&self, other: &Self) -> Self::Output;
+}
+
+// 3D cross: Vector<T,3> x Vector<T,3> -> Vector<T,3>
+impl<T: na::RealField + Copy> CrossProduct<T> for Vector<T, 3> {
+    type Output = Vector<T, 3>;
+    fn cross(&self, other: &Self) -> Vector<T, 3> {
+        Self::from_inner(self.as_inner().cross(other.as_inner()))
+    }
+}
+
+// 2D cross: Vector<T,2> x Vector<T,2> -> Vector<T,3> (scaled z-basis vector)
+impl<T: na::RealField + Copy> CrossProduct<T> for Vector<T, 2> {
+    type Output = Vector<T, 3>;
+    fn cross(&self, other: &Self) -> Vector<T, 3> {
+        let z = self.as_inner()[0] * other.as_inner()[1] - self.as_inner()[1] * other.as_inner()[0];
+        Vector::<T, 3>::new(T::zero(), T::zero(), z)
+    }
+}
+
+// ============================================================================
+// OuterProduct -- vector x vector -> matrix
+// ============================================================================
+
+/// Outer product: produces a matrix from two vectors.
+pub trait OuterProduct<T, Rhs> {
+    type Output;
+    fn outer(&self, other: &Rhs) -> Self::Output;
+}
+
+// Vector<T,N> x Vector<T,M> -> Matrix<T,N,M>
+impl<
+    T: na::Scalar
+        + Copy
+        + Mul<Output = T>
+        + std::ops::Add<Output = T>
+        + std::ops::AddAssign
+        + std::ops::MulAssign
+        + num_traits::Zero
+        + num_traits::One,
+    const N: usize,
+    const M: usize,
+> OuterProduct<T, Vector<T, M>> for Vector<T, N>
+{
+    type Output = Matrix<T, N, M>;
+    fn outer(&self, other: &Vector<T, M>) -> Matrix<T, N, M> {
+        Matrix::from_inner(self.as_inner() * other.as_inner().transpose())
+    }
+}
+
+// DynVector<T> (n) x DynVector<T> (m) -> DynMatrix<T> (n x m)
+impl<
+    T: na::Scalar
+        + Copy
+        + Mul<Output = T>
+        + std::ops::Add<Output = T>
+        + std::ops::AddAssign
+        + std::ops::MulAssign
+        + num_traits::Zero
+        + num_traits::One,
+> OuterProduct<T, DynVector<T>> for DynVector<T>
+{
+    type Output = DynMatrix<T>;
+    fn outer(&self, other: &DynVector<T>) -> DynMatrix<T> {
+        DynMatrix::from_inner(self.as_inner() * other.as_inner().transpose())
+    }
+}
+
+// ============================================================================
+// Hadamard -- element-wise multiplication (single generic impl)
+// ============================================================================
+
+/// Element-wise (Hadamard/Schur) product.
+pub trait Hadamard {
+    fn hadamard(&self, other: &Self) -> Self;
+}
+
+// Single blanket implementation -- works for vectors, matrices, dynamic types.
+// Dynamic operands are checked for equal length at runtime (static shapes
+// always agree).
+impl<T, S, B> Hadamard for MultiArray<T, S, B>
+where
+    T: Copy + Mul<Output = T>,
+    S: Shape,
+    B: DenseRawStorage<T> + Clone,
+{
+    fn hadamard(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.data.len(),
+            other.data.len(),
+            "Hadamard product: operand lengths differ"
+        );
+        let mut result = self.clone();
+        for (a, b) in result
+            .data
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.data.as_slice())
+        {
+            *a = *a * *b;
+        }
+        result
+    }
+}
+
+// ============================================================================
+// Transpose -- standalone trait (vectors AND matrices)
+// ============================================================================
+
+/// Transpose operation. Applies to both vectors and matrices.
+pub trait Transpose {
+    type Output;
+    fn transpose(&self) -> Self::Output;
+}
+
+// Vector<T, N> -> Matrix<T, 1, N> (column -> row vector)
+impl<T: na::Scalar + Copy, const N: usize> Transpose for Vector<T, N> {
+    type Output = Matrix<T, 1, N>;
+    fn transpose(&self) -> Matrix<T, 1, N> {
+        Matrix::from_inner(self.as_inner().transpose())
+    }
+}
+
+// Matrix<T, R, C> -> Matrix<T, C, R>
+impl<T: na::Scalar + Copy, const R: usize, const C: usize> Transpose for Matrix<T, R, C> {
+    type Output = Matrix<T, C, R>;
+    fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_inner(self.as_inner().transpose())
+    }
+}
+
+// DynVector<T> (n) -> DynMatrix<T> (1 x n)
+impl<T: na::Scalar + Copy> Transpose for DynVector<T> {
+    type Output = DynMatrix<T>;
+    fn transpose(&self) -> DynMatrix<T> {
+        let v = self.as_inner();
+        DynMatrix::from_inner(na::DMatrix::from_iterator(1, v.len(), v.iter().copied()))
+    }
+}
+
+// DynMatrix<T> (r x c) -> DynMatrix<T> (c x r)
+impl<T: na::Scalar + Copy> Transpose for DynMatrix<T> {
+    type Output = DynMatrix<T>;
+    fn transpose(&self) -> DynMatrix<T> {
+        DynMatrix::from_inner(self.as_inner().transpose())
+    }
+}
+
+// NativeVector<T, N> -> NativeMatrix<T, 1, N>
+impl<T: Copy, const N: usize> Transpose for NativeVector<T, N> {
+    type Output = NativeMatrix<T, 1, N>;
+    fn transpose(&self) -> NativeMatrix<T, 1, N> {
+        let row = &self.as_inner().0[0];
+        NativeMatrix::from_inner(ArrayStorage(std::array::from_fn(|j| [row[j]])))
+    }
+}
+
+// NativeMatrix<T, R, C> -> NativeMatrix<T, C, R>
+impl<T: Copy, const R: usize, const C: usize> Transpose for NativeMatrix<T, R, C> {
+    type Output = NativeMatrix<T, C, R>;
+    fn transpose(&self) -> NativeMatrix<T, C, R> {
+        let cols = &self.as_inner().0;
+        NativeMatrix::from_inner(ArrayStorage(std::array::from_fn(|i| {
+            std::array::from_fn(|j| cols[j][i])
+        })))
+    }
+}
+
+// NativeDynMatrix<T> -> NativeDynMatrix<T>
+impl<T: Copy> Transpose for NativeDynMatrix<T> {
+    type Output = NativeDynMatrix<T>;
+    fn transpose(&self) -> NativeDynMatrix<T> {
+        NativeDynMatrix::from_fn(self.ncols(), self.nrows(), |i, j| self[(j, i)])
+    }
+}
+
+// CsrMatrix<T> -> CsrMatrix<T>
+impl<T: Copy> Transpose for CsrMatrix<T> {
+    type Output = CsrMatrix<T>;
+    fn transpose(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_inner(self.as_inner().transpose())
+    }
+}
+
+// CooMatrix<T> -> CooMatrix<T> (swap row and column of every triplet)
+impl<T: Copy> Transpose for CooMatrix<T> {
+    type Output = CooMatrix<T>;
+    fn transpose(&self) -> CooMatrix<T> {
+        let mut t = CooMatrix::with_capacity(self.ncols(), self.nrows(), self.data.nnz());
+        for (i, j, v) in self.triplets() {
+            t.push(j, i, v);
+        }
+        t
+    }
+}
+
+// ============================================================================
+// SquareMatrixOps -- implemented for square static matrices and DynMatrix
+// ============================================================================
+
+/// Square matrix operations.
+pub trait SquareMatrixOps<T>: Sized {
+    /// Matrix inverse. Returns `None` if the matrix is singular (or, for
+    /// dynamic matrices, not square).
+    fn try_inverse(&self) -> Option<Self>;
+    /// Matrix inverse. Panics if singular.
+    fn inverse(&self) -> Self {
+        self.try_inverse()
+            .expect("SquareMatrixOps::inverse: matrix is singular")
+    }
+    /// Matrix determinant.
+    fn determinant(&self) -> T;
+    /// Sum of diagonal elements.
+    fn trace(&self) -> T;
+}
+
+// Matrix<T, N, N> -- the `DimMin` bound is satisfied by every concrete `N`
+// (nalgebra resolves it through typenum); it only needs restating in generic code.
+impl<T: na::RealField + Copy, const N: usize> SquareMatrixOps<T> for Matrix<T, N, N>
+where
+    na::Const<N>: na::DimMin<na::Const<N>, Output = na::Const<N>>,
+{
+    fn try_inverse(&self) -> Option<Self> {
+        self.as_inner().try_inverse().map(Self::from_inner)
+    }
+    fn determinant(&self) -> T {
+        self.as_inner().determinant()
+    }
+    fn trace(&self) -> T {
+        self.as_inner().trace()
+    }
+}
+
+// DynMatrix<T> -- squareness is a runtime property: `try_inverse` returns
+// `None` for non-square input, `determinant` and `trace` panic.
+impl<T: na::RealField + Copy> SquareMatrixOps<T> for DynMatrix<T> {
+    fn try_inverse(&self) -> Option<Self> {
+        if !self.as_inner().is_square() {
+            return None;
+        }
+        self.as_inner().clone().try_inverse().map(Self::from_inner)
+    }
+    fn determinant(&self) -> T {
+        self.as_inner().determinant()
+    }
+    fn trace(&self) -> T {
+        self.as_inner().trace()
+    }
+}
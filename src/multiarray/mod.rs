@@ -22,13 +22,23 @@
 //! ```
 
 mod aliases;
+mod array_storage;
 pub mod linalg;
 mod operators;
+mod rotation;
+mod sparse_storage;
 mod traits;
 mod types;
+mod vec_storage;
+mod view_storage;
+mod views;
 
 pub use aliases::*;
+pub use array_storage::*;
+pub use sparse_storage::*;
 pub use traits::*;
 pub use types::*;
+pub use vec_storage::*;
+pub use view_storage::*;
 // operators.rs contains only trait impls (no new public items to re-export),
 // but we still need the module to be compiled.
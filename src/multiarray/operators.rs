@@ -2,18 +2,23 @@
 //!
 //! Provides `std::ops` trait implementations (Add, Sub, Neg, Mul, Div,
 //! Rem, bitwise ops, and their compound-assignment variants),
-//! `std::iter::Sum`, and dedicated matrix multiplication impls.
+//! `std::iter::Sum`, and dedicated matrix multiplication impls (including
+//! sparse `&CsrMatrix * &DynVector` and the native `ArrayStorage` /
+//! `VecStorage` backends).
 //!
 //! Operators fall into two categories:
 //!
 //! - **Backend-delegating**: Add, Sub, Neg, Mul, Div and their compound
-//!   variants delegate directly to the nalgebra backend.
+//!   variants delegate directly to the backend (nalgebra or native).
 //! - **Element-wise**: Rem, BitAnd, BitOr, BitXor, Not, Shl, Shr and
 //!   their compound variants operate element-by-element via
 //!   `DenseRawStorage`, because nalgebra does not implement these.
 
+use super::array_storage::ArrayStorage;
+use super::sparse_storage::CsrStorage;
 use super::types::*;
-use crate::common::Real;
+use super::vec_storage::VecStorage;
+use crate::common::{Complex, Real};
 use nalgebra as na;
 use std::marker::PhantomData;
 use std::ops::{
@@ -68,10 +73,10 @@ impl<S: Shape, B: Mul<Real, Output = B>> Mul<MultiArray<Real, S, B>> for Real {
     }
 }
 
-// Scalar multiplication: MultiArray * Real  (scalar on the right)
-impl<S: Shape, B: Mul<Real, Output = B>> Mul<Real> for MultiArray<Real, S, B> {
+// Scalar multiplication: MultiArray * T  (scalar on the right)
+impl<T: Copy, S: Shape, B: Mul<T, Output = B>> Mul<T> for MultiArray<T, S, B> {
     type Output = Self;
-    fn mul(self, scalar: Real) -> Self {
+    fn mul(self, scalar: T) -> Self {
         Self {
             data: self.data * scalar,
             _phantoms: PhantomData,
@@ -116,6 +121,68 @@ impl<T: Copy, S: Shape, B: DivAssign<T>> DivAssign<T> for MultiArray<T, S, B> {
     }
 }
 
+// --- Complex arrays: complex scalar on the left, real scalars ---
+//
+// The blanket impls above only take scalars of the element type on the right.
+// These let `Vector3c` & co. be scaled like their real counterparts, which is
+// what `Field<Vector3c> *= Real` relies on.
+
+// Complex * MultiArray<Complex>
+impl<S: Shape, B: Mul<Complex, Output = B>> Mul<MultiArray<Complex, S, B>> for Complex {
+    type Output = MultiArray<Complex, S, B>;
+    fn mul(self, rhs: MultiArray<Complex, S, B>) -> MultiArray<Complex, S, B> {
+        MultiArray {
+            data: rhs.data * self,
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+// Real * MultiArray<Complex>
+impl<S: Shape, B: Mul<Complex, Output = B>> Mul<MultiArray<Complex, S, B>> for Real {
+    type Output = MultiArray<Complex, S, B>;
+    fn mul(self, rhs: MultiArray<Complex, S, B>) -> MultiArray<Complex, S, B> {
+        MultiArray {
+            data: rhs.data * Complex::from(self),
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+// MultiArray<Complex> * Real
+impl<S: Shape, B: Mul<Complex, Output = B>> Mul<Real> for MultiArray<Complex, S, B> {
+    type Output = Self;
+    fn mul(self, scalar: Real) -> Self {
+        Self {
+            data: self.data * Complex::from(scalar),
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+// MultiArray<Complex> / Real
+impl<S: Shape, B: Div<Complex, Output = B>> Div<Real> for MultiArray<Complex, S, B> {
+    type Output = Self;
+    fn div(self, scalar: Real) -> Self {
+        Self {
+            data: self.data / Complex::from(scalar),
+            _phantoms: PhantomData,
+        }
+    }
+}
+
+impl<S: Shape, B: MulAssign<Complex>> MulAssign<Real> for MultiArray<Complex, S, B> {
+    fn mul_assign(&mut self, scalar: Real) {
+        self.data *= Complex::from(scalar);
+    }
+}
+
+impl<S: Shape, B: DivAssign<Complex>> DivAssign<Real> for MultiArray<Complex, S, B> {
+    fn div_assign(&mut self, scalar: Real) {
+        self.data /= Complex::from(scalar);
+    }
+}
+
 // ============================================================================
 // std::iter::Sum
 // ============================================================================
@@ -206,13 +273,242 @@ where
     }
 }
 
+// DynMatrix * DynMatrix -> DynMatrix (runtime-checked; by value or by reference)
+// DynMatrix<T> (r x k) * DynMatrix<T> (k x c) -> DynMatrix<T> (r x c)
+impl<T> Mul<&MultiArray<T, DynRank2, na::DMatrix<T>>> for &MultiArray<T, DynRank2, na::DMatrix<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank2, na::DMatrix<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank2, na::DMatrix<T>>) -> Self::Output {
+        assert_eq!(
+            self.data.ncols(),
+            rhs.data.nrows(),
+            "DynMatrix product: lhs ncols must equal rhs nrows"
+        );
+        MultiArray::from_inner(&self.data * &rhs.data)
+    }
+}
+
+impl<T> Mul<MultiArray<T, DynRank2, na::DMatrix<T>>> for MultiArray<T, DynRank2, na::DMatrix<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank2, na::DMatrix<T>>;
+    fn mul(self, rhs: MultiArray<T, DynRank2, na::DMatrix<T>>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// DynMatrix * DynVector -> DynVector (runtime-checked; by value or by reference)
+// DynMatrix<T> (r x c) * DynVector<T> (c) -> DynVector<T> (r)
+impl<T> Mul<&MultiArray<T, DynRank1, na::DVector<T>>> for &MultiArray<T, DynRank2, na::DMatrix<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank1, na::DVector<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank1, na::DVector<T>>) -> Self::Output {
+        assert_eq!(
+            self.data.ncols(),
+            rhs.data.nrows(),
+            "DynMatrix product: vector length must equal ncols"
+        );
+        MultiArray::from_inner(&self.data * &rhs.data)
+    }
+}
+
+impl<T> Mul<MultiArray<T, DynRank1, na::DVector<T>>> for MultiArray<T, DynRank2, na::DMatrix<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank1, na::DVector<T>>;
+    fn mul(self, rhs: MultiArray<T, DynRank1, na::DVector<T>>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// DynVector * DynRowVector -> DynMatrix (outer product; runtime-checked)
+// DynVector<T> (n) * DynMatrix<T> (1 x m) -> DynMatrix<T> (n x m)
+impl<T> Mul<&MultiArray<T, DynRank2, na::DMatrix<T>>> for &MultiArray<T, DynRank1, na::DVector<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank2, na::DMatrix<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank2, na::DMatrix<T>>) -> Self::Output {
+        assert_eq!(
+            rhs.data.nrows(),
+            1,
+            "DynVector * DynMatrix: rhs must have exactly one row"
+        );
+        MultiArray::from_inner(&self.data * &rhs.data)
+    }
+}
+
+impl<T> Mul<MultiArray<T, DynRank2, na::DMatrix<T>>> for MultiArray<T, DynRank1, na::DVector<T>>
+where
+    T: na::Scalar
+        + Copy
+        + num_traits::Zero
+        + num_traits::One
+        + AddAssign
+        + MulAssign
+        + na::ClosedAddAssign
+        + na::ClosedMulAssign,
+{
+    type Output = MultiArray<T, DynRank2, na::DMatrix<T>>;
+    fn mul(self, rhs: MultiArray<T, DynRank2, na::DMatrix<T>>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+// Sparse * DynVector -> DynVector (by reference: neither operand is Copy)
+// &CsrMatrix<T> * &DynVector<T> -> DynVector<T>
+impl<T> Mul<&MultiArray<T, DynRank1, na::DVector<T>>> for &MultiArray<T, DynRank2, CsrStorage<T>>
+where
+    T: na::Scalar + Copy + num_traits::Zero + Mul<Output = T>,
+{
+    type Output = MultiArray<T, DynRank1, na::DVector<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank1, na::DVector<T>>) -> Self::Output {
+        self.mul_vec(rhs)
+    }
+}
+
+// --- Native backends (no nalgebra bounds on T) ---
+
+/// `c = a * b` for column-major `r x k` and `k x n` slices (`n = c.len() / r`).
+fn native_matmul<T: Copy + num_traits::Zero + Mul<Output = T>>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    r: usize,
+    k: usize,
+) {
+    for (c_col, b_col) in c.chunks_exact_mut(r.max(1)).zip(b.chunks_exact(k.max(1))) {
+        for (i, cij) in c_col.iter_mut().enumerate() {
+            *cij = (0..k).fold(T::zero(), |acc, p| acc + a[i + r * p] * b_col[p]);
+        }
+    }
+}
+
+// NativeMatrix<T, R, K> * NativeMatrix<T, K, C> -> NativeMatrix<T, R, C>
+impl<T, const R: usize, const K: usize, const C: usize>
+    Mul<MultiArray<T, Rank2<K, C>, ArrayStorage<T, K, C>>>
+    for MultiArray<T, Rank2<R, K>, ArrayStorage<T, R, K>>
+where
+    T: Copy + num_traits::Zero + Mul<Output = T>,
+{
+    type Output = MultiArray<T, Rank2<R, C>, ArrayStorage<T, R, C>>;
+    fn mul(self, rhs: MultiArray<T, Rank2<K, C>, ArrayStorage<T, K, C>>) -> Self::Output {
+        let mut out = ArrayStorage::splat(T::zero());
+        native_matmul(
+            self.data.as_slice(),
+            rhs.data.as_slice(),
+            out.as_mut_slice(),
+            R,
+            K,
+        );
+        MultiArray::from_inner(out)
+    }
+}
+
+// NativeMatrix<T, R, C> * NativeVector<T, C> -> NativeVector<T, R>
+impl<T, const R: usize, const C: usize> Mul<MultiArray<T, Rank1<C>, ArrayStorage<T, C, 1>>>
+    for MultiArray<T, Rank2<R, C>, ArrayStorage<T, R, C>>
+where
+    T: Copy + num_traits::Zero + Mul<Output = T>,
+{
+    type Output = MultiArray<T, Rank1<R>, ArrayStorage<T, R, 1>>;
+    fn mul(self, rhs: MultiArray<T, Rank1<C>, ArrayStorage<T, C, 1>>) -> Self::Output {
+        let mut out = ArrayStorage::splat(T::zero());
+        native_matmul(
+            self.data.as_slice(),
+            rhs.data.as_slice(),
+            out.as_mut_slice(),
+            R,
+            C,
+        );
+        MultiArray::from_inner(out)
+    }
+}
+
+// &NativeDynMatrix<T> * &NativeDynMatrix<T> -> NativeDynMatrix<T> (runtime-checked)
+impl<T> Mul<&MultiArray<T, DynRank2, VecStorage<T>>> for &MultiArray<T, DynRank2, VecStorage<T>>
+where
+    T: Copy + num_traits::Zero + Mul<Output = T>,
+{
+    type Output = MultiArray<T, DynRank2, VecStorage<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank2, VecStorage<T>>) -> Self::Output {
+        let (r, k) = (self.data.nrows, self.data.ncols);
+        assert_eq!(
+            k, rhs.data.nrows,
+            "NativeDynMatrix product: lhs ncols must equal rhs nrows"
+        );
+        let mut out = VecStorage::splat(T::zero(), r, rhs.data.ncols);
+        native_matmul(&self.data.data, &rhs.data.data, &mut out.data, r, k);
+        MultiArray::from_inner(out)
+    }
+}
+
+// &NativeDynMatrix<T> * &NativeDynVector<T> -> NativeDynVector<T> (runtime-checked)
+impl<T> Mul<&MultiArray<T, DynRank1, VecStorage<T>>> for &MultiArray<T, DynRank2, VecStorage<T>>
+where
+    T: Copy + num_traits::Zero + Mul<Output = T>,
+{
+    type Output = MultiArray<T, DynRank1, VecStorage<T>>;
+    fn mul(self, rhs: &MultiArray<T, DynRank1, VecStorage<T>>) -> Self::Output {
+        let (r, k) = (self.data.nrows, self.data.ncols);
+        assert_eq!(
+            k, rhs.data.nrows,
+            "NativeDynMatrix product: vector length must equal ncols"
+        );
+        let mut out = VecStorage::splat(T::zero(), r, 1);
+        native_matmul(&self.data.data, &rhs.data.data, &mut out.data, r, k);
+        MultiArray::from_inner(out)
+    }
+}
+
 // ============================================================================
 // Element-wise operators (Rem, bitwise)
 // ============================================================================
 //
 // nalgebra does not implement Rem or bitwise ops on its matrix types, so
 // these operate element-by-element via DenseRawStorage (same approach as
-// Hadamard in linalg.rs). Trait bounds on T naturally restrict availability:
+// Hadamard in linalg/mod.rs). Trait bounds on T naturally restrict availability:
 // Rem works for any numeric T, bitwise ops only for integer/bool types.
 
 // Element-wise binary: array OP array -> array
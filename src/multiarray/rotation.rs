@@ -0,0 +1,375 @@
+//! Rotation types: `Rotation2`, `Rotation3` and the unit `Quaternion`.
+//!
+//! Constructors, conversions (angle, axis-angle, Euler angles, rotation
+//! matrices), spherical interpolation, and the composition / application
+//! operators. The backends are nalgebra's `Rotation2`, `Rotation3` and
+//! `UnitQuaternion`, so orthonormality and unit norm hold by construction.
+//!
+//! Euler angles follow nalgebra's convention: `(roll, pitch, yaw)` about the
+//! fixed x, y and z axes, applied in that order, i.e.
+//! `R = Rz(yaw) * Ry(pitch) * Rx(roll)`.
+//!
+//! `Point3` is the same type as `Vector3`, so `r * p` rotates a point about
+//! the origin.
+
+use super::aliases::*;
+use super::types::*;
+use crate::common::Real;
+use nalgebra as na;
+use std::ops::Mul;
+
+/// Maximum entry of `|MᵀM - I|` and `|det M - 1|` accepted by `try_from_matrix`,
+/// and maximum `| |q| - 1 |` of a deserialized quaternion.
+const ORTHONORMAL_TOL: Real = 1e-9;
+
+/// Iteration cap for the nearest-rotation extraction in `from_matrix`.
+const FROM_MATRIX_MAX_ITER: usize = 100;
+
+fn is_rotation<const N: usize>(m: &na::SMatrix<Real, N, N>, det: Real) -> bool {
+    let defect = m.transpose() * m - na::SMatrix::<Real, N, N>::identity();
+    defect.amax() <= ORTHONORMAL_TOL && (det - 1.0).abs() <= ORTHONORMAL_TOL
+}
+
+/// Whether `q` has unit norm, within `ORTHONORMAL_TOL`.
+pub(super) fn is_unit(q: &na::Quaternion<Real>) -> bool {
+    (q.norm() - 1.0).abs() <= ORTHONORMAL_TOL
+}
+
+// ============================================================================
+// Rotation2
+// ============================================================================
+
+impl Rotation2 {
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn new(angle: Real) -> Self {
+        Self::from_inner(na::Rotation2::new(angle))
+    }
+
+    pub fn identity() -> Self {
+        Self::from_inner(na::Rotation2::identity())
+    }
+
+    /// Rotation angle in `(-π, π]`.
+    pub fn angle(&self) -> Real {
+        self.data.angle()
+    }
+
+    /// Signed angle of `other * self⁻¹`, in `(-π, π]`.
+    pub fn angle_to(&self, other: &Self) -> Real {
+        self.data.angle_to(&other.data)
+    }
+
+    /// Rotation closest to an arbitrary matrix (iterative polar extraction).
+    pub fn from_matrix(m: &Matrix2) -> Self {
+        Self::from_inner(na::Rotation2::from_matrix_eps(
+            m.as_inner(),
+            Real::EPSILON,
+            FROM_MATRIX_MAX_ITER,
+            na::Rotation2::identity(),
+        ))
+    }
+
+    /// Wrap `m` if it is orthonormal with determinant +1, otherwise `None`.
+    pub fn try_from_matrix(m: &Matrix2) -> Option<Self> {
+        let m = m.as_inner();
+        is_rotation(m, m.determinant())
+            .then(|| Self::from_inner(na::Rotation2::from_matrix_unchecked(*m)))
+    }
+
+    pub fn to_matrix(&self) -> Matrix2 {
+        Matrix2::from_inner(*self.data.matrix())
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::from_inner(self.data.inverse())
+    }
+
+    /// Interpolate along the shorter arc: `t = 0` gives `self`, `t = 1` gives `other`.
+    pub fn slerp(&self, other: &Self, t: Real) -> Self {
+        Self::from_inner(self.data.slerp(&other.data, t))
+    }
+
+    /// Re-orthonormalize after many compositions have accumulated round-off.
+    pub fn renormalize(&mut self) {
+        self.data.renormalize();
+    }
+}
+
+// ============================================================================
+// Rotation3
+// ============================================================================
+
+impl Rotation3 {
+    pub fn identity() -> Self {
+        Self::from_inner(na::Rotation3::identity())
+    }
+
+    /// Rotation by `angle` radians about `axis` (normalized internally).
+    /// A zero axis gives the identity.
+    pub fn from_axis_angle(axis: &Vector3, angle: Real) -> Self {
+        match na::Unit::try_new(*axis.as_inner(), 0.0) {
+            Some(axis) => Self::from_inner(na::Rotation3::from_axis_angle(&axis, angle)),
+            None => Self::identity(),
+        }
+    }
+
+    /// Rotation about `v / |v|` by `|v|` radians (rotation vector).
+    pub fn from_scaled_axis(v: &Vector3) -> Self {
+        Self::from_inner(na::Rotation3::from_scaled_axis(*v.as_inner()))
+    }
+
+    /// Unit axis and angle in `[0, π]`, or `None` for the identity.
+    pub fn axis_angle(&self) -> Option<(Vector3, Real)> {
+        self.data
+            .axis_angle()
+            .map(|(axis, angle)| (Vector3::from_inner(axis.into_inner()), angle))
+    }
+
+    /// Rotation vector `axis * angle`.
+    pub fn scaled_axis(&self) -> Vector3 {
+        Vector3::from_inner(self.data.scaled_axis())
+    }
+
+    /// Rotation angle in `[0, π]`.
+    pub fn angle(&self) -> Real {
+        self.data.angle()
+    }
+
+    /// Angle of the rotation taking `self` to `other`.
+    pub fn angle_to(&self, other: &Self) -> Real {
+        self.data.angle_to(&other.data)
+    }
+
+    /// Rotation from `(roll, pitch, yaw)` (see the module docs for the convention).
+    pub fn from_euler_angles(roll: Real, pitch: Real, yaw: Real) -> Self {
+        Self::from_inner(na::Rotation3::from_euler_angles(roll, pitch, yaw))
+    }
+
+    /// Inverse of [`from_euler_angles`](Self::from_euler_angles): `(roll, pitch, yaw)`.
+    pub fn euler_angles(&self) -> (Real, Real, Real) {
+        self.data.euler_angles()
+    }
+
+    /// Rotation closest to an arbitrary matrix (iterative polar extraction).
+    pub fn from_matrix(m: &Matrix3) -> Self {
+        Self::from_inner(na::Rotation3::from_matrix_eps(
+            m.as_inner(),
+            Real::EPSILON,
+            FROM_MATRIX_MAX_ITER,
+            na::Rotation3::identity(),
+        ))
+    }
+
+    /// Wrap `m` if it is orthonormal with determinant +1, otherwise `None`.
+    pub fn try_from_matrix(m: &Matrix3) -> Option<Self> {
+        let m = m.as_inner();
+        is_rotation(m, m.determinant())
+            .then(|| Self::from_inner(na::Rotation3::from_matrix_unchecked(*m)))
+    }
+
+    pub fn to_matrix(&self) -> Matrix3 {
+        Matrix3::from_inner(*self.data.matrix())
+    }
+
+    pub fn to_quaternion(&self) -> Quaternion {
+        Quaternion::from_inner(na::UnitQuaternion::from_rotation_matrix(&self.data))
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::from_inner(self.data.inverse())
+    }
+
+    /// Spherical linear interpolation along the shorter arc: `t = 0` gives
+    /// `self`, `t = 1` gives `other` (see [`Quaternion::slerp`]).
+    pub fn slerp(&self, other: &Self, t: Real) -> Self {
+        self.to_quaternion()
+            .slerp(&other.to_quaternion(), t)
+            .to_rotation()
+    }
+
+    /// Re-orthonormalize after many compositions have accumulated round-off.
+    pub fn renormalize(&mut self) {
+        self.data.renormalize();
+    }
+}
+
+// ============================================================================
+// Quaternion
+// ============================================================================
+
+impl Quaternion {
+    pub fn identity() -> Self {
+        Self::from_inner(na::UnitQuaternion::identity())
+    }
+
+    /// Normalize `w + xi + yj + zk`. `None` if its norm is zero.
+    pub fn try_new(w: Real, x: Real, y: Real, z: Real) -> Option<Self> {
+        na::UnitQuaternion::try_new(na::Quaternion::new(w, x, y, z), 0.0).map(Self::from_inner)
+    }
+
+    pub fn w(&self) -> Real {
+        self.data.w
+    }
+    pub fn x(&self) -> Real {
+        self.data.i
+    }
+    pub fn y(&self) -> Real {
+        self.data.j
+    }
+    pub fn z(&self) -> Real {
+        self.data.k
+    }
+
+    /// The four components in storage order `[x, y, z, w]`.
+    pub fn coords(&self) -> Vector4 {
+        Vector4::from_inner(self.data.coords)
+    }
+
+    /// Rotation by `angle` radians about `axis` (normalized internally).
+    /// A zero axis gives the identity.
+    pub fn from_axis_angle(axis: &Vector3, angle: Real) -> Self {
+        Rotation3::from_axis_angle(axis, angle).to_quaternion()
+    }
+
+    /// Rotation about `v / |v|` by `|v|` radians (rotation vector).
+    pub fn from_scaled_axis(v: &Vector3) -> Self {
+        Self::from_inner(na::UnitQuaternion::from_scaled_axis(*v.as_inner()))
+    }
+
+    /// Unit axis and angle in `[0, π]`, or `None` for the identity.
+    pub fn axis_angle(&self) -> Option<(Vector3, Real)> {
+        self.data
+            .axis_angle()
+            .map(|(axis, angle)| (Vector3::from_inner(axis.into_inner()), angle))
+    }
+
+    /// Rotation vector `axis * angle`.
+    pub fn scaled_axis(&self) -> Vector3 {
+        Vector3::from_inner(self.data.scaled_axis())
+    }
+
+    /// Rotation angle in `[0, π]`.
+    pub fn angle(&self) -> Real {
+        self.data.angle()
+    }
+
+    /// Angle of the rotation taking `self` to `other`.
+    pub fn angle_to(&self, other: &Self) -> Real {
+        self.data.angle_to(&other.data)
+    }
+
+    /// Rotation from `(roll, pitch, yaw)` (see the module docs for the convention).
+    pub fn from_euler_angles(roll: Real, pitch: Real, yaw: Real) -> Self {
+        Self::from_inner(na::UnitQuaternion::from_euler_angles(roll, pitch, yaw))
+    }
+
+    /// Inverse of [`from_euler_angles`](Self::from_euler_angles): `(roll, pitch, yaw)`.
+    pub fn euler_angles(&self) -> (Real, Real, Real) {
+        self.data.euler_angles()
+    }
+
+    /// Rotation closest to an arbitrary matrix (iterative polar extraction).
+    pub fn from_matrix(m: &Matrix3) -> Self {
+        Rotation3::from_matrix(m).to_quaternion()
+    }
+
+    /// Convert `m` if it is orthonormal with determinant +1, otherwise `None`.
+    pub fn try_from_matrix(m: &Matrix3) -> Option<Self> {
+        Rotation3::try_from_matrix(m).map(|r| r.to_quaternion())
+    }
+
+    pub fn to_matrix(&self) -> Matrix3 {
+        self.to_rotation().to_matrix()
+    }
+
+    pub fn to_rotation(&self) -> Rotation3 {
+        Rotation3::from_inner(self.data.to_rotation_matrix())
+    }
+
+    /// The conjugate, which is the inverse for a unit quaternion.
+    pub fn inverse(&self) -> Self {
+        Self::from_inner(self.data.inverse())
+    }
+
+    /// Spherical linear interpolation along the shorter arc: `t = 0` gives
+    /// `self`, `t = 1` gives `other`.
+    ///
+    /// Computed as `self * exp(t * log(self⁻¹ * other))`, which stays accurate
+    /// for nearly equal rotations. At exactly 180 degrees apart both arcs are
+    /// equally short, and the one about the axis of `self⁻¹ * other` is taken.
+    pub fn slerp(&self, other: &Self, t: Real) -> Self {
+        let delta = (self.inverse() * *other).scaled_axis();
+        *self * Self::from_scaled_axis(&(delta * t))
+    }
+
+    /// Restore unit norm, e.g. after reading components back from a solver.
+    pub fn renormalize(&mut self) {
+        self.data.renormalize();
+    }
+}
+
+// ============================================================================
+// Conversions
+// ============================================================================
+
+impl From<Quaternion> for Rotation3 {
+    fn from(q: Quaternion) -> Self {
+        q.to_rotation()
+    }
+}
+
+impl From<Rotation3> for Quaternion {
+    fn from(r: Rotation3) -> Self {
+        r.to_quaternion()
+    }
+}
+
+impl From<Rotation2> for Matrix2 {
+    fn from(r: Rotation2) -> Self {
+        r.to_matrix()
+    }
+}
+
+impl From<Rotation3> for Matrix3 {
+    fn from(r: Rotation3) -> Self {
+        r.to_matrix()
+    }
+}
+
+impl From<Quaternion> for Matrix3 {
+    fn from(q: Quaternion) -> Self {
+        q.to_matrix()
+    }
+}
+
+// ============================================================================
+// Composition and application
+// ============================================================================
+
+// Example expansion -- impl_rotation_mul!(Rotation3, Vector3 => Vector3):
+//
+//   impl Mul<Vector3> for Rotation3 {
+//       type Output = Vector3;
+//       fn mul(self, rhs: Vector3) -> Vector3 { MultiArray::from_inner(self.data * rhs.data) }
+//   }
+macro_rules! impl_rotation_mul {
+    ($($Lhs:ty, $Rhs:ty => $Out:ty);+ $(;)?) => {
+        $(
+            impl Mul<$Rhs> for $Lhs {
+                type Output = $Out;
+                fn mul(self, rhs: $Rhs) -> $Out {
+                    MultiArray::from_inner(self.data * rhs.data)
+                }
+            }
+        )+
+    };
+}
+
+impl_rotation_mul!(
+    Rotation2, Rotation2 => Rotation2;
+    Rotation2, Vector2 => Vector2;
+    Rotation3, Rotation3 => Rotation3;
+    Rotation3, Vector3 => Vector3;
+    Quaternion, Quaternion => Quaternion;
+    Quaternion, Vector3 => Vector3;
+);
@@ -0,0 +1,346 @@
+//! Sparse matrix backends for large assembled systems.
+//!
+//! - [`CooStorage`]: coordinate (triplet) format. Cheap to append to, so it is
+//!   used as the assembly builder; duplicate entries are allowed and summed on
+//!   conversion.
+//! - [`CsrStorage`]: compressed sparse row format. Column indices are sorted
+//!   and unique within each row. Used for products and solvers.
+//!
+//! Both implement `RawStorage` (with `len` = logical `nrows * ncols`) and
+//! `SparseRawStorage`, but not `DenseRawStorage`: there is no contiguous slice
+//! of all elements. The stored values are exposed through the alias methods
+//! in `aliases.rs` instead.
+
+use super::types::*;
+use std::ops::{AddAssign, Mul};
+
+/// Coordinate-format (triplet) backend for `CooMatrix<T>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CooParts<T>")
+)]
+pub struct CooStorage<T> {
+    pub(crate) nrows: usize,
+    pub(crate) ncols: usize,
+    pub(crate) rows: Vec<usize>,
+    pub(crate) cols: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+/// Compressed sparse row backend for `CsrMatrix<T>`.
+///
+/// Row `i` holds the entries `row_offsets[i]..row_offsets[i + 1]` of
+/// `col_indices` / `values`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "CsrParts<T>")
+)]
+pub struct CsrStorage<T> {
+    pub(crate) nrows: usize,
+    pub(crate) ncols: usize,
+    pub(crate) row_offsets: Vec<usize>,
+    pub(crate) col_indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+// ============================================================================
+// Storage traits
+// ============================================================================
+
+impl<T> RawStorage<T> for CooStorage<T> {
+    fn len(&self) -> usize {
+        self.nrows * self.ncols
+    }
+    fn matrix_dims(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+}
+
+impl<T> SparseRawStorage<T> for CooStorage<T> {
+    fn nnz(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T> RawStorage<T> for CsrStorage<T> {
+    fn len(&self) -> usize {
+        self.nrows * self.ncols
+    }
+    fn matrix_dims(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+}
+
+impl<T> SparseRawStorage<T> for CsrStorage<T> {
+    fn nnz(&self) -> usize {
+        self.values.len()
+    }
+}
+
+// ============================================================================
+// COO
+// ============================================================================
+
+impl<T> CooStorage<T> {
+    pub(crate) fn new(nrows: usize, ncols: usize) -> Self {
+        Self {
+            nrows,
+            ncols,
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, row: usize, col: usize, value: T) {
+        assert!(
+            row < self.nrows && col < self.ncols,
+            "CooMatrix::push: entry ({row}, {col}) out of bounds for {}x{} matrix",
+            self.nrows,
+            self.ncols
+        );
+        self.rows.push(row);
+        self.cols.push(col);
+        self.values.push(value);
+    }
+}
+
+// ============================================================================
+// CSR
+// ============================================================================
+
+impl<T: Copy + AddAssign> CsrStorage<T> {
+    /// Compress triplets: bucket by row, then sort each row by column and sum
+    /// duplicates. Explicit zeros are kept as structural entries.
+    pub(crate) fn from_coo(coo: &CooStorage<T>) -> Self {
+        let nrows = coo.nrows;
+
+        // Bucket entry positions by row (counting sort).
+        let mut bucket_offsets = vec![0usize; nrows + 1];
+        for &r in &coo.rows {
+            bucket_offsets[r + 1] += 1;
+        }
+        for r in 0..nrows {
+            bucket_offsets[r + 1] += bucket_offsets[r];
+        }
+        let mut next = bucket_offsets.clone();
+        let mut order = vec![0usize; coo.values.len()];
+        for (e, &r) in coo.rows.iter().enumerate() {
+            order[next[r]] = e;
+            next[r] += 1;
+        }
+
+        let mut row_offsets = Vec::with_capacity(nrows + 1);
+        let mut col_indices = Vec::with_capacity(coo.values.len());
+        let mut values = Vec::with_capacity(coo.values.len());
+        row_offsets.push(0);
+
+        let mut row: Vec<(usize, T)> = Vec::new();
+        for r in 0..nrows {
+            row.clear();
+            row.extend(
+                order[bucket_offsets[r]..bucket_offsets[r + 1]]
+                    .iter()
+                    .map(|&e| (coo.cols[e], coo.values[e])),
+            );
+            row.sort_by_key(|&(c, _)| c);
+
+            let row_start = col_indices.len();
+            for &(c, v) in &row {
+                if col_indices.len() > row_start && col_indices.last() == Some(&c) {
+                    *values.last_mut().unwrap() += v;
+                } else {
+                    col_indices.push(c);
+                    values.push(v);
+                }
+            }
+            row_offsets.push(col_indices.len());
+        }
+
+        Self {
+            nrows,
+            ncols: coo.ncols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T: Copy> CsrStorage<T> {
+    /// Transpose by bucketing entries by column. Rows are visited in order,
+    /// so the column indices of the result come out sorted.
+    pub(crate) fn transpose(&self) -> Self {
+        let mut row_offsets = vec![0usize; self.ncols + 1];
+        for &c in &self.col_indices {
+            row_offsets[c + 1] += 1;
+        }
+        for c in 0..self.ncols {
+            row_offsets[c + 1] += row_offsets[c];
+        }
+
+        let mut next = row_offsets.clone();
+        let mut col_indices = vec![0usize; self.values.len()];
+        let mut values = self.values.clone();
+        for r in 0..self.nrows {
+            for e in self.row_offsets[r]..self.row_offsets[r + 1] {
+                let c = self.col_indices[e];
+                col_indices[next[c]] = r;
+                values[next[c]] = self.values[e];
+                next[c] += 1;
+            }
+        }
+
+        Self {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T: Copy + num_traits::Zero + Mul<Output = T>> CsrStorage<T> {
+    /// `y = A x` on flat slices.
+    pub(crate) fn mul_vec_into(&self, x: &[T], y: &mut [T]) {
+        assert_eq!(
+            x.len(),
+            self.ncols,
+            "CsrMatrix product: vector length must equal ncols"
+        );
+        assert_eq!(
+            y.len(),
+            self.nrows,
+            "CsrMatrix product: output length must equal nrows"
+        );
+        for (r, yr) in y.iter_mut().enumerate() {
+            let range = self.row_offsets[r]..self.row_offsets[r + 1];
+            *yr = self.col_indices[range.clone()]
+                .iter()
+                .zip(&self.values[range])
+                .fold(T::zero(), |acc, (&c, &v)| acc + v * x[c]);
+        }
+    }
+}
+
+// ============================================================================
+// Serde
+// ============================================================================
+//
+// Deserialization goes through the raw parts, which are checked against the
+// invariants the products and conversions rely on.
+
+/// Rejects dimensions whose dense length `nrows * ncols` overflows `usize`.
+#[cfg(feature = "serde")]
+fn check_dense_len(name: &str, nrows: usize, ncols: usize) -> Result<(), String> {
+    match nrows.checked_mul(ncols) {
+        Some(_) => Ok(()),
+        None => Err(format!("{name}: {nrows}x{ncols} overflows usize")),
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CooParts<T> {
+    nrows: usize,
+    ncols: usize,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<CooParts<T>> for CooStorage<T> {
+    type Error = String;
+    fn try_from(p: CooParts<T>) -> Result<Self, String> {
+        check_dense_len("CooStorage", p.nrows, p.ncols)?;
+        if p.rows.len() != p.values.len() || p.cols.len() != p.values.len() {
+            return Err(format!(
+                "CooStorage: {} rows and {} cols for {} values",
+                p.rows.len(),
+                p.cols.len(),
+                p.values.len()
+            ));
+        }
+        if let Some((r, c)) = p
+            .rows
+            .iter()
+            .zip(&p.cols)
+            .find(|&(&r, &c)| r >= p.nrows || c >= p.ncols)
+        {
+            return Err(format!(
+                "CooStorage: entry ({r}, {c}) out of bounds for {}x{} matrix",
+                p.nrows, p.ncols
+            ));
+        }
+        Ok(Self {
+            nrows: p.nrows,
+            ncols: p.ncols,
+            rows: p.rows,
+            cols: p.cols,
+            values: p.values,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CsrParts<T> {
+    nrows: usize,
+    ncols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<CsrParts<T>> for CsrStorage<T> {
+    type Error = String;
+    fn try_from(p: CsrParts<T>) -> Result<Self, String> {
+        check_dense_len("CsrStorage", p.nrows, p.ncols)?;
+        let n_offsets = p
+            .nrows
+            .checked_add(1)
+            .ok_or_else(|| format!("CsrStorage: {} rows overflow usize", p.nrows))?;
+        if p.row_offsets.len() != n_offsets {
+            return Err(format!(
+                "CsrStorage: expected {n_offsets} row offsets, found {}",
+                p.row_offsets.len()
+            ));
+        }
+        if p.col_indices.len() != p.values.len() {
+            return Err(format!(
+                "CsrStorage: {} column indices for {} values",
+                p.col_indices.len(),
+                p.values.len()
+            ));
+        }
+        if p.row_offsets[0] != 0
+            || p.row_offsets[p.nrows] != p.values.len()
+            || p.row_offsets.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err("CsrStorage: row offsets must rise from 0 to nnz".to_string());
+        }
+        for (r, w) in p.row_offsets.windows(2).enumerate() {
+            let cols = &p.col_indices[w[0]..w[1]];
+            if cols.windows(2).any(|c| c[0] >= c[1]) || cols.last() >= Some(&p.ncols) {
+                return Err(format!(
+                    "CsrStorage: column indices of row {r} must be sorted, unique and below {}",
+                    p.ncols
+                ));
+            }
+        }
+        Ok(Self {
+            nrows: p.nrows,
+            ncols: p.ncols,
+            row_offsets: p.row_offsets,
+            col_indices: p.col_indices,
+            values: p.values,
+        })
+    }
+}
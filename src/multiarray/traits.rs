@@ -1,10 +1,15 @@
 //! Stable API traits and their implementations for `MultiArray`.
 //!
-//! Defines `MultiArrayOps`, `DenseMultiArrayOps`, `NumericMultiArrayOps` and
-//! implements them for `MultiArray` under the appropriate trait bounds.
-//! Also provides `Index` and `IndexMut` for element access.
+//! Defines `MultiArrayOps`, `DenseMultiArrayOps`, `SparseMultiArrayOps`,
+//! `NumericMultiArrayOps` and implements them for `MultiArray` under the appropriate trait bounds.
+//! Also provides `Index` and `IndexMut` for element access (flat, plus
+//! multi-index access for `Tensor3` / `Tensor4` / `SymMatrix3` and the native
+//! matrix backends).
 
+use super::array_storage::*;
 use super::types::*;
+use super::vec_storage::VecStorage;
+use nalgebra as na;
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
 };
@@ -36,6 +41,15 @@ pub trait DenseMultiArrayOps<T>: MultiArrayOps<T> {
     fn as_mut_slice(&mut self) -> &mut [T];
 }
 
+/// Sparse specialisation: only explicitly stored entries occupy memory.
+///
+/// Implemented by `MultiArray` when the backend is sparse (`CooMatrix`,
+/// `CsrMatrix`). `len()` still reports the logical element count.
+pub trait SparseMultiArrayOps<T>: MultiArrayOps<T> {
+    /// Number of stored entries.
+    fn nnz(&self) -> usize;
+}
+
 /// Stable API for numeric multi-dimensional arrays.
 ///
 /// Pure operator bundle -- guarantees arithmetic support.
@@ -88,6 +102,15 @@ impl<T: Clone, S: Shape, B: DenseRawStorage<T> + Clone> DenseMultiArrayOps<T>
     }
 }
 
+// SparseMultiArrayOps -- implemented ONLY when B is a sparse backend
+impl<T: Clone, S: Shape, B: SparseRawStorage<T> + Clone> SparseMultiArrayOps<T>
+    for MultiArray<T, S, B>
+{
+    fn nnz(&self) -> usize {
+        self.data.nnz()
+    }
+}
+
 // NumericMultiArrayOps -- implemented ONLY when T is numeric and all operator
 // bounds are satisfied. This is a marker trait (no methods).
 impl<T, S: Shape, B: RawStorage<T> + Clone> NumericMultiArrayOps<T> for MultiArray<T, S, B>
@@ -134,3 +157,111 @@ impl<T, S, B: DenseRawStorage<T>> IndexMut<usize> for MultiArray<T, S, B> {
         &mut self.data.as_mut_slice()[i]
     }
 }
+
+// Multi-index access for higher-rank tensors: t[(i, j, k)], t[(i, j, k, l)].
+// Storage is first-index-fastest, matching nalgebra's column-major matrices.
+
+impl<T, const D1: usize, const D2: usize, const D3: usize> Index<(usize, usize, usize)>
+    for MultiArray<T, Rank3<D1, D2, D3>, TensorStorage3<T, D1, D2, D3>>
+{
+    type Output = T;
+    fn index(&self, (i, j, k): (usize, usize, usize)) -> &T {
+        &self.data.0[k][j][i]
+    }
+}
+
+impl<T, const D1: usize, const D2: usize, const D3: usize> IndexMut<(usize, usize, usize)>
+    for MultiArray<T, Rank3<D1, D2, D3>, TensorStorage3<T, D1, D2, D3>>
+{
+    fn index_mut(&mut self, (i, j, k): (usize, usize, usize)) -> &mut T {
+        &mut self.data.0[k][j][i]
+    }
+}
+
+impl<T, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    Index<(usize, usize, usize, usize)>
+    for MultiArray<T, Rank4<D1, D2, D3, D4>, TensorStorage4<T, D1, D2, D3, D4>>
+{
+    type Output = T;
+    fn index(&self, (i, j, k, l): (usize, usize, usize, usize)) -> &T {
+        &self.data.0[l][k][j][i]
+    }
+}
+
+impl<T, const D1: usize, const D2: usize, const D3: usize, const D4: usize>
+    IndexMut<(usize, usize, usize, usize)>
+    for MultiArray<T, Rank4<D1, D2, D3, D4>, TensorStorage4<T, D1, D2, D3, D4>>
+{
+    fn index_mut(&mut self, (i, j, k, l): (usize, usize, usize, usize)) -> &mut T {
+        &mut self.data.0[l][k][j][i]
+    }
+}
+
+// Symmetric (row, col) access: m[(i, j)] and m[(j, i)] refer to the same
+// packed component, stored in Voigt order [xx, yy, zz, yz, xz, xy].
+
+/// Packed Voigt position of component `(i, j)` of a symmetric 3x3 matrix.
+pub(crate) const fn voigt_index(i: usize, j: usize) -> usize {
+    match (i, j) {
+        (0, 0) => 0,
+        (1, 1) => 1,
+        (2, 2) => 2,
+        (1, 2) | (2, 1) => 3,
+        (0, 2) | (2, 0) => 4,
+        (0, 1) | (1, 0) => 5,
+        _ => panic!("SymMatrix3 index out of bounds"),
+    }
+}
+
+impl<T: na::Scalar> Index<(usize, usize)> for MultiArray<T, SymRank2<3>, na::SVector<T, 6>> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[voigt_index(i, j)]
+    }
+}
+
+impl<T: na::Scalar> IndexMut<(usize, usize)> for MultiArray<T, SymRank2<3>, na::SVector<T, 6>> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[voigt_index(i, j)]
+    }
+}
+
+// (row, col) access for the native backends, column-major like nalgebra.
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)>
+    for MultiArray<T, Rank2<R, C>, ArrayStorage<T, R, C>>
+{
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data.0[j][i]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)>
+    for MultiArray<T, Rank2<R, C>, ArrayStorage<T, R, C>>
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data.0[j][i]
+    }
+}
+
+impl<T> Index<(usize, usize)> for MultiArray<T, DynRank2, VecStorage<T>> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(
+            i < self.data.nrows && j < self.data.ncols,
+            "NativeDynMatrix index ({i}, {j}) out of bounds"
+        );
+        &self.data.data[i + self.data.nrows * j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MultiArray<T, DynRank2, VecStorage<T>> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            i < self.data.nrows && j < self.data.ncols,
+            "NativeDynMatrix index ({i}, {j}) out of bounds"
+        );
+        &mut self.data.data[i + self.data.nrows * j]
+    }
+}
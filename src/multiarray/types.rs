@@ -1,10 +1,15 @@
 //! Core `MultiArray` struct, shape types, and backend storage traits.
 //!
 //! This file defines the foundational building blocks:
-//! - `Shape` trait and concrete shape types (`Rank1`, `Rank2`, `DynRank1`, `DynRank2`)
-//! - `RawStorage` / `DenseRawStorage` backend traits + nalgebra implementations
+//! - `Shape` trait and concrete shape types (`Rank1`, `Rank2`, `Rank3`, `Rank4`,
+//!   `SymRank2`, `RotRank2`, `QuatRank1`, `DynRank1`, `DynRank2`)
+//! - `RawStorage` / `DenseRawStorage` / `MapStorage` / `SparseRawStorage`
+//!   backend traits + nalgebra implementations
 //! - `MultiArray<T, S, B>` struct with inherent escape-hatch methods
 
+use super::aliases::{Matrix2, Matrix3, Rotation2, Rotation3};
+use super::rotation::is_unit;
+use crate::common::Real;
 use nalgebra as na;
 use std::marker::PhantomData;
 
@@ -39,6 +44,49 @@ impl<const R: usize, const C: usize> Shape for Rank2<R, C> {
     const SIZE: Option<usize> = Some(R * C);
 }
 
+/// Shape for a static rank-3 array of dimensions `D1` x `D2` x `D3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rank3<const D1: usize, const D2: usize, const D3: usize>;
+impl<const D1: usize, const D2: usize, const D3: usize> Shape for Rank3<D1, D2, D3> {
+    const RANK: usize = 3;
+    const SIZE: Option<usize> = Some(D1 * D2 * D3);
+}
+
+/// Shape for a static rank-4 array of dimensions `D1` x `D2` x `D3` x `D4`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rank4<const D1: usize, const D2: usize, const D3: usize, const D4: usize>;
+impl<const D1: usize, const D2: usize, const D3: usize, const D4: usize> Shape
+    for Rank4<D1, D2, D3, D4>
+{
+    const RANK: usize = 4;
+    const SIZE: Option<usize> = Some(D1 * D2 * D3 * D4);
+}
+
+/// Shape for a static symmetric `N` x `N` matrix, stored as its
+/// `N * (N + 1) / 2` independent components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SymRank2<const N: usize>;
+impl<const N: usize> Shape for SymRank2<N> {
+    const RANK: usize = 2;
+    const SIZE: Option<usize> = Some(N * (N + 1) / 2);
+}
+
+/// Shape for a static `N` x `N` rotation matrix (orthonormal, determinant +1).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotRank2<const N: usize>;
+impl<const N: usize> Shape for RotRank2<N> {
+    const RANK: usize = 2;
+    const SIZE: Option<usize> = Some(N * N);
+}
+
+/// Shape for a unit quaternion, stored as its 4 components `[x, y, z, w]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuatRank1;
+impl Shape for QuatRank1 {
+    const RANK: usize = 1;
+    const SIZE: Option<usize> = Some(4);
+}
+
 /// Shape for a dynamic rank-1 array (vector with runtime length).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct DynRank1;
@@ -65,6 +113,17 @@ pub trait RawStorage<T> {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// `(nrows, ncols)` of the backend. Backends without a row/column layout
+    /// report `(len, 1)`.
+    fn matrix_dims(&self) -> (usize, usize) {
+        (self.len(), 1)
+    }
+    /// Checks invariants beyond the dimensions (orthonormality, unit norm).
+    /// Only backends that are not valid by construction override this; it
+    /// runs on deserialization.
+    fn check_invariants(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// Dense backend: contiguous memory, supports slice access.
@@ -73,6 +132,21 @@ pub trait DenseRawStorage<T>: RawStorage<T> {
     fn as_mut_slice(&mut self) -> &mut [T];
 }
 
+/// Dense backend that can be rebuilt with another element type and the same
+/// dimensions. Used for element-wise maps and boolean masks.
+pub trait MapStorage<T>: DenseRawStorage<T> {
+    /// This backend holding `U` instead of `T`.
+    type Mapped<U: na::Scalar + Copy>: DenseRawStorage<U>;
+    /// Applies `f` to every element, in storage order.
+    fn map_storage<U: na::Scalar + Copy>(&self, f: impl FnMut(T) -> U) -> Self::Mapped<U>;
+}
+
+/// Sparse backend: only explicitly stored entries occupy memory.
+pub trait SparseRawStorage<T>: RawStorage<T> {
+    /// Number of stored entries.
+    fn nnz(&self) -> usize;
+}
+
 // --- RawStorage impls for nalgebra types ---
 
 impl<T, R, C, S> RawStorage<T> for na::Matrix<T, R, C, S>
@@ -85,6 +159,9 @@ where
     fn len(&self) -> usize {
         self.nrows() * self.ncols()
     }
+    fn matrix_dims(&self) -> (usize, usize) {
+        self.shape()
+    }
 }
 
 impl<T, R, C, St> DenseRawStorage<T> for na::Matrix<T, R, C, St>
@@ -102,6 +179,65 @@ where
     }
 }
 
+impl<T, R, C> MapStorage<T> for na::OMatrix<T, R, C>
+where
+    T: na::Scalar,
+    R: na::Dim,
+    C: na::Dim,
+    na::DefaultAllocator: na::allocator::Allocator<R, C>,
+{
+    type Mapped<U: na::Scalar + Copy> = na::OMatrix<U, R, C>;
+    fn map_storage<U: na::Scalar + Copy>(&self, f: impl FnMut(T) -> U) -> Self::Mapped<U> {
+        self.map(f)
+    }
+}
+
+// Rotation backends keep their invariants (orthonormality, unit norm), so they
+// expose no mutable slice and implement only `RawStorage`. Deserialized data
+// bypasses the constructors and is checked with the same tolerance as
+// `try_from_matrix`.
+
+macro_rules! impl_raw_storage_rotation {
+    ($($d:literal => $Rot:ident, $Mat:ident);*) => {$(
+        impl RawStorage<Real> for na::Rotation<Real, $d> {
+            fn len(&self) -> usize {
+                $d * $d
+            }
+            fn matrix_dims(&self) -> (usize, usize) {
+                ($d, $d)
+            }
+            fn check_invariants(&self) -> Result<(), String> {
+                match $Rot::try_from_matrix(&$Mat::from_inner(*self.matrix())) {
+                    Some(_) => Ok(()),
+                    None => Err(concat!(
+                        stringify!($Rot),
+                        ": matrix is not orthonormal with determinant +1"
+                    )
+                    .to_string()),
+                }
+            }
+        }
+    )*};
+}
+
+impl_raw_storage_rotation!(2 => Rotation2, Matrix2; 3 => Rotation3, Matrix3);
+
+impl RawStorage<Real> for na::UnitQuaternion<Real> {
+    fn len(&self) -> usize {
+        4
+    }
+    fn check_invariants(&self) -> Result<(), String> {
+        if is_unit(self.quaternion()) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Quaternion: norm {} is not 1",
+                self.quaternion().norm()
+            ))
+        }
+    }
+}
+
 // ============================================================================
 // The core struct
 // ============================================================================
@@ -117,11 +253,35 @@ where
 /// rather than this struct directly.
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct MultiArray<T, S, B> {
     pub(crate) data: B,
     pub(crate) _phantoms: PhantomData<(T, S)>,
 }
 
+// Deserialized transparently from the backend. Backends check their own
+// dimensions; a rank-1 shape additionally needs a single column, which a
+// dynamic backend such as `VecStorage` cannot know by itself, and rotation
+// backends must pass `check_invariants`.
+#[cfg(feature = "serde")]
+impl<'de, T, S, B> serde::Deserialize<'de> for MultiArray<T, S, B>
+where
+    S: Shape,
+    B: serde::Deserialize<'de> + RawStorage<T>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let data = B::deserialize(deserializer)?;
+        let (nrows, ncols) = data.matrix_dims();
+        if S::RANK == 1 && ncols != 1 {
+            return Err(serde::de::Error::custom(format!(
+                "MultiArray: a vector needs a single column, found {nrows}x{ncols}"
+            )));
+        }
+        data.check_invariants().map_err(serde::de::Error::custom)?;
+        Ok(Self::from_inner(data))
+    }
+}
+
 // ============================================================================
 // Inherent methods (escape hatches + convenience)
 // ============================================================================
@@ -0,0 +1,206 @@
+//! Heap-allocated contiguous backend for dynamic shapes.
+//!
+//! [`VecStorage`] is the dynamic counterpart of [`ArrayStorage`](super::ArrayStorage):
+//! a column-major `Vec<T>` plus its dimensions, with no nalgebra bounds on `T`.
+//! Vectors are stored as `n x 1`.
+//!
+//! Like `na::DVector`, the element-wise `std::ops` impls check dimensions at
+//! runtime and panic on a mismatch. There is no `num_traits::Zero` impl: a
+//! dynamic zero has no size, so `std::iter::Sum` is not available.
+
+use super::types::*;
+use nalgebra as na;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Backend for `NativeDynVector<T>` and `NativeDynMatrix<T>`: a column-major
+/// `Vec<T>` of length `nrows * ncols`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VecStorage<T> {
+    pub(crate) data: Vec<T>,
+    pub(crate) nrows: usize,
+    pub(crate) ncols: usize,
+}
+
+impl<T> VecStorage<T> {
+    /// Wrap a column-major `Vec`. Panics if `data.len() != nrows * ncols`.
+    pub fn from_vec(data: Vec<T>, nrows: usize, ncols: usize) -> Self {
+        assert_eq!(
+            data.len(),
+            nrows * ncols,
+            "VecStorage: data length must equal nrows * ncols"
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// Unwrap into the column-major `Vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    fn check_dims(&self, rhs: &Self) {
+        assert_eq!(
+            (self.nrows, self.ncols),
+            (rhs.nrows, rhs.ncols),
+            "VecStorage: dimension mismatch"
+        );
+    }
+}
+
+impl<T: Copy> VecStorage<T> {
+    /// `nrows x ncols` storage with every element set to `value`.
+    pub fn splat(value: T, nrows: usize, ncols: usize) -> Self {
+        Self {
+            data: vec![value; nrows * ncols],
+            nrows,
+            ncols,
+        }
+    }
+}
+
+// ============================================================================
+// Storage traits
+// ============================================================================
+
+impl<T> RawStorage<T> for VecStorage<T> {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+    fn matrix_dims(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+}
+
+impl<T> DenseRawStorage<T> for VecStorage<T> {
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T: Copy> MapStorage<T> for VecStorage<T> {
+    type Mapped<U: na::Scalar + Copy> = VecStorage<U>;
+    fn map_storage<U: na::Scalar + Copy>(&self, f: impl FnMut(T) -> U) -> VecStorage<U> {
+        VecStorage {
+            data: self.data.iter().copied().map(f).collect(),
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+}
+
+// Serialized as `(data, nrows, ncols)` like nalgebra's dynamic storage; the
+// data length is checked against the dimensions on deserialization.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for VecStorage<T> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        (&self.data, self.nrows, self.ncols).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for VecStorage<T> {
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let (data, nrows, ncols): (Vec<T>, usize, usize) =
+            serde::Deserialize::deserialize(deserializer)?;
+        let len = nrows.checked_mul(ncols).ok_or_else(|| {
+            serde::de::Error::custom(format!("VecStorage: {nrows}x{ncols} overflows usize"))
+        })?;
+        if data.len() != len {
+            return Err(serde::de::Error::custom(format!(
+                "VecStorage: expected {len} components for {nrows}x{ncols}, found {}",
+                data.len()
+            )));
+        }
+        Ok(Self { data, nrows, ncols })
+    }
+}
+
+// ============================================================================
+// Element-wise std::ops
+// ============================================================================
+
+impl<T: Copy + Add<Output = T>> Add for VecStorage<T> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Sub for VecStorage<T> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Neg for VecStorage<T> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for a in &mut self.data {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for VecStorage<T> {
+    type Output = Self;
+    fn mul(mut self, scalar: T) -> Self {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for VecStorage<T> {
+    type Output = Self;
+    fn div(mut self, scalar: T) -> Self {
+        self /= scalar;
+        self
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for VecStorage<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.check_dims(&rhs);
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for VecStorage<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.check_dims(&rhs);
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> MulAssign<T> for VecStorage<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        for a in &mut self.data {
+            *a = *a * scalar;
+        }
+    }
+}
+
+impl<T: Copy + Div<Output = T>> DivAssign<T> for VecStorage<T> {
+    fn div_assign(&mut self, scalar: T) {
+        for a in &mut self.data {
+            *a = *a / scalar;
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Borrowed strided backends for non-owning views.
+//!
+//! [`StridedStorage`] and [`StridedStorageMut`] borrow part of another
+//! array's buffer: element `(i, j)` of the view lives at
+//! `i * row_stride + j * col_stride` past the view's first element. A column,
+//! a row (stride `nrows` through a column-major buffer) or a sub-block of a
+//! matrix can therefore be used as a `MultiArray` without copying.
+//!
+//! Both wrap nalgebra matrix views with runtime strides. They are not
+//! contiguous, so they implement `RawStorage` only: no `as_slice`, and the
+//! element-wise operators live in `views.rs` instead of the blanket impls.
+
+use super::types::*;
+use nalgebra as na;
+
+/// Read-only borrowed backend for `VectorView`, `MatrixView`,
+/// `DynVectorView` and `DynMatrixView` (`Copy`).
+pub struct StridedStorage<'a, T, R: na::Dim, C: na::Dim>(
+    pub(crate) na::MatrixView<'a, T, R, C, na::Dyn, na::Dyn>,
+);
+
+/// Mutable borrowed backend for `VectorViewMut`, `MatrixViewMut`,
+/// `DynVectorViewMut` and `DynMatrixViewMut`.
+pub struct StridedStorageMut<'a, T, R: na::Dim, C: na::Dim>(
+    pub(crate) na::MatrixViewMut<'a, T, R, C, na::Dyn, na::Dyn>,
+);
+
+impl<'a, T: na::Scalar, R: na::Dim, C: na::Dim> StridedStorage<'a, T, R, C> {
+    /// View of `nrows x ncols` elements of `data` starting at `offset`.
+    /// Panics if the view does not fit in `data`. An empty view may start
+    /// past the end (e.g. a `0x0` block at the bottom-right corner).
+    pub(crate) fn new(
+        data: &'a [T],
+        offset: usize,
+        (nrows, ncols): (R, C),
+        (row_stride, col_stride): (usize, usize),
+    ) -> Self {
+        let offset = offset.min(data.len());
+        Self(na::MatrixView::from_slice_with_strides_generic(
+            &data[offset..],
+            nrows,
+            ncols,
+            na::Dyn(row_stride),
+            na::Dyn(col_stride),
+        ))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.0.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.0.ncols()
+    }
+}
+
+impl<'a, T: na::Scalar, R: na::Dim, C: na::Dim> StridedStorageMut<'a, T, R, C> {
+    /// Mutable view of `nrows x ncols` elements of `data` starting at
+    /// `offset`. Panics if the view does not fit in `data` or if the strides
+    /// alias two elements. An empty view may start past the end.
+    pub(crate) fn new(
+        data: &'a mut [T],
+        offset: usize,
+        (nrows, ncols): (R, C),
+        (row_stride, col_stride): (usize, usize),
+    ) -> Self {
+        let offset = offset.min(data.len());
+        Self(na::MatrixViewMut::from_slice_with_strides_generic(
+            &mut data[offset..],
+            nrows,
+            ncols,
+            na::Dyn(row_stride),
+            na::Dyn(col_stride),
+        ))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.0.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.0.ncols()
+    }
+
+    /// Read-only reborrow.
+    pub fn as_view(&self) -> StridedStorage<'_, T, R, C> {
+        StridedStorage(self.0.as_view())
+    }
+}
+
+// Manual impls: derives would miss the `na::Scalar` bound nalgebra needs.
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> Clone for StridedStorage<'_, T, R, C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: na::Scalar + Copy, R: na::Dim, C: na::Dim> Copy for StridedStorage<'_, T, R, C> {}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> std::fmt::Debug for StridedStorage<'_, T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StridedStorage").field(&self.0).finish()
+    }
+}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> std::fmt::Debug for StridedStorageMut<'_, T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StridedStorageMut").field(&self.0).finish()
+    }
+}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> PartialEq for StridedStorage<'_, T, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> PartialEq for StridedStorageMut<'_, T, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> RawStorage<T> for StridedStorage<'_, T, R, C> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn matrix_dims(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+}
+
+impl<T: na::Scalar, R: na::Dim, C: na::Dim> RawStorage<T> for StridedStorageMut<'_, T, R, C> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn matrix_dims(&self) -> (usize, usize) {
+        self.0.shape()
+    }
+}
+
+/// Shapes that have views: the nalgebra row and column dimensions of a shape
+/// (vectors are `N x 1`).
+pub trait ViewShape: Shape {
+    type R: na::Dim;
+    type C: na::Dim;
+}
+
+impl<const N: usize> ViewShape for Rank1<N> {
+    type R = na::Const<N>;
+    type C = na::U1;
+}
+
+impl<const R: usize, const C: usize> ViewShape for Rank2<R, C> {
+    type R = na::Const<R>;
+    type C = na::Const<C>;
+}
+
+impl ViewShape for DynRank1 {
+    type R = na::Dyn;
+    type C = na::U1;
+}
+
+impl ViewShape for DynRank2 {
+    type R = na::Dyn;
+    type C = na::Dyn;
+}
@@ -0,0 +1,755 @@
+//! Non-owning views: columns, rows and sub-blocks of matrices.
+//!
+//! `Matrix::column`, `Matrix::row`, `Matrix::block` and the `DynMatrix`
+//! equivalents (`rows`, `columns`, `view`) borrow part of the matrix as a
+//! `VectorView` / `MatrixView` / `DynVectorView` / `DynMatrixView` instead of
+//! copying it. Views are `MultiArray`s whose backend is a borrowed strided
+//! slice (see `view_storage.rs`), so they support indexing, the arithmetic
+//! operators and `VectorOps`. Results of arithmetic are owned arrays of the
+//! same shape; the `_mut` views also support the compound-assignment
+//! operators, which write through to the parent.
+//!
+//! ```
+//! use strelitzia::multiarray::{Matrix3, Vector3, linalg::VectorOps};
+//!
+//! let mut m = Matrix3::identity();
+//! assert_eq!(m.column(1) * 2.0, Vector3::new(0.0, 2.0, 0.0));
+//! assert_eq!(m.row(0).dot(&m.row(1)), 0.0);
+//!
+//! let mut c = m.column_mut(2);
+//! c += Vector3::new(1.0, 1.0, 0.0);
+//! assert_eq!(m.column(2), Vector3::new(1.0, 1.0, 1.0));
+//! ```
+//!
+//! Rows are strided views through the column-major buffer, returned as
+//! (column) vector views. Constructors panic if the requested view does not
+//! fit in the parent.
+
+use super::aliases::*;
+use super::linalg::VectorOps;
+use super::types::*;
+use super::view_storage::*;
+use nalgebra as na;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// Read-only view with shape `S`.
+type View<'a, T, S> =
+    MultiArray<T, S, StridedStorage<'a, T, <S as ViewShape>::R, <S as ViewShape>::C>>;
+/// Mutable view with shape `S`.
+type ViewMut<'a, T, S> =
+    MultiArray<T, S, StridedStorageMut<'a, T, <S as ViewShape>::R, <S as ViewShape>::C>>;
+/// Owned array with the same shape as a view with shape `S`.
+type Owned<T, S> = MultiArray<T, S, na::OMatrix<T, <S as ViewShape>::R, <S as ViewShape>::C>>;
+
+// ============================================================================
+// Constructors
+// ============================================================================
+
+impl<T: na::Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Column `j`.
+    pub fn column(&self, j: usize) -> VectorView<'_, T, R> {
+        assert!(
+            j < C,
+            "Matrix::column: index {j} out of bounds for {C} columns"
+        );
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            j * R,
+            (na::Const::<R>, na::U1),
+            (1, R),
+        ))
+    }
+
+    /// Column `j`, mutable.
+    pub fn column_mut(&mut self, j: usize) -> VectorViewMut<'_, T, R> {
+        assert!(
+            j < C,
+            "Matrix::column_mut: index {j} out of bounds for {C} columns"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            j * R,
+            (na::Const::<R>, na::U1),
+            (1, R),
+        ))
+    }
+
+    /// Row `i`, as a (column) vector view with stride `R`.
+    pub fn row(&self, i: usize) -> VectorView<'_, T, C> {
+        assert!(i < R, "Matrix::row: index {i} out of bounds for {R} rows");
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            i,
+            (na::Const::<C>, na::U1),
+            (R, R * C),
+        ))
+    }
+
+    /// Row `i`, mutable.
+    pub fn row_mut(&mut self, i: usize) -> VectorViewMut<'_, T, C> {
+        assert!(
+            i < R,
+            "Matrix::row_mut: index {i} out of bounds for {R} rows"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            i,
+            (na::Const::<C>, na::U1),
+            (R, R * C),
+        ))
+    }
+
+    /// The `BR x BC` block whose upper-left entry is `(i, j)`.
+    pub fn block<const BR: usize, const BC: usize>(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> MatrixView<'_, T, BR, BC> {
+        assert!(
+            i + BR <= R && j + BC <= C,
+            "Matrix::block: {BR}x{BC} block at ({i}, {j}) does not fit in {R}x{C}"
+        );
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            i + j * R,
+            (na::Const::<BR>, na::Const::<BC>),
+            (1, R),
+        ))
+    }
+
+    /// The `BR x BC` block whose upper-left entry is `(i, j)`, mutable.
+    pub fn block_mut<const BR: usize, const BC: usize>(
+        &mut self,
+        i: usize,
+        j: usize,
+    ) -> MatrixViewMut<'_, T, BR, BC> {
+        assert!(
+            i + BR <= R && j + BC <= C,
+            "Matrix::block_mut: {BR}x{BC} block at ({i}, {j}) does not fit in {R}x{C}"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            i + j * R,
+            (na::Const::<BR>, na::Const::<BC>),
+            (1, R),
+        ))
+    }
+}
+
+impl<T: na::Scalar> DynMatrix<T> {
+    /// Column `j`.
+    pub fn column(&self, j: usize) -> DynVectorView<'_, T> {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            j < ncols,
+            "DynMatrix::column: index {j} out of bounds for {ncols} columns"
+        );
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            j * nrows,
+            (na::Dyn(nrows), na::U1),
+            (1, nrows),
+        ))
+    }
+
+    /// Column `j`, mutable.
+    pub fn column_mut(&mut self, j: usize) -> DynVectorViewMut<'_, T> {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            j < ncols,
+            "DynMatrix::column_mut: index {j} out of bounds for {ncols} columns"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            j * nrows,
+            (na::Dyn(nrows), na::U1),
+            (1, nrows),
+        ))
+    }
+
+    /// Row `i`, as a (column) vector view with stride `nrows`.
+    pub fn row(&self, i: usize) -> DynVectorView<'_, T> {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            i < nrows,
+            "DynMatrix::row: index {i} out of bounds for {nrows} rows"
+        );
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            i,
+            (na::Dyn(ncols), na::U1),
+            (nrows, nrows * ncols),
+        ))
+    }
+
+    /// Row `i`, mutable.
+    pub fn row_mut(&mut self, i: usize) -> DynVectorViewMut<'_, T> {
+        let (nrows, ncols) = self.data.shape();
+        assert!(
+            i < nrows,
+            "DynMatrix::row_mut: index {i} out of bounds for {nrows} rows"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            i,
+            (na::Dyn(ncols), na::U1),
+            (nrows, nrows * ncols),
+        ))
+    }
+
+    /// The `n` rows starting at row `start`.
+    pub fn rows(&self, start: usize, n: usize) -> DynMatrixView<'_, T> {
+        self.view(start, 0, n, self.data.ncols())
+    }
+
+    /// The `n` rows starting at row `start`, mutable.
+    pub fn rows_mut(&mut self, start: usize, n: usize) -> DynMatrixViewMut<'_, T> {
+        let ncols = self.data.ncols();
+        self.view_mut(start, 0, n, ncols)
+    }
+
+    /// The `n` columns starting at column `start`.
+    pub fn columns(&self, start: usize, n: usize) -> DynMatrixView<'_, T> {
+        self.view(0, start, self.data.nrows(), n)
+    }
+
+    /// The `n` columns starting at column `start`, mutable.
+    pub fn columns_mut(&mut self, start: usize, n: usize) -> DynMatrixViewMut<'_, T> {
+        let nrows = self.data.nrows();
+        self.view_mut(0, start, nrows, n)
+    }
+
+    /// The `nrows x ncols` block whose upper-left entry is `(i, j)`.
+    pub fn view(&self, i: usize, j: usize, nrows: usize, ncols: usize) -> DynMatrixView<'_, T> {
+        let (r, c) = self.data.shape();
+        assert!(
+            i + nrows <= r && j + ncols <= c,
+            "DynMatrix::view: {nrows}x{ncols} block at ({i}, {j}) does not fit in {r}x{c}"
+        );
+        MultiArray::from_inner(StridedStorage::new(
+            self.data.as_slice(),
+            i + j * r,
+            (na::Dyn(nrows), na::Dyn(ncols)),
+            (1, r),
+        ))
+    }
+
+    /// The `nrows x ncols` block whose upper-left entry is `(i, j)`, mutable.
+    pub fn view_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> DynMatrixViewMut<'_, T> {
+        let (r, c) = self.data.shape();
+        assert!(
+            i + nrows <= r && j + ncols <= c,
+            "DynMatrix::view_mut: {nrows}x{ncols} block at ({i}, {j}) does not fit in {r}x{c}"
+        );
+        MultiArray::from_inner(StridedStorageMut::new(
+            self.data.as_mut_slice(),
+            i + j * r,
+            (na::Dyn(nrows), na::Dyn(ncols)),
+            (1, r),
+        ))
+    }
+}
+
+// ============================================================================
+// View methods
+// ============================================================================
+
+impl<T: na::Scalar, S: ViewShape> View<'_, T, S> {
+    pub fn nrows(&self) -> usize {
+        self.data.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// Copy the viewed elements into an owned array.
+    pub fn into_owned(self) -> Owned<T, S>
+    where
+        na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+    {
+        MultiArray::from_inner(self.data.0.clone_owned())
+    }
+}
+
+impl<T: na::Scalar, S: ViewShape> ViewMut<'_, T, S> {
+    pub fn nrows(&self) -> usize {
+        self.data.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// Read-only reborrow, for the arithmetic operators and `VectorOps`.
+    pub fn as_view(&self) -> View<'_, T, S> {
+        MultiArray::from_inner(self.data.as_view())
+    }
+
+    /// Copy the viewed elements into an owned array.
+    pub fn into_owned(self) -> Owned<T, S>
+    where
+        na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+    {
+        MultiArray::from_inner(self.data.0.clone_owned())
+    }
+
+    /// Overwrite the viewed elements with `value`.
+    pub fn fill(&mut self, value: T) {
+        self.data.0.fill(value);
+    }
+
+    /// Overwrite the viewed elements with those of an owned array or view of
+    /// the same shape.
+    pub fn copy_from<B: ViewSource<T, S>>(&mut self, src: &MultiArray<T, S, B>) {
+        self.data.0.zip_apply(src.data.matrix(), |a, b| *a = b);
+    }
+}
+
+/// Backends that can be read as an nalgebra matrix of shape `S`: the owned
+/// nalgebra backend of `S` and the view backends. Used by the view operators
+/// to accept either operand kind.
+pub trait ViewSource<T: na::Scalar, S: ViewShape> {
+    type Storage: na::Storage<T, S::R, S::C>;
+    fn matrix(&self) -> &na::Matrix<T, S::R, S::C, Self::Storage>;
+}
+
+impl<T: na::Scalar, S: ViewShape> ViewSource<T, S> for na::OMatrix<T, S::R, S::C>
+where
+    na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+{
+    type Storage = <na::DefaultAllocator as na::allocator::Allocator<S::R, S::C>>::Buffer<T>;
+    fn matrix(&self) -> &na::OMatrix<T, S::R, S::C> {
+        self
+    }
+}
+
+impl<'a, T: na::Scalar, S: ViewShape> ViewSource<T, S> for StridedStorage<'a, T, S::R, S::C> {
+    type Storage = na::ViewStorage<'a, T, S::R, S::C, na::Dyn, na::Dyn>;
+    fn matrix(&self) -> &na::Matrix<T, S::R, S::C, Self::Storage> {
+        &self.0
+    }
+}
+
+impl<'a, T: na::Scalar, S: ViewShape> ViewSource<T, S> for StridedStorageMut<'a, T, S::R, S::C> {
+    type Storage = na::ViewStorageMut<'a, T, S::R, S::C, na::Dyn, na::Dyn>;
+    fn matrix(&self) -> &na::Matrix<T, S::R, S::C, Self::Storage> {
+        &self.0
+    }
+}
+
+// ============================================================================
+// Indexing (flat index is column-major, like the owned backends)
+// ============================================================================
+
+impl<T: na::Scalar, S: ViewShape> Index<(usize, usize)> for View<'_, T, S> {
+    type Output = T;
+    fn index(&self, ij: (usize, usize)) -> &T {
+        &self.data.0[ij]
+    }
+}
+
+impl<T: na::Scalar, S: ViewShape> Index<(usize, usize)> for ViewMut<'_, T, S> {
+    type Output = T;
+    fn index(&self, ij: (usize, usize)) -> &T {
+        &self.data.0[ij]
+    }
+}
+
+impl<T: na::Scalar, S: ViewShape> IndexMut<(usize, usize)> for ViewMut<'_, T, S> {
+    fn index_mut(&mut self, ij: (usize, usize)) -> &mut T {
+        &mut self.data.0[ij]
+    }
+}
+
+// Flat `view[k]` is implemented per element type: a generic `T` would overlap
+// with the `B: DenseRawStorage<T>` blanket in traits.rs, which downstream
+// crates could extend to the view backends. The scalar operators below list
+// element types for the same reason (as nalgebra does for `scalar * matrix`).
+macro_rules! impl_view_flat_index {
+    ($($T:ty)*) => {$(
+        impl<S: ViewShape> Index<usize> for View<'_, $T, S> {
+            type Output = $T;
+            fn index(&self, k: usize) -> &$T {
+                &self.data.0[k]
+            }
+        }
+
+        impl<S: ViewShape> Index<usize> for ViewMut<'_, $T, S> {
+            type Output = $T;
+            fn index(&self, k: usize) -> &$T {
+                &self.data.0[k]
+            }
+        }
+
+        impl<S: ViewShape> IndexMut<usize> for ViewMut<'_, $T, S> {
+            fn index_mut(&mut self, k: usize) -> &mut $T {
+                &mut self.data.0[k]
+            }
+        }
+    )*};
+}
+
+impl_view_flat_index!(f32 f64 i8 i16 i32 i64 isize u8 u16 u32 u64 usize bool);
+
+// ============================================================================
+// Comparison with owned arrays
+// ============================================================================
+
+impl<T: na::Scalar, S: ViewShape> PartialEq<Owned<T, S>> for View<'_, T, S>
+where
+    na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+{
+    fn eq(&self, other: &Owned<T, S>) -> bool {
+        self.data.0 == other.data
+    }
+}
+
+impl<T: na::Scalar, S: ViewShape> PartialEq<View<'_, T, S>> for Owned<T, S>
+where
+    na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+{
+    fn eq(&self, other: &View<'_, T, S>) -> bool {
+        self.data == other.data.0
+    }
+}
+
+impl<T: na::Scalar, S: ViewShape> PartialEq<Owned<T, S>> for ViewMut<'_, T, S>
+where
+    na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+{
+    fn eq(&self, other: &Owned<T, S>) -> bool {
+        self.data.0 == other.data
+    }
+}
+
+// ============================================================================
+// Element-wise arithmetic (results are owned)
+// ============================================================================
+
+// Example expansion -- impl_view_binop!(Add, add, +):
+//
+//   impl<T, S, B: ViewSource<T, S>> Add<MultiArray<T, S, B>> for View<'_, T, S> { ... }
+//   impl<'a, T, S> Add<View<'a, T, S>> for Owned<T, S> { ... }
+//
+// i.e. view OP view, view OP owned and owned OP view. owned OP owned is the
+// blanket impl in operators.rs.
+macro_rules! impl_view_binop {
+    ($Trait:ident, $method:ident, $op:tt) => {
+        impl<T, S, B> $Trait<MultiArray<T, S, B>> for View<'_, T, S>
+        where
+            T: na::Scalar + $Trait<Output = T>,
+            S: ViewShape,
+            B: ViewSource<T, S>,
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            type Output = Owned<T, S>;
+            fn $method(self, rhs: MultiArray<T, S, B>) -> Owned<T, S> {
+                MultiArray::from_inner(self.data.0.zip_map(rhs.data.matrix(), |a, b| a $op b))
+            }
+        }
+
+        impl<'a, T, S> $Trait<View<'a, T, S>> for Owned<T, S>
+        where
+            T: na::Scalar + $Trait<Output = T>,
+            S: ViewShape,
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            type Output = Owned<T, S>;
+            fn $method(self, rhs: View<'a, T, S>) -> Owned<T, S> {
+                MultiArray::from_inner(self.data.zip_map(&rhs.data.0, |a, b| a $op b))
+            }
+        }
+    };
+}
+
+impl_view_binop!(Add, add, +);
+impl_view_binop!(Sub, sub, -);
+
+impl<T, S> Neg for View<'_, T, S>
+where
+    T: na::Scalar + Neg<Output = T>,
+    S: ViewShape,
+    na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+{
+    type Output = Owned<T, S>;
+    fn neg(self) -> Owned<T, S> {
+        MultiArray::from_inner(self.data.0.map(|a| -a))
+    }
+}
+
+// View OP scalar, scalar * view and mutable view OP= scalar (see the note on
+// `impl_view_flat_index!`).
+macro_rules! impl_view_scalar_ops {
+    ($($T:ty)*) => {$(
+        impl<S: ViewShape> Mul<$T> for View<'_, $T, S>
+        where
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            type Output = Owned<$T, S>;
+            fn mul(self, rhs: $T) -> Owned<$T, S> {
+                MultiArray::from_inner(self.data.0.map(|a| a * rhs))
+            }
+        }
+
+        impl<S: ViewShape> Mul<View<'_, $T, S>> for $T
+        where
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            type Output = Owned<$T, S>;
+            fn mul(self, rhs: View<'_, $T, S>) -> Owned<$T, S> {
+                MultiArray::from_inner(rhs.data.0.map(|a| self * a))
+            }
+        }
+
+        impl<S: ViewShape> Div<$T> for View<'_, $T, S>
+        where
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            type Output = Owned<$T, S>;
+            fn div(self, rhs: $T) -> Owned<$T, S> {
+                MultiArray::from_inner(self.data.0.map(|a| a / rhs))
+            }
+        }
+
+        impl<S: ViewShape> MulAssign<$T> for ViewMut<'_, $T, S> {
+            fn mul_assign(&mut self, rhs: $T) {
+                self.data.0.apply(|a| *a *= rhs);
+            }
+        }
+
+        impl<S: ViewShape> DivAssign<$T> for ViewMut<'_, $T, S> {
+            fn div_assign(&mut self, rhs: $T) {
+                self.data.0.apply(|a| *a /= rhs);
+            }
+        }
+    )*};
+}
+
+impl_view_scalar_ops!(f32 f64 i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+
+// --- Compound assignment: owned OP= view, mutable view OP= (view | owned) ---
+
+macro_rules! impl_view_assign {
+    ($Trait:ident, $method:ident, $op:tt) => {
+        impl<'a, T, S> $Trait<View<'a, T, S>> for Owned<T, S>
+        where
+            T: na::Scalar + $Trait,
+            S: ViewShape,
+            na::DefaultAllocator: na::allocator::Allocator<S::R, S::C>,
+        {
+            fn $method(&mut self, rhs: View<'a, T, S>) {
+                self.data.zip_apply(&rhs.data.0, |a, b| *a $op b);
+            }
+        }
+
+        impl<T, S, B> $Trait<MultiArray<T, S, B>> for ViewMut<'_, T, S>
+        where
+            T: na::Scalar + $Trait,
+            S: ViewShape,
+            B: ViewSource<T, S>,
+        {
+            fn $method(&mut self, rhs: MultiArray<T, S, B>) {
+                self.data.0.zip_apply(rhs.data.matrix(), |a, b| *a $op b);
+            }
+        }
+    };
+}
+
+impl_view_assign!(AddAssign, add_assign, +=);
+impl_view_assign!(SubAssign, sub_assign, -=);
+
+// ============================================================================
+// Matrix multiplication
+// ============================================================================
+
+// MatrixView<T, R, K> * (MatrixView | Matrix)<T, K, C> -> Matrix<T, R, C>
+impl<T, B, const R: usize, const K: usize, const C: usize> Mul<MultiArray<T, Rank2<K, C>, B>>
+    for MatrixView<'_, T, R, K>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+    B: ViewSource<T, Rank2<K, C>>,
+{
+    type Output = Matrix<T, R, C>;
+    fn mul(self, rhs: MultiArray<T, Rank2<K, C>, B>) -> Matrix<T, R, C> {
+        MultiArray::from_inner(self.data.0 * rhs.data.matrix())
+    }
+}
+
+// MatrixView<T, R, C> * (VectorView | Vector)<T, C> -> Vector<T, R>
+impl<T, B, const R: usize, const C: usize> Mul<MultiArray<T, Rank1<C>, B>>
+    for MatrixView<'_, T, R, C>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+    B: ViewSource<T, Rank1<C>>,
+{
+    type Output = Vector<T, R>;
+    fn mul(self, rhs: MultiArray<T, Rank1<C>, B>) -> Vector<T, R> {
+        MultiArray::from_inner(self.data.0 * rhs.data.matrix())
+    }
+}
+
+// Matrix<T, R, K> * MatrixView<T, K, C> -> Matrix<T, R, C>
+impl<'a, T, const R: usize, const K: usize, const C: usize> Mul<MatrixView<'a, T, K, C>>
+    for Matrix<T, R, K>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = Matrix<T, R, C>;
+    fn mul(self, rhs: MatrixView<'a, T, K, C>) -> Matrix<T, R, C> {
+        MultiArray::from_inner(self.data * rhs.data.0)
+    }
+}
+
+// Matrix<T, R, C> * VectorView<T, C> -> Vector<T, R>
+impl<'a, T, const R: usize, const C: usize> Mul<VectorView<'a, T, C>> for Matrix<T, R, C>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = Vector<T, R>;
+    fn mul(self, rhs: VectorView<'a, T, C>) -> Vector<T, R> {
+        MultiArray::from_inner(self.data * rhs.data.0)
+    }
+}
+
+// DynMatrixView * (DynMatrixView | DynMatrix) -> DynMatrix (panics on mismatch)
+impl<T, B> Mul<MultiArray<T, DynRank2, B>> for DynMatrixView<'_, T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+    B: ViewSource<T, DynRank2>,
+{
+    type Output = DynMatrix<T>;
+    fn mul(self, rhs: MultiArray<T, DynRank2, B>) -> DynMatrix<T> {
+        let rhs = rhs.data.matrix();
+        assert_eq!(
+            self.ncols(),
+            rhs.nrows(),
+            "DynMatrixView * DynMatrix: inner dimensions differ"
+        );
+        MultiArray::from_inner(self.data.0 * rhs)
+    }
+}
+
+// DynMatrixView * (DynVectorView | DynVector) -> DynVector (panics on mismatch)
+impl<T, B> Mul<MultiArray<T, DynRank1, B>> for DynMatrixView<'_, T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+    B: ViewSource<T, DynRank1>,
+{
+    type Output = DynVector<T>;
+    fn mul(self, rhs: MultiArray<T, DynRank1, B>) -> DynVector<T> {
+        let rhs = rhs.data.matrix();
+        assert_eq!(
+            self.ncols(),
+            rhs.nrows(),
+            "DynMatrixView * DynVector: inner dimensions differ"
+        );
+        MultiArray::from_inner(self.data.0 * rhs)
+    }
+}
+
+// DynMatrix * DynMatrixView -> DynMatrix (panics on mismatch)
+impl<'a, T> Mul<DynMatrixView<'a, T>> for &DynMatrix<T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = DynMatrix<T>;
+    fn mul(self, rhs: DynMatrixView<'a, T>) -> DynMatrix<T> {
+        assert_eq!(
+            self.data.ncols(),
+            rhs.nrows(),
+            "DynMatrix * DynMatrixView: inner dimensions differ"
+        );
+        MultiArray::from_inner(&self.data * rhs.data.0)
+    }
+}
+
+// DynMatrix * DynVectorView -> DynVector (panics on mismatch)
+impl<'a, T> Mul<DynVectorView<'a, T>> for &DynMatrix<T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = DynVector<T>;
+    fn mul(self, rhs: DynVectorView<'a, T>) -> DynVector<T> {
+        assert_eq!(
+            self.data.ncols(),
+            rhs.nrows(),
+            "DynMatrix * DynVectorView: inner dimensions differ"
+        );
+        MultiArray::from_inner(&self.data * rhs.data.0)
+    }
+}
+
+// By value: as above.
+impl<'a, T> Mul<DynMatrixView<'a, T>> for DynMatrix<T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = DynMatrix<T>;
+    fn mul(self, rhs: DynMatrixView<'a, T>) -> DynMatrix<T> {
+        &self * rhs
+    }
+}
+
+impl<'a, T> Mul<DynVectorView<'a, T>> for DynMatrix<T>
+where
+    T: na::Scalar + num_traits::Zero + num_traits::One + na::ClosedAddAssign + na::ClosedMulAssign,
+{
+    type Output = DynVector<T>;
+    fn mul(self, rhs: DynVectorView<'a, T>) -> DynVector<T> {
+        &self * rhs
+    }
+}
+
+// ============================================================================
+// VectorOps for vector views
+// ============================================================================
+
+// Example expansion -- impl_view_vector_ops!([const N: usize] VectorView<'_, T, N> => Vector<T, N>):
+//
+//   impl<T: na::ComplexField + Copy, const N: usize> VectorOps<T> for VectorView<'_, T, N> {
+//       type Output = Vector<T, N>;
+//       type Norm = T::RealField;
+//       fn dot(&self, other: &Self) -> T { self.data.0.dotc(&other.data.0) }
+//       ...
+//   }
+macro_rules! impl_view_vector_ops {
+    ([$($gen:tt)*] $View:ty => $Owned:ty) => {
+        impl<T: na::ComplexField + Copy, $($gen)*> VectorOps<T> for $View {
+            type Output = $Owned;
+            type Norm = T::RealField;
+            fn dot(&self, other: &Self) -> T {
+                self.data.0.dotc(&other.data.0)
+            }
+            fn l1_norm(&self) -> T::RealField {
+                self.data.0.lp_norm(1)
+            }
+            fn l2_norm(&self) -> T::RealField {
+                self.data.0.norm()
+            }
+            fn linf_norm(&self) -> T::RealField {
+                self.data.0.camax()
+            }
+            fn lp_norm(&self, p: i32) -> T::RealField {
+                self.data.0.lp_norm(p)
+            }
+            fn norm_squared(&self) -> T::RealField {
+                self.data.0.norm_squared()
+            }
+            fn normalised(&self) -> $Owned {
+                MultiArray::from_inner(self.data.0.normalize())
+            }
+        }
+    };
+}
+
+impl_view_vector_ops!([const N: usize] VectorView<'_, T, N> => Vector<T, N>);
+impl_view_vector_ops!([] DynVectorView<'_, T> => DynVector<T>);
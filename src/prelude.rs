@@ -1,16 +1,18 @@
 pub use crate::common::{Int, Real, UInt};
 pub use crate::fields::{
     BoolField, Field, IntField, Matrix3Field, Matrix3bField, Matrix3iField, Matrix3uField,
-    RealField, ScalarField, SolverInterop, UIntField, Vector3Field, Vector3bField, Vector3iField,
-    Vector3uField,
+    RealField, ScalarField, SolverInterop, SymMatrix3Field, Tensor3333Field, UIntField,
+    Vector3Field, Vector3bField, Vector3iField, Vector3uField,
 };
-pub use crate::multiarray::{DynMatrix, DynVector, Matrix, Vector};
+pub use crate::multiarray::SymMatrix3;
+pub use crate::multiarray::{CooMatrix, CsrMatrix, DynMatrix, DynVector, Matrix, Vector};
 pub use crate::multiarray::{Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4};
 pub use crate::multiarray::{Matrix2b, Matrix3b, Matrix4b, Vector2b, Vector3b, Vector4b};
 pub use crate::multiarray::{Matrix2i, Matrix3i, Matrix4i, Vector2i, Vector3i, Vector4i};
 pub use crate::multiarray::{Matrix2u, Matrix3u, Matrix4u, Vector2u, Vector3u, Vector4u};
 pub use crate::multiarray::{MultiIndex, MultiIndex2, MultiIndex3, MultiIndex4};
 pub use crate::multiarray::{Point, Point2, Point3, Point4};
+pub use crate::multiarray::{Tensor3, Tensor4, Tensor222, Tensor333, Tensor2222, Tensor3333};
 pub use crate::multiarray::{X_AXIS, Y_AXIS, Z_AXIS};
 pub use crate::multiarray::{X_AXIS2, Y_AXIS2};
 pub use crate::multiarray::{X_AXIS3, Y_AXIS3, Z_AXIS3};
@@ -0,0 +1,735 @@
+//! Forward-mode automatic differentiation.
+//!
+//! - [`Dual<N>`]: value and gradient with respect to `N` variables.
+//! - [`Dual2<N>`]: value, gradient and Hessian with respect to `N` variables.
+//!
+//! Both are `Copy`, implement `num_traits::Float`, and work as the element
+//! type `T` of `Vector<T, N>` / `Matrix<T, R, C>`. Seed the inputs with
+//! [`Dual::variables`] (or [`Dual::variable`]) and read the derivatives off
+//! the result.
+//!
+//! Equality and ordering compare the value only, so branches in generic code
+//! (`if x > 0.0`, `max`, ...) behave as they would on `Real`.
+
+use crate::common::Real;
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// First-order dual number: `value + Σ grad[i] εᵢ`.
+#[derive(Debug, Clone, Copy)]
+pub struct Dual<const N: usize> {
+    pub value: Real,
+    /// Partial derivatives with respect to the `N` seeded variables.
+    pub grad: [Real; N],
+}
+
+/// Second-order (hyper-)dual number: value, gradient and Hessian.
+#[derive(Debug, Clone, Copy)]
+pub struct Dual2<const N: usize> {
+    pub value: Real,
+    /// Partial derivatives with respect to the `N` seeded variables.
+    pub grad: [Real; N],
+    /// Second partial derivatives, `hess[i][j] = ∂²f / ∂xᵢ∂xⱼ` (symmetric).
+    pub hess: [[Real; N]; N],
+}
+
+/// `c * x`, except that a zero `x` stays zero. In `chain` this keeps the
+/// derivatives of constants at zero where `f'` or `f''` is infinite (`sqrt`,
+/// `ln`, `cbrt` at 0) instead of turning them into NaN.
+fn scale_tangent(c: Real, x: Real) -> Real {
+    if x == 0.0 { 0.0 } else { c * x }
+}
+
+// ============================================================================
+// Dual -- construction and core arithmetic
+// ============================================================================
+
+impl<const N: usize> Dual<N> {
+    pub const fn new(value: Real, grad: [Real; N]) -> Self {
+        Self { value, grad }
+    }
+
+    /// A constant: all derivatives zero.
+    pub const fn constant(value: Real) -> Self {
+        Self::new(value, [0.0; N])
+    }
+
+    /// The `i`-th independent variable, with `∂/∂xᵢ = 1`.
+    pub fn variable(value: Real, i: usize) -> Self {
+        let mut grad = [0.0; N];
+        grad[i] = 1.0;
+        Self::new(value, grad)
+    }
+
+    /// Seed all `N` independent variables at once.
+    pub fn variables(values: [Real; N]) -> [Self; N] {
+        std::array::from_fn(|i| Self::variable(values[i], i))
+    }
+
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|&g| g == 0.0)
+    }
+
+    fn scale(mut self, s: Real) -> Self {
+        self.value *= s;
+        for g in &mut self.grad {
+            *g *= s;
+        }
+        self
+    }
+
+    /// Apply `f` with `f(x) = f0`, `f'(x) = df` (the second derivative is
+    /// unused at first order).
+    fn chain(self, f0: Real, df: Real, _d2f: Real) -> Self {
+        Self::new(f0, self.grad.map(|g| scale_tangent(df, g)))
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self.value += rhs.value;
+        for (a, b) in self.grad.iter_mut().zip(rhs.grad) {
+            *a += b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self.value -= rhs.value;
+        for (a, b) in self.grad.iter_mut().zip(rhs.grad) {
+            *a -= b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            std::array::from_fn(|i| self.value * rhs.grad[i] + rhs.value * self.grad[i]),
+        )
+    }
+}
+
+// ============================================================================
+// Dual2 -- construction and core arithmetic
+// ============================================================================
+
+impl<const N: usize> Dual2<N> {
+    pub const fn new(value: Real, grad: [Real; N], hess: [[Real; N]; N]) -> Self {
+        Self { value, grad, hess }
+    }
+
+    /// A constant: all derivatives zero.
+    pub const fn constant(value: Real) -> Self {
+        Self::new(value, [0.0; N], [[0.0; N]; N])
+    }
+
+    /// The `i`-th independent variable, with `∂/∂xᵢ = 1`.
+    pub fn variable(value: Real, i: usize) -> Self {
+        let mut x = Self::constant(value);
+        x.grad[i] = 1.0;
+        x
+    }
+
+    /// Seed all `N` independent variables at once.
+    pub fn variables(values: [Real; N]) -> [Self; N] {
+        std::array::from_fn(|i| Self::variable(values[i], i))
+    }
+
+    fn is_constant(&self) -> bool {
+        self.grad.iter().all(|&g| g == 0.0) && self.hess.as_flattened().iter().all(|&h| h == 0.0)
+    }
+
+    fn scale(mut self, s: Real) -> Self {
+        self.value *= s;
+        for g in &mut self.grad {
+            *g *= s;
+        }
+        for h in self.hess.as_flattened_mut() {
+            *h *= s;
+        }
+        self
+    }
+
+    /// Apply `f` with `f(x) = f0`, `f'(x) = df`, `f''(x) = d2f`:
+    /// `∇f = df ∇x`, `∇²f = df ∇²x + d2f ∇x ∇xᵀ`.
+    fn chain(self, f0: Real, df: Real, d2f: Real) -> Self {
+        let g = self.grad;
+        Self::new(
+            f0,
+            g.map(|gi| scale_tangent(df, gi)),
+            std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    scale_tangent(df, self.hess[i][j]) + scale_tangent(d2f, g[i] * g[j])
+                })
+            }),
+        )
+    }
+}
+
+impl<const N: usize> Add for Dual2<N> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self.value += rhs.value;
+        for (a, b) in self.grad.iter_mut().zip(rhs.grad) {
+            *a += b;
+        }
+        for (a, b) in self
+            .hess
+            .as_flattened_mut()
+            .iter_mut()
+            .zip(rhs.hess.as_flattened())
+        {
+            *a += b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Sub for Dual2<N> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self.value -= rhs.value;
+        for (a, b) in self.grad.iter_mut().zip(rhs.grad) {
+            *a -= b;
+        }
+        for (a, b) in self
+            .hess
+            .as_flattened_mut()
+            .iter_mut()
+            .zip(rhs.hess.as_flattened())
+        {
+            *a -= b;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Neg for Dual2<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl<const N: usize> Mul for Dual2<N> {
+    type Output = Self;
+    /// `∇²(ab) = a ∇²b + b ∇²a + ∇a ∇bᵀ + ∇b ∇aᵀ`
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.value * b.value,
+            std::array::from_fn(|i| a.value * b.grad[i] + b.value * a.grad[i]),
+            std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    a.value * b.hess[i][j]
+                        + b.value * a.hess[i][j]
+                        + a.grad[i] * b.grad[j]
+                        + b.grad[i] * a.grad[j]
+                })
+            }),
+        )
+    }
+}
+
+// ============================================================================
+// Shared impls: everything built on constant / chain / scale and the core ops
+// ============================================================================
+
+// Example expansion -- impl_dual_common!(Dual):
+//
+//   impl<const N: usize> Div for Dual<N> { ... self * rhs.recip() ... }
+//   impl<const N: usize> Add<Real> for Dual<N> { ... }
+//   impl<const N: usize> Add<Dual<N>> for Real { ... }
+//   ... (assign ops, Zero, One, Num, NumCast, Float, PartialEq, PartialOrd)
+macro_rules! impl_dual_common {
+    ($D:ident) => {
+        impl<const N: usize> Default for $D<N> {
+            fn default() -> Self {
+                Self::constant(0.0)
+            }
+        }
+
+        impl<const N: usize> From<Real> for $D<N> {
+            fn from(value: Real) -> Self {
+                Self::constant(value)
+            }
+        }
+
+        // Comparisons look at the value only.
+        impl<const N: usize> PartialEq for $D<N> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
+            }
+        }
+
+        impl<const N: usize> PartialOrd for $D<N> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.value.partial_cmp(&other.value)
+            }
+        }
+
+        // --- Remaining dual-dual operators ---
+
+        impl<const N: usize> Div for $D<N> {
+            type Output = Self;
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * rhs.recip()
+            }
+        }
+
+        // x % y = x - trunc(x / y) y, with trunc(x / y) locally constant.
+        impl<const N: usize> Rem for $D<N> {
+            type Output = Self;
+            fn rem(self, rhs: Self) -> Self {
+                self - rhs * (self.value / rhs.value).trunc()
+            }
+        }
+
+        impl<const N: usize> AddAssign for $D<N> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const N: usize> SubAssign for $D<N> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const N: usize> MulAssign for $D<N> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const N: usize> DivAssign for $D<N> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<const N: usize> RemAssign for $D<N> {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        // --- Mixed operators with Real ---
+
+        impl<const N: usize> Add<Real> for $D<N> {
+            type Output = Self;
+            fn add(mut self, rhs: Real) -> Self {
+                self.value += rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> Sub<Real> for $D<N> {
+            type Output = Self;
+            fn sub(mut self, rhs: Real) -> Self {
+                self.value -= rhs;
+                self
+            }
+        }
+
+        impl<const N: usize> Mul<Real> for $D<N> {
+            type Output = Self;
+            fn mul(self, rhs: Real) -> Self {
+                self.scale(rhs)
+            }
+        }
+
+        impl<const N: usize> Div<Real> for $D<N> {
+            type Output = Self;
+            fn div(self, rhs: Real) -> Self {
+                self.scale(1.0 / rhs)
+            }
+        }
+
+        impl<const N: usize> Add<$D<N>> for Real {
+            type Output = $D<N>;
+            fn add(self, rhs: $D<N>) -> $D<N> {
+                rhs + self
+            }
+        }
+
+        impl<const N: usize> Sub<$D<N>> for Real {
+            type Output = $D<N>;
+            fn sub(self, rhs: $D<N>) -> $D<N> {
+                $D::constant(self) - rhs
+            }
+        }
+
+        impl<const N: usize> Mul<$D<N>> for Real {
+            type Output = $D<N>;
+            fn mul(self, rhs: $D<N>) -> $D<N> {
+                rhs.scale(self)
+            }
+        }
+
+        impl<const N: usize> Div<$D<N>> for Real {
+            type Output = $D<N>;
+            fn div(self, rhs: $D<N>) -> $D<N> {
+                rhs.recip().scale(self)
+            }
+        }
+
+        impl<const N: usize> AddAssign<Real> for $D<N> {
+            fn add_assign(&mut self, rhs: Real) {
+                self.value += rhs;
+            }
+        }
+
+        impl<const N: usize> SubAssign<Real> for $D<N> {
+            fn sub_assign(&mut self, rhs: Real) {
+                self.value -= rhs;
+            }
+        }
+
+        impl<const N: usize> MulAssign<Real> for $D<N> {
+            fn mul_assign(&mut self, rhs: Real) {
+                *self = self.scale(rhs);
+            }
+        }
+
+        impl<const N: usize> DivAssign<Real> for $D<N> {
+            fn div_assign(&mut self, rhs: Real) {
+                *self = self.scale(1.0 / rhs);
+            }
+        }
+
+        impl<const N: usize> std::iter::Sum for $D<N> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<const N: usize> std::iter::Product for $D<N> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        // --- num_traits ---
+
+        impl<const N: usize> Zero for $D<N> {
+            fn zero() -> Self {
+                Self::constant(0.0)
+            }
+            fn is_zero(&self) -> bool {
+                self.value == 0.0
+            }
+        }
+
+        impl<const N: usize> One for $D<N> {
+            fn one() -> Self {
+                Self::constant(1.0)
+            }
+        }
+
+        impl<const N: usize> Num for $D<N> {
+            type FromStrRadixErr = <Real as Num>::FromStrRadixErr;
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                Real::from_str_radix(s, radix).map(Self::constant)
+            }
+        }
+
+        impl<const N: usize> ToPrimitive for $D<N> {
+            fn to_i64(&self) -> Option<i64> {
+                self.value.to_i64()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.value.to_u64()
+            }
+            fn to_f64(&self) -> Option<f64> {
+                self.value.to_f64()
+            }
+        }
+
+        impl<const N: usize> NumCast for $D<N> {
+            fn from<P: ToPrimitive>(n: P) -> Option<Self> {
+                <Real as NumCast>::from(n).map(Self::constant)
+            }
+        }
+
+        impl<const N: usize> Float for $D<N> {
+            fn nan() -> Self {
+                Self::constant(Real::NAN)
+            }
+            fn infinity() -> Self {
+                Self::constant(Real::INFINITY)
+            }
+            fn neg_infinity() -> Self {
+                Self::constant(Real::NEG_INFINITY)
+            }
+            fn neg_zero() -> Self {
+                Self::constant(-0.0)
+            }
+            fn min_value() -> Self {
+                Self::constant(Real::MIN)
+            }
+            fn min_positive_value() -> Self {
+                Self::constant(Real::MIN_POSITIVE)
+            }
+            fn epsilon() -> Self {
+                Self::constant(Real::EPSILON)
+            }
+            fn max_value() -> Self {
+                Self::constant(Real::MAX)
+            }
+
+            fn is_nan(self) -> bool {
+                self.value.is_nan()
+            }
+            fn is_infinite(self) -> bool {
+                self.value.is_infinite()
+            }
+            fn is_finite(self) -> bool {
+                self.value.is_finite()
+            }
+            fn is_normal(self) -> bool {
+                self.value.is_normal()
+            }
+            fn classify(self) -> FpCategory {
+                self.value.classify()
+            }
+            fn is_sign_positive(self) -> bool {
+                self.value.is_sign_positive()
+            }
+            fn is_sign_negative(self) -> bool {
+                self.value.is_sign_negative()
+            }
+            fn integer_decode(self) -> (u64, i16, i8) {
+                Float::integer_decode(self.value)
+            }
+
+            // Piecewise-constant functions have zero derivative.
+            fn floor(self) -> Self {
+                Self::constant(self.value.floor())
+            }
+            fn ceil(self) -> Self {
+                Self::constant(self.value.ceil())
+            }
+            fn round(self) -> Self {
+                Self::constant(self.value.round())
+            }
+            fn trunc(self) -> Self {
+                Self::constant(self.value.trunc())
+            }
+            fn signum(self) -> Self {
+                Self::constant(self.value.signum())
+            }
+            fn fract(self) -> Self {
+                self - self.value.trunc()
+            }
+            fn abs(self) -> Self {
+                if self.value.is_sign_negative() {
+                    -self
+                } else {
+                    self
+                }
+            }
+
+            fn max(self, other: Self) -> Self {
+                if self.value >= other.value || other.value.is_nan() {
+                    self
+                } else {
+                    other
+                }
+            }
+            fn min(self, other: Self) -> Self {
+                if self.value <= other.value || other.value.is_nan() {
+                    self
+                } else {
+                    other
+                }
+            }
+            fn abs_sub(self, other: Self) -> Self {
+                if self.value <= other.value {
+                    Self::zero()
+                } else {
+                    self - other
+                }
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+            fn hypot(self, other: Self) -> Self {
+                (self * self + other * other).sqrt()
+            }
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            // atan(y / x) and -atan(x / y) differ from atan2(y, x) by a
+            // locally constant offset; use whichever quotient is bounded.
+            fn atan2(self, other: Self) -> Self {
+                let mut r = if other.value.abs() >= self.value.abs() {
+                    (self / other).atan()
+                } else {
+                    -(other / self).atan()
+                };
+                r.value = self.value.atan2(other.value);
+                r
+            }
+
+            fn recip(self) -> Self {
+                let x = self.value;
+                self.chain(1.0 / x, -1.0 / (x * x), 2.0 / (x * x * x))
+            }
+            fn powi(self, n: i32) -> Self {
+                match n {
+                    0 => Self::one(),
+                    1 => self,
+                    _ => {
+                        let x = self.value;
+                        let nf = n as Real;
+                        self.chain(
+                            x.powi(n),
+                            nf * x.powi(n - 1),
+                            nf * (nf - 1.0) * x.powi(n - 2),
+                        )
+                    }
+                }
+            }
+            fn powf(self, n: Self) -> Self {
+                if !n.is_constant() {
+                    return (n * self.ln()).exp();
+                }
+                let (x, p) = (self.value, n.value);
+                if p == 0.0 {
+                    Self::one()
+                } else if p == 1.0 {
+                    self
+                } else {
+                    self.chain(
+                        x.powf(p),
+                        p * x.powf(p - 1.0),
+                        p * (p - 1.0) * x.powf(p - 2.0),
+                    )
+                }
+            }
+            fn sqrt(self) -> Self {
+                let s = self.value.sqrt();
+                self.chain(s, 0.5 / s, -0.25 / (s * self.value))
+            }
+            fn cbrt(self) -> Self {
+                let (x, c) = (self.value, self.value.cbrt());
+                self.chain(c, c / (3.0 * x), -2.0 * c / (9.0 * x * x))
+            }
+            fn exp(self) -> Self {
+                let e = self.value.exp();
+                self.chain(e, e, e)
+            }
+            fn exp2(self) -> Self {
+                let (e, l) = (self.value.exp2(), std::f64::consts::LN_2 as Real);
+                self.chain(e, e * l, e * l * l)
+            }
+            fn exp_m1(self) -> Self {
+                let e = self.value.exp();
+                self.chain(self.value.exp_m1(), e, e)
+            }
+            fn ln(self) -> Self {
+                let x = self.value;
+                self.chain(x.ln(), 1.0 / x, -1.0 / (x * x))
+            }
+            fn ln_1p(self) -> Self {
+                let y = 1.0 + self.value;
+                self.chain(self.value.ln_1p(), 1.0 / y, -1.0 / (y * y))
+            }
+            fn log2(self) -> Self {
+                let (x, l) = (self.value, std::f64::consts::LN_2 as Real);
+                self.chain(x.log2(), 1.0 / (x * l), -1.0 / (x * x * l))
+            }
+            fn log10(self) -> Self {
+                let (x, l) = (self.value, std::f64::consts::LN_10 as Real);
+                self.chain(x.log10(), 1.0 / (x * l), -1.0 / (x * x * l))
+            }
+            fn sin(self) -> Self {
+                let (s, c) = self.value.sin_cos();
+                self.chain(s, c, -s)
+            }
+            fn cos(self) -> Self {
+                let (s, c) = self.value.sin_cos();
+                self.chain(c, -s, -c)
+            }
+            fn tan(self) -> Self {
+                let t = self.value.tan();
+                let sec2 = 1.0 + t * t;
+                self.chain(t, sec2, 2.0 * t * sec2)
+            }
+            fn asin(self) -> Self {
+                let x = self.value;
+                let r = 1.0 - x * x;
+                self.chain(x.asin(), 1.0 / r.sqrt(), x / (r * r.sqrt()))
+            }
+            fn acos(self) -> Self {
+                let x = self.value;
+                let r = 1.0 - x * x;
+                self.chain(x.acos(), -1.0 / r.sqrt(), -x / (r * r.sqrt()))
+            }
+            fn atan(self) -> Self {
+                let x = self.value;
+                let r = 1.0 + x * x;
+                self.chain(x.atan(), 1.0 / r, -2.0 * x / (r * r))
+            }
+            fn sinh(self) -> Self {
+                let (s, c) = (self.value.sinh(), self.value.cosh());
+                self.chain(s, c, s)
+            }
+            fn cosh(self) -> Self {
+                let (s, c) = (self.value.sinh(), self.value.cosh());
+                self.chain(c, s, c)
+            }
+            fn tanh(self) -> Self {
+                let t = self.value.tanh();
+                let sech2 = 1.0 - t * t;
+                self.chain(t, sech2, -2.0 * t * sech2)
+            }
+            fn asinh(self) -> Self {
+                let x = self.value;
+                let r = x * x + 1.0;
+                self.chain(x.asinh(), 1.0 / r.sqrt(), -x / (r * r.sqrt()))
+            }
+            fn acosh(self) -> Self {
+                let x = self.value;
+                let r = x * x - 1.0;
+                self.chain(x.acosh(), 1.0 / r.sqrt(), -x / (r * r.sqrt()))
+            }
+            fn atanh(self) -> Self {
+                let x = self.value;
+                let r = 1.0 - x * x;
+                self.chain(x.atanh(), 1.0 / r, 2.0 * x / (r * r))
+            }
+        }
+    };
+}
+
+impl_dual_common!(Dual);
+impl_dual_common!(Dual2);
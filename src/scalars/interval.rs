@@ -0,0 +1,259 @@
+//! Interval arithmetic for verified floating-point predicates.
+//!
+//! An [`Interval`] `[lo, hi]` encloses an unknown real number. Every
+//! operation widens its rounded bounds outward by one ulp, so the exact
+//! result of an expression always lies inside the interval computed from
+//! enclosures of its inputs (for finite inputs).
+//!
+//! The point is not the width but the sign: [`Interval::sign`] returns
+//! `Some` only when the whole interval lies on one side of zero, in which
+//! case the floating-point sign of the same expression is certainly correct.
+//! `None` flags a near-degenerate input, where the caller should fall back to
+//! exact arithmetic (`robust::orient2d` and friends). This is the usual
+//! filter in front of an exact geometric predicate: cheap on the common case,
+//! exact on the rare one.
+//!
+//! `Interval` is `Copy` and works as the element type `T` of
+//! `Vector<T, N>` / `Matrix<T, R, C>`. Equality is structural (same bounds);
+//! there is no `PartialOrd`, because two overlapping intervals have no
+//! certain order -- compare with `(a - b).sign()` instead.
+
+use crate::common::Real;
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Closed interval `[lo, hi]` of reals with outward-rounded arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: Real,
+    hi: Real,
+}
+
+// ============================================================================
+// Construction and queries
+// ============================================================================
+
+impl Interval {
+    /// The whole real line, `[-inf, inf]`.
+    pub const ENTIRE: Self = Self {
+        lo: Real::NEG_INFINITY,
+        hi: Real::INFINITY,
+    };
+
+    /// The interval `[lo, hi]`. Panics unless `lo <= hi` (in particular if
+    /// either bound is NaN).
+    pub fn new(lo: Real, hi: Real) -> Self {
+        assert!(lo <= hi, "Interval::new: lo must not exceed hi");
+        Self { lo, hi }
+    }
+
+    /// The degenerate interval `[x, x]`: an input known exactly.
+    pub const fn point(x: Real) -> Self {
+        Self { lo: x, hi: x }
+    }
+
+    /// Rounds the bounds of an approximate result outward by one ulp.
+    fn widened(lo: Real, hi: Real) -> Self {
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    pub fn lo(&self) -> Real {
+        self.lo
+    }
+
+    pub fn hi(&self) -> Real {
+        self.hi
+    }
+
+    /// Midpoint, the best single-value estimate.
+    pub fn mid(&self) -> Real {
+        if self.lo.is_finite() && self.hi.is_finite() {
+            0.5 * self.lo + 0.5 * self.hi
+        } else {
+            0.5 * (self.lo + self.hi)
+        }
+    }
+
+    /// `hi - lo`.
+    pub fn width(&self) -> Real {
+        self.hi - self.lo
+    }
+
+    pub fn contains(&self, x: Real) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(0.0)
+    }
+
+    /// True for `[x, x]`.
+    pub fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Certain sign of the enclosed value: `Some(Greater)` if `lo > 0`,
+    /// `Some(Less)` if `hi < 0`, `Some(Equal)` for `[0, 0]`, and `None` if
+    /// the interval straddles or touches zero.
+    pub fn sign(&self) -> Option<Ordering> {
+        if self.lo > 0.0 {
+            Some(Ordering::Greater)
+        } else if self.hi < 0.0 {
+            Some(Ordering::Less)
+        } else if self.lo == 0.0 && self.hi == 0.0 {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Self::point(0.0)
+    }
+}
+
+impl From<Real> for Interval {
+    fn from(x: Real) -> Self {
+        Self::point(x)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+// ============================================================================
+// Arithmetic
+// ============================================================================
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::widened(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::widened(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+// Negation is exact.
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let p = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        Self::widened(
+            p.into_iter().fold(Real::INFINITY, Real::min),
+            p.into_iter().fold(Real::NEG_INFINITY, Real::max),
+        )
+    }
+}
+
+/// Division by an interval containing zero gives [`Interval::ENTIRE`].
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains_zero() {
+            return Self::ENTIRE;
+        }
+        let q = [
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ];
+        Self::widened(
+            q.into_iter().fold(Real::INFINITY, Real::min),
+            q.into_iter().fold(Real::NEG_INFINITY, Real::max),
+        )
+    }
+}
+
+// Example expansion -- impl_interval_ops!(Add, add, AddAssign, add_assign):
+//
+//   impl Add<Real> for Interval { ... self + Interval::point(rhs) }
+//   impl Add<Interval> for Real { ... Interval::point(self) + rhs }
+//   impl AddAssign for Interval { ... }
+//   impl AddAssign<Real> for Interval { ... }
+macro_rules! impl_interval_ops {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<Real> for Interval {
+            type Output = Self;
+            fn $op(self, rhs: Real) -> Self {
+                self.$op(Interval::point(rhs))
+            }
+        }
+
+        impl $Op<Interval> for Real {
+            type Output = Interval;
+            fn $op(self, rhs: Interval) -> Interval {
+                Interval::point(self).$op(rhs)
+            }
+        }
+
+        impl $OpAssign for Interval {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = (*self).$op(rhs);
+            }
+        }
+
+        impl $OpAssign<Real> for Interval {
+            fn $op_assign(&mut self, rhs: Real) {
+                *self = (*self).$op(Interval::point(rhs));
+            }
+        }
+    };
+}
+
+impl_interval_ops!(Add, add, AddAssign, add_assign);
+impl_interval_ops!(Sub, sub, SubAssign, sub_assign);
+impl_interval_ops!(Mul, mul, MulAssign, mul_assign);
+impl_interval_ops!(Div, div, DivAssign, div_assign);
+
+impl Zero for Interval {
+    fn zero() -> Self {
+        Self::point(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Self {
+        Self::point(1.0)
+    }
+}
+
+impl std::iter::Sum for Interval {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
@@ -0,0 +1,28 @@
+//! Scalar types that can replace `Real` as the element type `T` of a
+//! `MultiArray`.
+//!
+//! - [`Dual`] / [`Dual2`] -- forward-mode automatic differentiation (value plus
+//!   gradient, or value plus gradient and Hessian)
+//! - [`Interval`] -- outward-rounded interval arithmetic, for predicates that
+//!   must know when a floating-point sign cannot be trusted
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::Vector2;
+//! use strelitzia::scalars::Dual;
+//! use num_traits::Float;
+//!
+//! // f(x, y) = x² sin(y) at (2, 0)
+//! let [x, y] = Dual::<2>::variables([2.0, 0.0]);
+//! let f = x * x * y.sin();
+//!
+//! assert_eq!(f.value, 0.0);
+//! assert_eq!(Vector2::from_slice(&f.grad), Vector2::new(0.0, 4.0));
+//! ```
+
+mod dual;
+mod interval;
+
+pub use dual::*;
+pub use interval::*;
@@ -0,0 +1,366 @@
+//! Krylov subspace solvers: CG, BiCGStab and restarted GMRES.
+//!
+//! Every solver takes the initial guess in `x` and overwrites it with the
+//! solution. Work vectors are allocated once per call; the operator and
+//! preconditioner are only ever applied to flat slices.
+
+use super::{LinearOperator, LinearSolverResult, LinearSolverSettings, Preconditioner};
+use crate::common::Real;
+use std::time::Instant;
+
+// ============================================================================
+// Slice kernels
+// ============================================================================
+
+fn dot(a: &[Real], b: &[Real]) -> Real {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn norm2(a: &[Real]) -> Real {
+    dot(a, a).sqrt()
+}
+
+/// `y += alpha * x`
+fn axpy(alpha: Real, x: &[Real], y: &mut [Real]) {
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi += alpha * xi;
+    }
+}
+
+/// `r = b - A x`
+fn residual<A: LinearOperator + ?Sized>(a: &A, b: &[Real], x: &[Real], r: &mut [Real]) {
+    a.apply(x, r);
+    for (ri, bi) in r.iter_mut().zip(b) {
+        *ri = bi - *ri;
+    }
+}
+
+/// Bookkeeping shared by all solvers: residual history, timing, termination.
+struct Monitor {
+    start: Instant,
+    b_norm: Real,
+    tol: Real,
+    history: Vec<Real>,
+}
+
+impl Monitor {
+    fn new(b: &[Real], settings: &LinearSolverSettings) -> Self {
+        Self {
+            start: Instant::now(),
+            b_norm: norm2(b),
+            tol: settings.tol,
+            history: Vec::new(),
+        }
+    }
+
+    /// Record the residual norm; returns true once converged.
+    fn record(&mut self, r_norm: Real) -> bool {
+        let rel = r_norm / self.b_norm;
+        self.history.push(rel);
+        rel <= self.tol
+    }
+
+    fn finish(self, converged: bool) -> LinearSolverResult {
+        LinearSolverResult {
+            iterations: self.history.len().saturating_sub(1),
+            final_residual: self.history.last().copied().unwrap_or(0.0),
+            history: self.history,
+            elapsed: self.start.elapsed(),
+            converged,
+        }
+    }
+
+    /// `b = 0` has the exact solution `x = 0`.
+    fn zero_rhs(mut self, x: &mut [Real]) -> LinearSolverResult {
+        x.fill(0.0);
+        self.history.push(0.0);
+        self.finish(true)
+    }
+}
+
+fn check_lengths(b: &[Real], x: &[Real]) {
+    assert_eq!(
+        b.len(),
+        x.len(),
+        "Krylov solver: b and x must have same length"
+    );
+}
+
+// ============================================================================
+// Conjugate gradient
+// ============================================================================
+
+/// Preconditioned conjugate gradient for symmetric positive definite `A`
+/// (and symmetric positive definite `M`).
+///
+/// Stops without converging if `pᵀ A p ≤ 0`, i.e. `A` is not positive definite.
+pub fn cg<A, M>(
+    a: &A,
+    b: &[Real],
+    x: &mut [Real],
+    precond: &M,
+    settings: &LinearSolverSettings,
+) -> LinearSolverResult
+where
+    A: LinearOperator + ?Sized,
+    M: Preconditioner + ?Sized,
+{
+    check_lengths(b, x);
+    let mut monitor = Monitor::new(b, settings);
+    if monitor.b_norm == 0.0 {
+        return monitor.zero_rhs(x);
+    }
+
+    let n = b.len();
+    let mut r = vec![0.0; n];
+    let mut z = vec![0.0; n];
+    let mut q = vec![0.0; n];
+
+    residual(a, b, x, &mut r);
+    if monitor.record(norm2(&r)) {
+        return monitor.finish(true);
+    }
+    precond.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    for _ in 0..settings.max_iter {
+        a.apply(&p, &mut q);
+        let pq = dot(&p, &q);
+        if pq <= 0.0 {
+            break;
+        }
+        let alpha = rz / pq;
+        axpy(alpha, &p, x);
+        axpy(-alpha, &q, &mut r);
+
+        if monitor.record(norm2(&r)) {
+            return monitor.finish(true);
+        }
+
+        precond.apply(&r, &mut z);
+        let rz_new = dot(&r, &z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        for (pi, zi) in p.iter_mut().zip(&z) {
+            *pi = zi + beta * *pi;
+        }
+    }
+
+    monitor.finish(false)
+}
+
+// ============================================================================
+// BiCGStab
+// ============================================================================
+
+/// Right-preconditioned BiCGStab for general (non-symmetric) `A`.
+///
+/// Stops without converging on a breakdown (`ρ = 0`, `r̂·v = 0` or `ω = 0`).
+pub fn bicgstab<A, M>(
+    a: &A,
+    b: &[Real],
+    x: &mut [Real],
+    precond: &M,
+    settings: &LinearSolverSettings,
+) -> LinearSolverResult
+where
+    A: LinearOperator + ?Sized,
+    M: Preconditioner + ?Sized,
+{
+    check_lengths(b, x);
+    let mut monitor = Monitor::new(b, settings);
+    if monitor.b_norm == 0.0 {
+        return monitor.zero_rhs(x);
+    }
+
+    let n = b.len();
+    let mut r = vec![0.0; n];
+    residual(a, b, x, &mut r);
+    if monitor.record(norm2(&r)) {
+        return monitor.finish(true);
+    }
+
+    let r_hat = r.clone();
+    let mut p = vec![0.0; n];
+    let mut v = vec![0.0; n];
+    let mut p_hat = vec![0.0; n];
+    let mut s_hat = vec![0.0; n];
+    let mut t = vec![0.0; n];
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+
+    for _ in 0..settings.max_iter {
+        let rho_new = dot(&r_hat, &r);
+        if rho_new == 0.0 {
+            break;
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        rho = rho_new;
+        for ((pi, ri), vi) in p.iter_mut().zip(&r).zip(&v) {
+            *pi = ri + beta * (*pi - omega * vi);
+        }
+
+        precond.apply(&p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == 0.0 {
+            break;
+        }
+        alpha = rho / r_hat_v;
+
+        // r now holds s = r - alpha v
+        axpy(-alpha, &v, &mut r);
+        axpy(alpha, &p_hat, x);
+        let s_norm = norm2(&r);
+        if s_norm / monitor.b_norm <= monitor.tol {
+            monitor.record(s_norm);
+            return monitor.finish(true);
+        }
+
+        precond.apply(&r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+        let tt = dot(&t, &t);
+        omega = if tt > 0.0 { dot(&t, &r) / tt } else { 0.0 };
+        axpy(omega, &s_hat, x);
+        axpy(-omega, &t, &mut r);
+
+        if monitor.record(norm2(&r)) {
+            return monitor.finish(true);
+        }
+        if omega == 0.0 {
+            break;
+        }
+    }
+
+    monitor.finish(false)
+}
+
+// ============================================================================
+// Restarted GMRES
+// ============================================================================
+
+/// Right-preconditioned restarted GMRES(m) for general `A`, with
+/// `m = settings.restart`.
+///
+/// Uses modified Gram-Schmidt for the Arnoldi basis and Givens rotations for
+/// the least-squares problem. Each operator application counts as one
+/// iteration in `max_iter` and in the history.
+pub fn gmres<A, M>(
+    a: &A,
+    b: &[Real],
+    x: &mut [Real],
+    precond: &M,
+    settings: &LinearSolverSettings,
+) -> LinearSolverResult
+where
+    A: LinearOperator + ?Sized,
+    M: Preconditioner + ?Sized,
+{
+    check_lengths(b, x);
+    assert!(settings.restart > 0, "GMRES: restart must be positive");
+    let mut monitor = Monitor::new(b, settings);
+    if monitor.b_norm == 0.0 {
+        return monitor.zero_rhs(x);
+    }
+
+    let n = b.len();
+    let m = settings.restart;
+    let mut r = vec![0.0; n];
+    let mut w = vec![0.0; n];
+    let mut z = vec![0.0; n];
+    let mut basis: Vec<Vec<Real>> = vec![vec![0.0; n]; m + 1];
+    // Hessenberg matrix, column-major: h[j] is column j (length m + 1).
+    let mut h = vec![vec![0.0; m + 1]; m];
+    let mut cs = vec![0.0; m];
+    let mut sn = vec![0.0; m];
+    let mut g = vec![0.0; m + 1];
+    let mut y = vec![0.0; m];
+
+    residual(a, b, x, &mut r);
+    let mut beta = norm2(&r);
+    if monitor.record(beta) {
+        return monitor.finish(true);
+    }
+
+    let mut iters = 0;
+    while iters < settings.max_iter {
+        for (vi, ri) in basis[0].iter_mut().zip(&r) {
+            *vi = ri / beta;
+        }
+        g.fill(0.0);
+        g[0] = beta;
+
+        let mut k = 0;
+        let mut converged = false;
+        while k < m && iters < settings.max_iter {
+            precond.apply(&basis[k], &mut z);
+            a.apply(&z, &mut w);
+            iters += 1;
+
+            // Modified Gram-Schmidt
+            for i in 0..=k {
+                let hik = dot(&w, &basis[i]);
+                h[k][i] = hik;
+                axpy(-hik, &basis[i], &mut w);
+            }
+            let h_next = norm2(&w);
+            h[k][k + 1] = h_next;
+            if h_next > 0.0 {
+                for (vi, wi) in basis[k + 1].iter_mut().zip(&w) {
+                    *vi = wi / h_next;
+                }
+            }
+
+            // Apply the previous rotations, then eliminate h[k][k + 1].
+            for i in 0..k {
+                let (a0, a1) = (h[k][i], h[k][i + 1]);
+                h[k][i] = cs[i] * a0 + sn[i] * a1;
+                h[k][i + 1] = -sn[i] * a0 + cs[i] * a1;
+            }
+            let denom = h[k][k].hypot(h[k][k + 1]);
+            (cs[k], sn[k]) = if denom > 0.0 {
+                (h[k][k] / denom, h[k][k + 1] / denom)
+            } else {
+                (1.0, 0.0)
+            };
+            h[k][k] = denom;
+            h[k][k + 1] = 0.0;
+            g[k + 1] = -sn[k] * g[k];
+            g[k] *= cs[k];
+
+            k += 1;
+            converged = monitor.record(g[k].abs());
+            // h_next == 0: the Krylov space is invariant, the solution is exact.
+            if converged || h_next == 0.0 {
+                break;
+            }
+        }
+
+        // Back-substitute H y = g and update x += M⁻¹ (V y).
+        for i in (0..k).rev() {
+            let mut s = g[i];
+            for j in i + 1..k {
+                s -= h[j][i] * y[j];
+            }
+            y[i] = if h[i][i] != 0.0 { s / h[i][i] } else { 0.0 };
+        }
+        w.fill(0.0);
+        for (j, yj) in y.iter().enumerate().take(k) {
+            axpy(*yj, &basis[j], &mut w);
+        }
+        precond.apply(&w, &mut z);
+        axpy(1.0, &z, x);
+
+        if converged {
+            return monitor.finish(true);
+        }
+
+        residual(a, b, x, &mut r);
+        beta = norm2(&r);
+        if beta == 0.0 {
+            return monitor.finish(true);
+        }
+    }
+
+    monitor.finish(false)
+}
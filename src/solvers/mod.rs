@@ -0,0 +1,135 @@
+//! Iterative linear solvers for `A x = b`.
+//!
+//! Provides Krylov methods that work directly on flat `Real` slices, so the
+//! unknowns can live in a `Field<T>` and be exposed through
+//! [`SolverInterop`](crate::fields::SolverInterop):
+//!
+//! - [`cg`] -- preconditioned conjugate gradient (symmetric positive definite `A`)
+//! - [`bicgstab`] -- right-preconditioned BiCGStab (general `A`)
+//! - [`gmres`] -- right-preconditioned restarted GMRES(m) (general `A`)
+//!
+//! The operator is anything implementing [`LinearOperator`]: a `CsrMatrix<Real>`
+//! or a matrix-free closure `|x: &[Real], y: &mut [Real]| { /* y = A x */ }`.
+//! Preconditioners implement [`Preconditioner`]; [`IdentityPreconditioner`],
+//! [`JacobiPreconditioner`] and [`Ilu0Preconditioner`] are provided.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::fields::{RealField, SolverInterop};
+//! use strelitzia::multiarray::CooMatrix;
+//! use strelitzia::solvers::{cg, JacobiPreconditioner, LinearSolverSettings};
+//!
+//! // 1D Laplacian with Dirichlet ends
+//! let n = 10;
+//! let mut coo = CooMatrix::new(n, n);
+//! for i in 0..n {
+//!     coo.push(i, i, 2.0);
+//!     if i > 0 { coo.push(i, i - 1, -1.0); }
+//!     if i + 1 < n { coo.push(i, i + 1, -1.0); }
+//! }
+//! let a = coo.to_csr();
+//!
+//! let b: RealField = (0..n).map(|_| 1.0).collect();
+//! let mut x: RealField = (0..n).map(|_| 0.0).collect();
+//!
+//! let precond = JacobiPreconditioner::try_new(&a).unwrap();
+//! let result = cg(&a, b.as_flat_slice(), x.as_flat_slice_mut(), &precond,
+//!                 &LinearSolverSettings::default());
+//! assert!(result.converged);
+//! ```
+
+mod krylov;
+mod preconditioners;
+
+pub use krylov::*;
+pub use preconditioners::*;
+
+use crate::common::Real;
+use crate::multiarray::CsrMatrix;
+use std::time::Duration;
+
+// ============================================================================
+// LinearOperator -- the A in A x = b
+// ============================================================================
+
+/// A linear operator `y = A x` on flat slices.
+///
+/// Implemented for `CsrMatrix<Real>` and for any closure
+/// `Fn(&[Real], &mut [Real])`, which allows matrix-free solves.
+pub trait LinearOperator {
+    /// Overwrite `y` with `A x`.
+    fn apply(&self, x: &[Real], y: &mut [Real]);
+}
+
+impl<F: Fn(&[Real], &mut [Real])> LinearOperator for F {
+    fn apply(&self, x: &[Real], y: &mut [Real]) {
+        self(x, y)
+    }
+}
+
+impl LinearOperator for CsrMatrix<Real> {
+    fn apply(&self, x: &[Real], y: &mut [Real]) {
+        self.mul_vec_into(x, y);
+    }
+}
+
+// ============================================================================
+// LinearSolverSettings -- shared solver configuration
+// ============================================================================
+
+/// Configuration for the Krylov solvers.
+///
+/// # Convergence criterion
+///
+/// All solvers terminate when the **relative residual** drops below `tol`:
+///
+/// ```text
+/// ‖b − A x‖₂ / ‖b‖₂ ≤ tol
+/// ```
+///
+/// GMRES monitors the residual estimate from its least-squares problem, which
+/// equals the true residual in exact arithmetic (right preconditioning).
+#[derive(Debug, Clone)]
+pub struct LinearSolverSettings {
+    /// Maximum number of iterations (operator applications for GMRES,
+    /// iterations for CG / BiCGStab). Default is 1000.
+    pub max_iter: usize,
+    /// Convergence tolerance on the relative residual. Default is `1e-10`.
+    pub tol: Real,
+    /// Krylov subspace dimension before GMRES restarts. Default is 30.
+    pub restart: usize,
+}
+
+impl Default for LinearSolverSettings {
+    fn default() -> Self {
+        Self {
+            max_iter: 1000,
+            tol: 1e-10,
+            restart: 30,
+        }
+    }
+}
+
+// ============================================================================
+// LinearSolverResult -- solver output with timing
+// ============================================================================
+
+/// Result of a linear solve, including the residual history and timing.
+///
+/// The solution itself is written into the `x` slice passed to the solver.
+#[derive(Debug, Clone)]
+pub struct LinearSolverResult {
+    /// Relative residual after each iteration; `history[0]` is the initial
+    /// residual.
+    pub history: Vec<Real>,
+    /// Wall-clock time for the solver run.
+    pub elapsed: Duration,
+    /// True if the convergence criterion was satisfied within `max_iter`.
+    /// False on `max_iter` or on a breakdown of the method.
+    pub converged: bool,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Final relative residual.
+    pub final_residual: Real,
+}
@@ -0,0 +1,160 @@
+//! Preconditioners for the Krylov solvers.
+//!
+//! A preconditioner approximates `A⁻¹`: `apply(r, z)` computes `z = M⁻¹ r`.
+
+use crate::common::Real;
+use crate::multiarray::CsrMatrix;
+
+/// Preconditioner `z = M⁻¹ r` on flat slices.
+pub trait Preconditioner {
+    /// Overwrite `z` with `M⁻¹ r`.
+    fn apply(&self, r: &[Real], z: &mut [Real]);
+}
+
+// ============================================================================
+// Identity
+// ============================================================================
+
+/// No preconditioning: `M = I`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &[Real], z: &mut [Real]) {
+        z.copy_from_slice(r);
+    }
+}
+
+// ============================================================================
+// Jacobi
+// ============================================================================
+
+/// Diagonal (Jacobi) preconditioner: `M = diag(A)`.
+#[derive(Debug, Clone)]
+pub struct JacobiPreconditioner {
+    inv_diag: Vec<Real>,
+}
+
+impl JacobiPreconditioner {
+    /// Build from the diagonal of `a`. Returns `None` if any diagonal entry
+    /// is missing or zero.
+    pub fn try_new(a: &CsrMatrix<Real>) -> Option<Self> {
+        let n = a.nrows().min(a.ncols());
+        Self::try_from_diagonal(&(0..n).map(|i| a.get(i, i)).collect::<Vec<_>>())
+    }
+
+    /// Build from an explicit diagonal, e.g. for a matrix-free operator.
+    /// Returns `None` if any entry is zero.
+    pub fn try_from_diagonal(diag: &[Real]) -> Option<Self> {
+        if diag.contains(&0.0) {
+            return None;
+        }
+        Some(Self {
+            inv_diag: diag.iter().map(|d| 1.0 / d).collect(),
+        })
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &[Real], z: &mut [Real]) {
+        for ((zi, ri), di) in z.iter_mut().zip(r).zip(&self.inv_diag) {
+            *zi = ri * di;
+        }
+    }
+}
+
+// ============================================================================
+// ILU(0)
+// ============================================================================
+
+/// Incomplete LU factorisation with zero fill-in: `M = L U`, where `L`
+/// (unit lower) and `U` (upper) keep the sparsity pattern of `A`.
+#[derive(Debug, Clone)]
+pub struct Ilu0Preconditioner {
+    /// `L` (strictly lower part) and `U` (diagonal and above), stored in the
+    /// CSR pattern of `A`.
+    factors: CsrMatrix<Real>,
+    /// Position of the diagonal entry of each row in `factors.values()`.
+    diag: Vec<usize>,
+}
+
+impl Ilu0Preconditioner {
+    /// Factorise a square matrix. Returns `None` if `a` is not square, a
+    /// diagonal entry is missing from the pattern or a zero pivot is
+    /// encountered.
+    pub fn try_new(a: &CsrMatrix<Real>) -> Option<Self> {
+        if a.nrows() != a.ncols() {
+            return None;
+        }
+        let n = a.nrows();
+        let mut factors = a.clone();
+        let offsets = a.row_offsets().to_vec();
+        let cols = a.col_indices().to_vec();
+
+        let mut diag = Vec::with_capacity(n);
+        for i in 0..n {
+            let row = &cols[offsets[i]..offsets[i + 1]];
+            diag.push(offsets[i] + row.binary_search(&i).ok()?);
+        }
+
+        // IKJ variant: eliminate row i against every earlier row k in its
+        // pattern, discarding updates that fall outside the pattern.
+        const NONE: usize = usize::MAX;
+        let mut pos = vec![NONE; n];
+        let vals = factors.values_mut();
+        for i in 0..n {
+            for e in offsets[i]..offsets[i + 1] {
+                pos[cols[e]] = e;
+            }
+            for e in offsets[i]..diag[i] {
+                let k = cols[e];
+                let pivot = vals[diag[k]];
+                if pivot == 0.0 {
+                    return None;
+                }
+                vals[e] /= pivot;
+                let lik = vals[e];
+                for f in diag[k] + 1..offsets[k + 1] {
+                    let p = pos[cols[f]];
+                    if p != NONE {
+                        vals[p] -= lik * vals[f];
+                    }
+                }
+            }
+            for e in offsets[i]..offsets[i + 1] {
+                pos[cols[e]] = NONE;
+            }
+            if vals[diag[i]] == 0.0 {
+                return None;
+            }
+        }
+
+        Some(Self { factors, diag })
+    }
+}
+
+impl Preconditioner for Ilu0Preconditioner {
+    fn apply(&self, r: &[Real], z: &mut [Real]) {
+        let offsets = self.factors.row_offsets();
+        let cols = self.factors.col_indices();
+        let vals = self.factors.values();
+        let n = self.diag.len();
+
+        // Forward solve L y = r (unit diagonal), y stored in z.
+        for i in 0..n {
+            let mut s = r[i];
+            for e in offsets[i]..self.diag[i] {
+                s -= vals[e] * z[cols[e]];
+            }
+            z[i] = s;
+        }
+        // Backward solve U z = y.
+        for i in (0..n).rev() {
+            let mut s = z[i];
+            for e in self.diag[i] + 1..offsets[i + 1] {
+                s -= vals[e] * z[cols[e]];
+            }
+            z[i] = s / vals[self.diag[i]];
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Type-level SI dimensions.
+//!
+//! A dimension is a [`Dim`] whose seven parameters are `typenum` integers:
+//! the exponents of length, mass, time, electric current, temperature, amount
+//! of substance and luminous intensity. `Dim` implements `Mul` and `Div` at
+//! the type level (exponents add and subtract), so the dimension of a product
+//! of quantities is computed by the compiler:
+//!
+//! ```
+//! use strelitzia::units::dim::{Dimension, Velocity};
+//! use strelitzia::units::{Length, Time};
+//!
+//! let v = Length::new(3.0) / Time::new(2.0);
+//! assert_eq!(v.into_value(), 1.5);
+//! assert_eq!(Velocity::symbol(), "m s^-1");
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+use typenum::{Diff, Integer, N1, N2, N3, P1, P2, P3, Sum, Z0};
+
+/// SI dimension `m^L kg^M s^T A^I K^K mol^Mol cd^Cd`.
+///
+/// A zero-sized marker; only its type parameters carry information.
+pub struct Dim<L, M, T, I, K, Mol, Cd>(PhantomData<(L, M, T, I, K, Mol, Cd)>);
+
+/// A type-level SI dimension.
+pub trait Dimension: 'static {
+    /// Exponents of (m, kg, s, A, K, mol, cd).
+    const EXPONENTS: [i32; 7];
+
+    /// Unit symbol in SI base units, e.g. `"m s^-2"` (`""` if dimensionless).
+    fn symbol() -> String {
+        const BASE: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+        let mut parts = Vec::new();
+        for (base, &e) in BASE.iter().zip(Self::EXPONENTS.iter()) {
+            match e {
+                0 => {}
+                1 => parts.push(base.to_string()),
+                _ => parts.push(format!("{base}^{e}")),
+            }
+        }
+        parts.join(" ")
+    }
+}
+
+impl<L, M, T, I, K, Mol, Cd> Dimension for Dim<L, M, T, I, K, Mol, Cd>
+where
+    L: Integer + 'static,
+    M: Integer + 'static,
+    T: Integer + 'static,
+    I: Integer + 'static,
+    K: Integer + 'static,
+    Mol: Integer + 'static,
+    Cd: Integer + 'static,
+{
+    const EXPONENTS: [i32; 7] = [L::I32, M::I32, T::I32, I::I32, K::I32, Mol::I32, Cd::I32];
+}
+
+// Product of dimensions: exponents add.
+impl<L1, M1, T1, I1, K1, Mol1, Cd1, L2, M2, T2, I2, K2, Mol2, Cd2>
+    Mul<Dim<L2, M2, T2, I2, K2, Mol2, Cd2>> for Dim<L1, M1, T1, I1, K1, Mol1, Cd1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+    K1: Add<K2>,
+    Mol1: Add<Mol2>,
+    Cd1: Add<Cd2>,
+{
+    type Output = Dim<
+        Sum<L1, L2>,
+        Sum<M1, M2>,
+        Sum<T1, T2>,
+        Sum<I1, I2>,
+        Sum<K1, K2>,
+        Sum<Mol1, Mol2>,
+        Sum<Cd1, Cd2>,
+    >;
+    fn mul(self, _: Dim<L2, M2, T2, I2, K2, Mol2, Cd2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+// Quotient of dimensions: exponents subtract.
+impl<L1, M1, T1, I1, K1, Mol1, Cd1, L2, M2, T2, I2, K2, Mol2, Cd2>
+    Div<Dim<L2, M2, T2, I2, K2, Mol2, Cd2>> for Dim<L1, M1, T1, I1, K1, Mol1, Cd1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+    K1: Sub<K2>,
+    Mol1: Sub<Mol2>,
+    Cd1: Sub<Cd2>,
+{
+    type Output = Dim<
+        Diff<L1, L2>,
+        Diff<M1, M2>,
+        Diff<T1, T2>,
+        Diff<I1, I2>,
+        Diff<K1, K2>,
+        Diff<Mol1, Mol2>,
+        Diff<Cd1, Cd2>,
+    >;
+    fn div(self, _: Dim<L2, M2, T2, I2, K2, Mol2, Cd2>) -> Self::Output {
+        Dim(PhantomData)
+    }
+}
+
+// ============================================================================
+// Named dimensions
+// ============================================================================
+
+// Base dimensions
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type Current = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+pub type Amount = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+// Geometry and kinematics
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Velocity = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+
+// Mechanics
+pub type Density = Dim<N3, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Momentum = Dim<P1, P1, N1, Z0, Z0, Z0, Z0>;
+pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+/// Also the dimension of stress.
+pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+pub type DynamicViscosity = Dim<N1, P1, N1, Z0, Z0, Z0, Z0>;
+pub type KinematicViscosity = Dim<P2, Z0, N1, Z0, Z0, Z0, Z0>;
@@ -0,0 +1,45 @@
+//! Compile-time physical units (dimensional analysis).
+//!
+//! - [`Quantity<V, D>`] -- a value `V` (`Real`, `Vector3`, `Matrix3`, ...) in
+//!   SI base units, tagged with a type-level [`dim::Dimension`]
+//! - [`dim`] -- the SI dimensions (`Length`, `Velocity`, `Pressure`, ...)
+//! - quantity aliases (`Length<V>`, `Velocity<V>`, ...) and unit constants
+//!   (`METRE`, `MILLIMETRE`, `PASCAL`, ...)
+//!
+//! The layer is opt-in and zero-cost: `Quantity` is `#[repr(transparent)]`,
+//! and a `Field<Quantity<V, D>>` implements `SolverInterop` exactly like
+//! `Field<V>`. Values are stored in SI base units, so millimetre and metre
+//! inputs cannot be mixed up once wrapped.
+//!
+//! # Example
+//!
+//! ```
+//! use strelitzia::multiarray::Vector3;
+//! use strelitzia::units::{Length, MILLIMETRE, SECOND, Time, Velocity};
+//!
+//! let dx = Vector3::new(3.0, 4.0, 0.0) * MILLIMETRE;
+//! let dt: Time = 2.0 * SECOND;
+//! let v: Velocity<Vector3> = dx / dt;
+//!
+//! let speed: Velocity = v.norm();
+//! assert!((speed.into_value() - 2.5e-3).abs() < 1e-15);
+//! let length: Length = dx.norm();
+//! assert!((length.in_units(MILLIMETRE) - 5.0).abs() < 1e-12);
+//! ```
+//!
+//! Adding quantities of different dimensions does not compile:
+//!
+//! ```compile_fail
+//! use strelitzia::units::{PASCAL, Pressure, SECOND, Velocity, METRE};
+//!
+//! let v: Velocity = METRE / SECOND;
+//! let p: Pressure = 1.0 * PASCAL;
+//! let _ = v + p;
+//! ```
+
+pub mod dim;
+mod quantity;
+mod si;
+
+pub use quantity::*;
+pub use si::*;
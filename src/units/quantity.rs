@@ -0,0 +1,319 @@
+//! `Quantity<V, D>`: a value tagged with an SI dimension.
+//!
+//! `V` is any value type (`Real`, `Vector3`, `Matrix3`, ...) holding the
+//! magnitude in SI base units; `D` is a [`Dimension`]. The wrapper is
+//! `#[repr(transparent)]` over `V`, so a `Field<Quantity<Vector3, Velocity>>`
+//! has the same layout as a `Vector3Field` and keeps zero-copy
+//! `SolverInterop`.
+//!
+//! Addition and subtraction require equal dimensions; multiplication and
+//! division combine them. Plain `Real` factors leave the dimension unchanged.
+
+use super::dim::{Dimension, Dimensionless};
+use crate::common::Real;
+use crate::fields::{Field, FieldElement, LinearElement};
+use crate::multiarray::MultiArray;
+use crate::multiarray::linalg::VectorOps;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use typenum::{Prod, Quot};
+
+/// A value of type `V` in SI base units of dimension `D`.
+#[repr(transparent)]
+pub struct Quantity<V, D> {
+    value: V,
+    dim: PhantomData<D>,
+}
+
+impl<V, D> Quantity<V, D> {
+    /// Wraps a value expressed in SI base units.
+    pub const fn new(value: V) -> Self {
+        Self {
+            value,
+            dim: PhantomData,
+        }
+    }
+
+    /// The value in SI base units.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Mutable access to the value in SI base units.
+    pub fn value_mut(&mut self) -> &mut V {
+        &mut self.value
+    }
+
+    /// Unwraps the value in SI base units.
+    pub fn into_value(self) -> V {
+        self.value
+    }
+
+    /// The value expressed as a multiple of `unit`, e.g.
+    /// `x.in_units(MILLIMETRE)`.
+    pub fn in_units(self, unit: Quantity<Real, D>) -> V
+    where
+        V: Div<Real, Output = V>,
+    {
+        self.value / unit.value
+    }
+
+    /// Applies `f` to the value, keeping the dimension.
+    pub fn map<W>(self, f: impl FnOnce(V) -> W) -> Quantity<W, D> {
+        Quantity::new(f(self.value))
+    }
+
+    /// Dot product; the dimension is the product of both dimensions.
+    pub fn dot<T, D2>(&self, other: &Quantity<V, D2>) -> Quantity<T, Prod<D, D2>>
+    where
+        V: VectorOps<T>,
+        D: Mul<D2>,
+    {
+        Quantity::new(self.value.dot(&other.value))
+    }
+
+    /// Euclidean norm, with the dimension of the vector.
+    pub fn norm<T>(&self) -> Quantity<V::Norm, D>
+    where
+        V: VectorOps<T>,
+    {
+        Quantity::new(self.value.norm())
+    }
+}
+
+// ============================================================================
+// Standard traits (manual: derives would bound `D`)
+// ============================================================================
+
+impl<V: Clone, D> Clone for Quantity<V, D> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<V: Copy, D> Copy for Quantity<V, D> {}
+
+impl<V: Default, D> Default for Quantity<V, D> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<V: PartialEq, D> PartialEq for Quantity<V, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<V: PartialOrd, D> PartialOrd for Quantity<V, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// Prints the value followed by its SI unit, e.g. `9.81 [m s^-2]`.
+impl<V: fmt::Debug, D: Dimension> fmt::Debug for Quantity<V, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} [{}]", self.value, D::symbol())
+    }
+}
+
+impl<V> From<V> for Quantity<V, Dimensionless> {
+    fn from(value: V) -> Self {
+        Self::new(value)
+    }
+}
+
+// ============================================================================
+// Arithmetic: same dimension
+// ============================================================================
+
+impl<V: Add<Output = V>, D> Add for Quantity<V, D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl<V: Sub<Output = V>, D> Sub for Quantity<V, D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl<V: Neg<Output = V>, D> Neg for Quantity<V, D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.value)
+    }
+}
+
+impl<V: AddAssign, D> AddAssign for Quantity<V, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<V: SubAssign, D> SubAssign for Quantity<V, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<V: std::iter::Sum, D> std::iter::Sum for Quantity<V, D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(|q| q.value).sum())
+    }
+}
+
+// ============================================================================
+// Arithmetic: dimensions combine
+// ============================================================================
+
+// Quantity * Quantity
+impl<V, W, D1, D2> Mul<Quantity<W, D2>> for Quantity<V, D1>
+where
+    V: Mul<W>,
+    D1: Mul<D2>,
+{
+    type Output = Quantity<V::Output, Prod<D1, D2>>;
+    fn mul(self, rhs: Quantity<W, D2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+// Quantity / Quantity
+impl<V, W, D1, D2> Div<Quantity<W, D2>> for Quantity<V, D1>
+where
+    V: Div<W>,
+    D1: Div<D2>,
+{
+    type Output = Quantity<V::Output, Quot<D1, D2>>;
+    fn div(self, rhs: Quantity<W, D2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+// Real / Quantity: the reciprocal dimension
+impl<V, D> Div<Quantity<V, D>> for Real
+where
+    Real: Div<V>,
+    Dimensionless: Div<D>,
+{
+    type Output = Quantity<<Real as Div<V>>::Output, Quot<Dimensionless, D>>;
+    fn div(self, rhs: Quantity<V, D>) -> Self::Output {
+        Quantity::new(self / rhs.value)
+    }
+}
+
+// MultiArray * scalar quantity, e.g. `Vector3::new(1.0, 2.0, 0.0) * MILLIMETRE`
+impl<S, B, D> Mul<Quantity<Real, D>> for MultiArray<Real, S, B>
+where
+    MultiArray<Real, S, B>: Mul<Real>,
+{
+    type Output = Quantity<<MultiArray<Real, S, B> as Mul<Real>>::Output, D>;
+    fn mul(self, rhs: Quantity<Real, D>) -> Self::Output {
+        Quantity::new(self * rhs.value)
+    }
+}
+
+// Scalar quantity * MultiArray
+impl<S, B, D> Mul<MultiArray<Real, S, B>> for Quantity<Real, D>
+where
+    Real: Mul<MultiArray<Real, S, B>>,
+{
+    type Output = Quantity<<Real as Mul<MultiArray<Real, S, B>>>::Output, D>;
+    fn mul(self, rhs: MultiArray<Real, S, B>) -> Self::Output {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+// ============================================================================
+// Arithmetic: dimensionless Real factors
+// ============================================================================
+
+impl<V: Mul<Real>, D> Mul<Real> for Quantity<V, D> {
+    type Output = Quantity<V::Output, D>;
+    fn mul(self, rhs: Real) -> Self::Output {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+impl<V, D> Mul<Quantity<V, D>> for Real
+where
+    Real: Mul<V>,
+{
+    type Output = Quantity<<Real as Mul<V>>::Output, D>;
+    fn mul(self, rhs: Quantity<V, D>) -> Self::Output {
+        Quantity::new(self * rhs.value)
+    }
+}
+
+impl<V: Div<Real>, D> Div<Real> for Quantity<V, D> {
+    type Output = Quantity<V::Output, D>;
+    fn div(self, rhs: Real) -> Self::Output {
+        Quantity::new(self.value / rhs)
+    }
+}
+
+impl<V: MulAssign<Real>, D> MulAssign<Real> for Quantity<V, D> {
+    fn mul_assign(&mut self, rhs: Real) {
+        self.value *= rhs;
+    }
+}
+
+impl<V: DivAssign<Real>, D> DivAssign<Real> for Quantity<V, D> {
+    fn div_assign(&mut self, rhs: Real) {
+        self.value /= rhs;
+    }
+}
+
+// ============================================================================
+// Fields of quantities
+// ============================================================================
+
+// Same components and scalar as `V`: the flat solver view is unchanged.
+impl<V: FieldElement, D: Dimension> FieldElement for Quantity<V, D> {
+    type Scalar = V::Scalar;
+    const COMPONENTS: usize = V::COMPONENTS;
+    fn component(&self, i: usize) -> V::Scalar {
+        self.value.component(i)
+    }
+    fn from_scalars(data: &[V::Scalar]) -> Self {
+        Self::new(V::from_scalars(data))
+    }
+}
+
+impl<V: LinearElement, D: Dimension> LinearElement for Quantity<V, D> {}
+
+impl<V, D> Field<Quantity<V, D>> {
+    /// Tags every value of `values` (in SI base units) with dimension `D`.
+    pub fn from_values(values: Field<V>) -> Self {
+        values.into_vec().into_iter().map(Quantity::new).collect()
+    }
+
+    /// Drops the dimension, returning the values in SI base units.
+    pub fn into_values(self) -> Field<V> {
+        self.into_vec()
+            .into_iter()
+            .map(Quantity::into_value)
+            .collect()
+    }
+
+    /// The values in SI base units (zero-copy).
+    pub fn values(&self) -> &[V] {
+        let q = self.as_slice();
+        // SAFETY: `Quantity<V, D>` is `#[repr(transparent)]` over `V`.
+        unsafe { std::slice::from_raw_parts(q.as_ptr() as *const V, q.len()) }
+    }
+
+    /// Mutable values in SI base units (zero-copy).
+    pub fn values_mut(&mut self) -> &mut [V] {
+        let q = self.as_mut_slice();
+        // SAFETY: `Quantity<V, D>` is `#[repr(transparent)]` over `V`.
+        unsafe { std::slice::from_raw_parts_mut(q.as_mut_ptr() as *mut V, q.len()) }
+    }
+}
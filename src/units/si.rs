@@ -0,0 +1,70 @@
+//! Quantity aliases and SI unit constants.
+//!
+//! Each alias defaults to a scalar value: `Length` is a `Real` length,
+//! `Velocity<Vector3>` a velocity vector. Unit constants are scalar
+//! quantities, so `2.5 * MILLIMETRE` or `Vector3::new(1.0, 0.0, 0.0) *
+//! MILLIMETRE` converts to SI base units on construction.
+
+use super::dim;
+use super::quantity::Quantity;
+use crate::common::Real;
+
+// ============================================================================
+// Quantity aliases
+// ============================================================================
+
+pub type Dimensionless<V = Real> = Quantity<V, dim::Dimensionless>;
+pub type Length<V = Real> = Quantity<V, dim::Length>;
+pub type Mass<V = Real> = Quantity<V, dim::Mass>;
+pub type Time<V = Real> = Quantity<V, dim::Time>;
+pub type Current<V = Real> = Quantity<V, dim::Current>;
+pub type Temperature<V = Real> = Quantity<V, dim::Temperature>;
+pub type Amount<V = Real> = Quantity<V, dim::Amount>;
+pub type LuminousIntensity<V = Real> = Quantity<V, dim::LuminousIntensity>;
+pub type Area<V = Real> = Quantity<V, dim::Area>;
+pub type Volume<V = Real> = Quantity<V, dim::Volume>;
+pub type Frequency<V = Real> = Quantity<V, dim::Frequency>;
+pub type Velocity<V = Real> = Quantity<V, dim::Velocity>;
+pub type Acceleration<V = Real> = Quantity<V, dim::Acceleration>;
+pub type Density<V = Real> = Quantity<V, dim::Density>;
+pub type Momentum<V = Real> = Quantity<V, dim::Momentum>;
+pub type Force<V = Real> = Quantity<V, dim::Force>;
+pub type Pressure<V = Real> = Quantity<V, dim::Pressure>;
+pub type Energy<V = Real> = Quantity<V, dim::Energy>;
+pub type Power<V = Real> = Quantity<V, dim::Power>;
+pub type DynamicViscosity<V = Real> = Quantity<V, dim::DynamicViscosity>;
+pub type KinematicViscosity<V = Real> = Quantity<V, dim::KinematicViscosity>;
+
+// ============================================================================
+// Unit constants (value in SI base units)
+// ============================================================================
+
+pub const METRE: Length = Quantity::new(1.0);
+pub const CENTIMETRE: Length = Quantity::new(1e-2);
+pub const MILLIMETRE: Length = Quantity::new(1e-3);
+pub const MICROMETRE: Length = Quantity::new(1e-6);
+pub const KILOMETRE: Length = Quantity::new(1e3);
+
+pub const KILOGRAM: Mass = Quantity::new(1.0);
+pub const GRAM: Mass = Quantity::new(1e-3);
+
+pub const SECOND: Time = Quantity::new(1.0);
+pub const MILLISECOND: Time = Quantity::new(1e-3);
+pub const MINUTE: Time = Quantity::new(60.0);
+pub const HOUR: Time = Quantity::new(3600.0);
+
+pub const AMPERE: Current = Quantity::new(1.0);
+/// Temperature differences only: no offset is applied for Celsius.
+pub const KELVIN: Temperature = Quantity::new(1.0);
+pub const MOLE: Amount = Quantity::new(1.0);
+pub const CANDELA: LuminousIntensity = Quantity::new(1.0);
+
+pub const HERTZ: Frequency = Quantity::new(1.0);
+pub const NEWTON: Force = Quantity::new(1.0);
+pub const PASCAL: Pressure = Quantity::new(1.0);
+pub const KILOPASCAL: Pressure = Quantity::new(1e3);
+pub const MEGAPASCAL: Pressure = Quantity::new(1e6);
+pub const GIGAPASCAL: Pressure = Quantity::new(1e9);
+pub const BAR: Pressure = Quantity::new(1e5);
+pub const JOULE: Energy = Quantity::new(1.0);
+pub const WATT: Power = Quantity::new(1.0);
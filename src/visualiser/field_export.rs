@@ -30,7 +30,8 @@
 //! ```
 
 use super::FieldArray;
-use crate::fields::{ScalarField, SolverInterop, Vector3Field};
+use crate::common::Real;
+use crate::fields::{Field, FieldElement, ScalarField, SoaField, SolverInterop, Vector3Field};
 
 /// Convert ScalarField to VTK FieldArray for visualization.
 ///
@@ -71,3 +72,63 @@ pub fn scalar_field_to_vtk_array<'a>(name: &'a str, field: &'a ScalarField) -> F
 pub fn vector3_field_to_vtk_array<'a>(name: &'a str, field: &'a Vector3Field) -> FieldArray<'a> {
     FieldArray::from_slice(name, field.as_flat_slice(), 3)
 }
+
+/// Convert a named field to VTK FieldArray, taking the name from its metadata.
+///
+/// Returns `None` if the field has no metadata.
+///
+/// # Example
+/// ```
+/// # use strelitzia::multiarray::Vector3;
+/// # use strelitzia::fields::*;
+/// # use strelitzia::visualiser::*;
+/// let velocity: Vector3Field = [Vector3::new(1.0, 0.0, 0.0)].into_iter().collect();
+/// let velocity = velocity.with_meta(FieldMeta::new("velocity"));
+/// let vtk_array = field_to_vtk_array(&velocity).unwrap();
+/// assert_eq!((vtk_array.name, vtk_array.num_components), ("velocity", 3));
+/// ```
+pub fn field_to_vtk_array<T>(field: &Field<T>) -> Option<FieldArray<'_>>
+where
+    T: FieldElement<Scalar = Real>,
+{
+    let name = field.name()?;
+    Some(FieldArray::from_slice(
+        name,
+        field.as_flat_slice(),
+        T::COMPONENTS,
+    ))
+}
+
+/// Convert a struct-of-arrays field to VTK FieldArray for visualization.
+///
+/// VTK wants the components of each element next to each other, so the
+/// components are interleaved into `buffer` (overwriting it), which the
+/// returned array borrows. Reuse the buffer across time steps to avoid
+/// reallocating.
+///
+/// # Arguments
+/// - `name`: Field name displayed in ParaView
+/// - `field`: The field data, one array per component
+/// - `buffer`: Scratch storage for the interleaved values
+///
+/// # Example
+/// ```
+/// # use strelitzia::multiarray::Vector3;
+/// # use strelitzia::fields::*;
+/// # use strelitzia::visualiser::*;
+/// let velocity: Vector3SoaField = [Vector3::new(1.0, 0.0, 0.0)].into_iter().collect();
+/// let mut buffer = Vec::new();
+/// let vtk_array = soa_field_to_vtk_array("velocity", &velocity, &mut buffer);
+/// assert_eq!(vtk_array.num_components, 3);
+/// ```
+pub fn soa_field_to_vtk_array<'a, T>(
+    name: &'a str,
+    field: &SoaField<T>,
+    buffer: &'a mut Vec<Real>,
+) -> FieldArray<'a>
+where
+    T: FieldElement<Scalar = Real>,
+{
+    field.interleave_into(buffer);
+    FieldArray::from_slice(name, buffer, T::COMPONENTS)
+}
@@ -16,6 +16,7 @@ use bytemuck::Pod;
 /// | `Hexa` | 12 | Hexahedron |
 /// | `Wedge` | 13 | Triangular prism |
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellType {
     Vertex,
     Edge,
@@ -0,0 +1,182 @@
+//! Tests for approximate equality: `Tolerance`, `ApproxEq` on scalars,
+//! `MultiArray`, `Field`, `CvtState` and `Mesh`, and the assertion macros.
+
+use strelitzia::approx::{ApproxEq, Mismatch, Tolerance};
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::{Field, ScalarField, Vector3Field};
+use strelitzia::meshgen::cvt::CvtState;
+use strelitzia::meshgen::mesh::Mesh;
+use strelitzia::multiarray::{DynMatrix, Matrix2, Point2, Vector3};
+use strelitzia::{assert_approx_eq, assert_approx_ne};
+
+#[test]
+fn test_scalar_tolerances() {
+    let a: Real = 1.0;
+    let b = a + 1e-9;
+    assert!(a.abs_diff_eq(&b, 1e-8));
+    assert!(!a.abs_diff_eq(&b, 1e-10));
+    assert!(a.relative_eq(&b, 1e-8));
+    assert!((1e6 as Real).relative_eq(&(1e6 + 1e-3), 1e-8));
+    assert!(!(1e6 as Real).abs_diff_eq(&(1e6 + 1e-3), 1e-8));
+
+    let next = Real::from_bits(a.to_bits() + 3);
+    assert!(a.ulps_eq(&next, 3));
+    assert!(!a.ulps_eq(&next, 2));
+    // ULPs never match across a sign change; absolute tolerance handles zero.
+    assert!(!(1e-300 as Real).ulps_eq(&-1e-300, 1 << 20));
+    assert!((1e-300 as Real).abs_diff_eq(&-1e-300, 1e-12));
+
+    assert!(Real::INFINITY.approx_eq(&Real::INFINITY, Tolerance::EXACT));
+    assert!(!Real::INFINITY.approx_eq(&Real::MAX, Tolerance::relative(1.0)));
+    assert!(!Real::NAN.approx_eq(&Real::NAN, Tolerance::default()));
+
+    let tol = Tolerance::absolute(1e-3).with_rel(1e-6).with_ulps(2);
+    assert_eq!((tol.abs, tol.rel, tol.ulps), (1e-3, 1e-6, 2));
+    assert_eq!(tol.to_string(), "abs = 1e-3, rel = 1e-6, ulps = 2");
+}
+
+#[test]
+fn test_first_mismatch_reports_index_values_and_difference() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(1.0, 2.5, 4.0);
+    let m = a.first_mismatch(&b, Tolerance::default()).unwrap();
+    assert_eq!(m.index, "[1]");
+    assert_eq!((m.left.as_str(), m.right.as_str()), ("2.0", "2.5"));
+    assert_eq!(m.diff, Some(0.5));
+    assert_eq!(
+        m.to_string(),
+        "at [1]: left = 2.0, right = 2.5, diff = 5e-1"
+    );
+
+    // Matrices in storage (column-major) order.
+    let p = Matrix2::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+    let q = Matrix2::from_slice(&[1.0, 2.0, 3.5, 4.0]);
+    assert_eq!(p.first_mismatch(&q, Tolerance::EXACT).unwrap().index, "[2]");
+
+    // Dynamic arrays check their shape before any component.
+    let d = DynMatrix::<Real>::zeros(2, 2);
+    let m = d.first_mismatch(&DynMatrix::zeros(1, 2), Tolerance::EXACT);
+    assert_eq!(m, Some(Mismatch::shape((2, 2), (1, 2))));
+    let wide = DynMatrix::<Real>::zeros(2, 3);
+    let m = wide.first_mismatch(&DynMatrix::zeros(3, 2), Tolerance::EXACT);
+    assert_eq!(
+        m.unwrap().to_string(),
+        "at shape: left = (2, 3), right = (3, 2)"
+    );
+    assert!(!wide.approx_eq(&DynMatrix::zeros(3, 2), Tolerance::default()));
+
+    let z = Complex::new(1.0, 2.0);
+    let m = z.first_mismatch(&Complex::new(1.0, 2.1), Tolerance::absolute(1e-3));
+    assert_eq!(m.unwrap().index, "im");
+}
+
+#[test]
+fn test_fields_nest_element_locations() {
+    let a: Vector3Field = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 3.0)]
+        .into_iter()
+        .collect();
+    let mut b = a.clone();
+    assert!(a.approx_eq(&b, Tolerance::EXACT));
+
+    b[1] = Vector3::new(1.0, 2.0, 3.0 + 1e-6);
+    let m = a.first_mismatch(&b, Tolerance::default()).unwrap();
+    assert_eq!(m.index, "[1][2]");
+    assert!(a.approx_eq(&b, Tolerance::relative(1e-6)));
+
+    let short: Vector3Field = [Vector3::zeros()].into_iter().collect();
+    assert_eq!(
+        a.first_mismatch(&short, Tolerance::default())
+            .unwrap()
+            .index,
+        "len()"
+    );
+
+    let c: Field<Complex> = [Complex::new(0.0, 1.0)].into_iter().collect();
+    let d: Field<Complex> = [Complex::new(0.1, 1.0)].into_iter().collect();
+    assert_eq!(
+        c.first_mismatch(&d, Tolerance::EXACT).unwrap().index,
+        "[0].re"
+    );
+}
+
+fn state(iteration: usize, seeds: &[Real], residual: Real) -> CvtState<Real> {
+    CvtState {
+        iteration,
+        seeds: seeds.iter().copied().collect(),
+        energy: 0.25,
+        residual,
+        max_mass: Real::NAN,
+        min_mass: Real::NAN,
+    }
+}
+
+#[test]
+fn test_cvt_state_approx_eq() {
+    let a = state(3, &[0.25, 0.75], 1e-3);
+    let b = state(3, &[0.25, 0.75 + 1e-14], 1e-3);
+    // NaN diagnostics (not computed) match each other.
+    assert_approx_eq!(a, b);
+
+    let m = a.first_mismatch(&state(4, &[0.25, 0.75], 1e-3), Tolerance::default());
+    assert_eq!(m.unwrap().index, "iteration");
+    let m = a.first_mismatch(&state(3, &[0.25, 0.8], 1e-3), Tolerance::default());
+    assert_eq!(m.unwrap().index, "seeds[1]");
+    let m = a.first_mismatch(&state(3, &[0.25, 0.75], 2e-3), Tolerance::default());
+    assert_eq!(m.unwrap().index, "residual");
+}
+
+#[test]
+fn test_mesh_approx_eq() {
+    let x: ScalarField = [0.0, 1.0, 2.0].into_iter().collect();
+    let y: ScalarField = [0.0, 1.0].into_iter().collect();
+    let a = Mesh::<Point2>::tensor_product(&x, &y);
+
+    let mut b = a.clone();
+    b.vertices[3] += Point2::new(1e-13, 0.0);
+    assert_approx_eq!(a, b);
+    b.vertices[3] += Point2::new(0.0, 1e-3);
+    assert_eq!(
+        a.first_mismatch(&b, Tolerance::default()).unwrap().index,
+        "vertices[3][1]"
+    );
+
+    let mut c = a.clone();
+    c.cells[1].swap(0, 1);
+    let m = a.first_mismatch(&c, Tolerance::default()).unwrap();
+    assert_eq!(m.index, "cells[1]");
+    assert_eq!(m.diff, None);
+
+    let mut d = a.clone();
+    d.cells.pop();
+    assert_eq!(
+        a.first_mismatch(&d, Tolerance::default()).unwrap().index,
+        "cells.len()"
+    );
+}
+
+#[test]
+fn test_assertion_macros_accept_tolerances() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = a * (1.0 + 1e-10);
+    assert_approx_eq!(a, b, rel = 1e-9);
+    assert_approx_eq!(a, b, abs = 1e-9);
+    assert_approx_eq!(a, a, ulps = 0);
+    assert_approx_eq!(a, b, tol = Tolerance::relative(1e-9).with_ulps(1));
+    assert_approx_ne!(a, b);
+    assert_approx_ne!(a, b, ulps = 4);
+}
+
+#[test]
+#[should_panic(expected = "first mismatch at [1]: left = 2.0, right = 2.5, diff = 5e-1")]
+fn test_assert_approx_eq_failure_message() {
+    let a: ScalarField = [1.0, 2.0, 3.0].into_iter().collect();
+    let b: ScalarField = [1.0, 2.5, 3.5].into_iter().collect();
+    assert_approx_eq!(a, b, abs = 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "assertion `a ≉ a` failed")]
+fn test_assert_approx_ne_failure_message() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    assert_approx_ne!(a, a);
+}
@@ -0,0 +1,105 @@
+//! Tests for BLAS level-1 field kernels: agreement with element arithmetic,
+//! summation accuracy, and length checks.
+
+use strelitzia::common::Real;
+use strelitzia::fields::*;
+use strelitzia::multiarray::linalg::VectorOps;
+use strelitzia::multiarray::{Matrix3, SymMatrix3, Vector3};
+use strelitzia::units::Length;
+
+fn vectors(n: usize, scale: Real) -> Vector3Field {
+    (0..n)
+        .map(|i| Vector3::new(i as Real, scale, -scale * i as Real))
+        .collect()
+}
+
+#[test]
+fn test_update_kernels_match_element_arithmetic() {
+    let x = vectors(50, 2.0);
+    let y0 = vectors(50, -1.0);
+
+    let mut y = y0.clone();
+    y.axpy(0.5, &x);
+    for i in 0..50 {
+        assert_eq!(y[i], y0[i] + x[i] * 0.5);
+    }
+
+    let mut y = y0.clone();
+    y.axpby(3.0, &x, -2.0);
+    for i in 0..50 {
+        assert_eq!(y[i], x[i] * 3.0 + y0[i] * -2.0);
+    }
+
+    y.scale(0.25);
+    let mut expected = y0.clone();
+    expected.axpby(3.0, &x, -2.0);
+    expected *= 0.25;
+    assert_eq!(y.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_reductions_over_flat_components() {
+    let x = vectors(20, 2.0);
+    let y = vectors(20, 3.0);
+    let expected: Real = x.iter().zip(y.iter()).map(|(a, b)| a.dot(b)).sum();
+    for summation in [Summation::Naive, Summation::Pairwise, Summation::Kahan] {
+        assert_eq!(x.dot_with(&y, summation), expected);
+    }
+    assert_eq!(x.dot(&y), expected);
+    assert_eq!(x.norm2(), x.dot(&x).sqrt());
+    assert_eq!(x.norm_inf(), 38.0);
+
+    // Matrices contribute all nine components.
+    let m: Matrix3Field = [Matrix3::identity() * -2.0].into_iter().collect();
+    assert_eq!(m.norm2_with(Summation::Kahan), 12.0_f64.sqrt());
+    assert_eq!(m.norm_inf(), 2.0);
+
+    // Packed symmetric tensors and quantities are linear elements too.
+    let s: SymMatrix3Field = [SymMatrix3::new(1.0, 2.0, 2.0, 0.0, 0.0, 0.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(s.norm2(), 3.0);
+    let mut dx: Field<Length<Vector3>> = Field::from_values(x.clone());
+    dx.scale(2.0);
+    assert_eq!(dx.norm_inf(), 76.0);
+
+    let empty = ScalarField::new();
+    assert_eq!(
+        (empty.dot(&empty), empty.norm2(), empty.norm_inf()),
+        (0.0, 0.0, 0.0)
+    );
+    let nan: ScalarField = [1.0, Real::NAN, 5.0].into_iter().collect();
+    assert!(nan.norm_inf().is_nan());
+}
+
+#[test]
+fn test_compensated_summation_accuracy() {
+    // 1 followed by many terms below half an ulp of 1.
+    let n = 100_000;
+    let tiny = 1e-17;
+    let x: ScalarField = std::iter::once(1.0)
+        .chain(std::iter::repeat_n(tiny, n))
+        .collect();
+    let ones = ScalarField::from_vec(vec![1.0; n + 1]);
+    let exact = 1.0 + n as Real * tiny;
+
+    let naive = x.dot_with(&ones, Summation::Naive);
+    let pairwise = x.dot_with(&ones, Summation::Pairwise);
+    let kahan = x.dot_with(&ones, Summation::Kahan);
+    assert_eq!(naive, 1.0);
+    assert!((pairwise - exact).abs() < 0.01 * (exact - 1.0));
+    assert!((kahan - exact).abs() <= Real::EPSILON);
+
+    // Cancellation: the large terms cancel exactly only with compensation.
+    let big: ScalarField = [1e16, 1.0, -1e16].into_iter().collect();
+    let ones = ScalarField::from_vec(vec![1.0; 3]);
+    assert_eq!(big.dot_with(&ones, Summation::Naive), 0.0);
+    assert_eq!(big.dot_with(&ones, Summation::Kahan), 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Fields must have same length")]
+fn test_length_mismatch_panics() {
+    let mut y = vectors(3, 1.0);
+    y.axpy(1.0, &vectors(4, 1.0));
+}
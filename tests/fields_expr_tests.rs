@@ -0,0 +1,95 @@
+//! Tests for lazy field expressions: agreement with compound statements,
+//! element types beyond `Real`, and length checks.
+
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::*;
+use strelitzia::multiarray::Vector3;
+use strelitzia::units::Length;
+
+fn ramp(n: usize, scale: Real) -> ScalarField {
+    (0..n).map(|i| scale * i as Real + 0.25).collect()
+}
+
+#[test]
+fn test_assign_matches_compound_statements() {
+    let (a, b, c) = (ramp(100, 1.0), ramp(100, -3.0), ramp(100, 0.5));
+
+    let mut expected = a.clone();
+    let mut tmp = b.clone();
+    tmp *= 2.0;
+    expected += &tmp;
+    expected -= &c;
+
+    let mut u = ScalarField::from_vec(vec![0.0; 100]);
+    u.assign(&a + &b * 2.0 - &c);
+    assert_eq!(u.as_slice(), expected.as_slice());
+
+    // `+=` / `-=` add the expression in the same single pass.
+    u -= &a + &b * 2.0;
+    assert_eq!(u.as_slice(), (-&c).to_field().as_slice());
+    u += 2.0 * (&c - &a) / 4.0;
+    for i in 0..100 {
+        assert_eq!(u[i], -c[i] + 2.0 * (c[i] - a[i]) / 4.0);
+    }
+}
+
+#[test]
+fn test_expressions_are_lazy_and_reusable() {
+    let a = ramp(4, 1.0);
+    let b = ramp(4, 2.0);
+    let expr = (&a - &b) * 3.0;
+    assert_eq!(expr.len(), 4);
+    assert!(!expr.is_empty());
+
+    // The same expression evaluates into several targets.
+    let mut u = ScalarField::from_vec(vec![1.0; 4]);
+    let mut v = ScalarField::from_vec(vec![0.0; 4]);
+    u += expr;
+    v.assign(expr);
+    assert_eq!(u[3], 1.0 + 3.0 * (a[3] - b[3]));
+    assert_eq!(v.as_slice(), expr.to_field().as_slice());
+
+    let empty = ScalarField::new();
+    assert!((&empty + &empty).is_empty());
+}
+
+#[test]
+fn test_vector_and_complex_elements() {
+    let x: Vector3Field = (0..10)
+        .map(|i| Vector3::new(i as Real, 1.0, -(i as Real)))
+        .collect();
+    let v: Vector3Field = (0..10).map(|_| Vector3::new(1.0, 2.0, 3.0)).collect();
+    let dt = 0.5;
+
+    let mut next = Vector3Field::from_vec(vec![Vector3::zeros(); 10]);
+    next.assign(&x + &v * dt);
+    assert_eq!(next[4], Vector3::new(4.5, 2.0, -2.5));
+    next -= -(&v / 2.0);
+    assert_eq!(next[4], Vector3::new(5.0, 3.0, -1.0));
+
+    let z: ComplexField = (0..5).map(|i| Complex::new(i as Real, 1.0)).collect();
+    let mut w = ComplexField::from_vec(vec![Complex::new(0.0, 0.0); 5]);
+    w.assign(Complex::new(0.0, 1.0) * &z + &z / Complex::new(2.0, 0.0));
+    assert_eq!(w[2], Complex::new(0.0, 2.5)); // i (2 + i) + (2 + i) / 2
+
+    // Dimensioned elements: the element operators decide what may combine.
+    let x0 = Field::<Length>::from_values(ramp(3, 1.0));
+    let dx = Field::<Length>::from_values(ramp(3, 2.0));
+    let mut x = Field::<Length>::from_values(ScalarField::from_vec(vec![0.0; 3]));
+    x.assign(&x0 + &dx * 0.5);
+    assert_eq!(x.values()[2], 2.25 + 0.5 * 4.25);
+}
+
+#[test]
+#[should_panic(expected = "Fields must have same length")]
+fn test_operand_length_mismatch_panics() {
+    let _ = &ramp(3, 1.0) + &ramp(4, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Fields must have same length")]
+fn test_target_length_mismatch_panics() {
+    let (a, b) = (ramp(3, 1.0), ramp(3, 2.0));
+    let mut u = ScalarField::new();
+    u.assign(&a - &b);
+}
@@ -0,0 +1,154 @@
+//! Tests for parallel field operations: agreement with the serial
+//! operations and thread-count-independent reductions.
+
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::*;
+use strelitzia::multiarray::Vector3;
+
+/// `threads` threads with chunks small enough that every thread gets work.
+fn settings(threads: usize) -> ParallelSettings {
+    ParallelSettings {
+        threads,
+        min_chunk_len: 1,
+    }
+}
+
+/// Values spanning many magnitudes, so that summation order matters.
+fn scalars(n: usize) -> ScalarField {
+    (0..n)
+        .map(|i| {
+            ((i * 7919) % 1009) as Real
+                * (1.0 + 1e-9 * i as Real)
+                * Real::powi(10.0, (i % 13) as i32 - 6)
+        })
+        .collect()
+}
+
+#[test]
+fn test_elementwise_ops_match_serial() {
+    let a = scalars(50_001);
+    let b = scalars(50_001).par(settings(3)).map(|x| 0.5 * x + 1.0);
+
+    for threads in [1, 2, 5, 16] {
+        let mut serial = a.clone();
+        serial += &b;
+        serial *= 3.0;
+        serial -= 1.0;
+        serial /= 7.0;
+
+        let mut parallel = a.clone();
+        let mut view = parallel.par_mut(settings(threads));
+        view += &b;
+        view *= 3.0;
+        view -= 1.0;
+        view /= 7.0;
+        assert_eq!(parallel.as_slice(), serial.as_slice(), "{threads} threads");
+
+        let mut view = parallel.par_mut(settings(threads));
+        view -= &b;
+        view += 2.0;
+        view.fill(4.0);
+        assert!(parallel.iter().all(|&x| x == 4.0));
+    }
+}
+
+#[test]
+fn test_map_zip_map_and_for_each() {
+    let v: Vector3Field = (0..10_000)
+        .map(|i| Vector3::new(i as Real, 1.0, -(i as Real)))
+        .collect();
+    let par = settings(4);
+
+    let norms: ScalarField = v.par(par).map(|x| x.x() + x.z());
+    assert_eq!(norms.len(), v.len());
+    assert!(norms.iter().all(|&n| n == 0.0));
+
+    let w = v
+        .par(par)
+        .zip_map(&norms, |x, n| *x * 2.0 + Vector3::new(*n, 0.0, 0.0));
+    assert_eq!(w[9_999], Vector3::new(19_998.0, 2.0, -19_998.0));
+
+    let mut indexed: Field<(usize, Real)> = (0..10_000).map(|i| (i, 0.0)).collect();
+    indexed
+        .par_mut(par)
+        .for_each(|(i, x)| *x = (*i * *i) as Real);
+    assert!(indexed.iter().all(|&(i, x)| x == (i * i) as Real));
+
+    indexed
+        .par_mut(par)
+        .zip_for_each(&norms, |(_, x), n| *x += n);
+    assert_eq!(indexed[100].1, 10_000.0);
+
+    let count = std::sync::atomic::AtomicUsize::new(0);
+    v.par(par).for_each(|x| {
+        if x.x() >= 5_000.0 {
+            count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    });
+    assert_eq!(count.into_inner(), 5_000);
+}
+
+#[test]
+fn test_reductions_are_deterministic() {
+    let f = scalars(3 * REDUCTION_BLOCK + 123);
+    let reference = f.par(settings(1)).sum();
+    for threads in [2, 3, 4, 7, 64] {
+        assert_eq!(
+            f.par(settings(threads)).sum().to_bits(),
+            reference.to_bits()
+        );
+    }
+    assert_eq!(f.par(ParallelSettings::default()).sum(), reference);
+    assert!((reference - f.sum()).abs() <= 1e-12 * reference.abs());
+
+    // Same element as the serial reductions.
+    for threads in [1, 4, 9] {
+        assert_eq!(f.par(settings(threads)).max(), f.max());
+        assert_eq!(f.par(settings(threads)).min(), f.min());
+    }
+
+    let z: ComplexField = (0..20_000).map(|i| Complex::new(i as Real, 1.0)).collect();
+    assert_eq!(
+        z.par(settings(6)).sum(),
+        Complex::new((19_999 * 20_000 / 2) as Real, 20_000.0)
+    );
+}
+
+#[test]
+fn test_complex_scalars_and_small_fields() {
+    let mut z: ComplexField = (0..1_000).map(|i| Complex::new(i as Real, 0.0)).collect();
+    let mut view = z.par_mut(settings(4));
+    view *= Complex::new(0.0, 1.0);
+    view += Complex::new(1.0, 0.0);
+    assert_eq!(z[10], Complex::new(1.0, 10.0));
+
+    // Below `min_chunk_len`, and empty fields.
+    let small = scalars(10);
+    assert_eq!(
+        small.par(ParallelSettings::with_threads(8)).sum(),
+        small.sum()
+    );
+    let empty = ScalarField::new();
+    assert_eq!(empty.par(settings(4)).sum(), 0.0);
+    assert_eq!(empty.par(settings(4)).max(), None);
+    assert!(empty.par(settings(4)).map(|x| x * 2.0).is_empty());
+    // Zero threads runs on the calling thread.
+    assert_eq!(small.par(settings(0)).map(|x| 2.0 * x)[9], 2.0 * small[9]);
+}
+
+#[test]
+#[should_panic(expected = "Fields must have same length")]
+fn test_length_mismatch_panics() {
+    let mut a = scalars(100);
+    let mut view = a.par_mut(settings(2));
+    view += &scalars(99);
+}
+
+#[test]
+#[should_panic(expected = "worker panic")]
+fn test_worker_panics_propagate() {
+    // Only the last chunk panics, on a spawned thread.
+    let mut a: ScalarField = (0..10_000).map(|i| i as Real).collect();
+    a.par_mut(settings(4))
+        .for_each(|x| assert!(*x < 9_000.0, "worker panic"));
+}
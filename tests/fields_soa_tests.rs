@@ -0,0 +1,113 @@
+//! Tests for `SoaField<T>`: conversions to and from `Field<T>`, component
+//! slices and compound operators.
+
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::*;
+use strelitzia::multiarray::{Matrix3, Vector3, Vector3c};
+
+fn vectors() -> Vector3Field {
+    (0..5)
+        .map(|i| Vector3::new(i as Real, 10.0 + i as Real, -(i as Real)))
+        .collect()
+}
+
+#[test]
+fn test_round_trip_and_layout() {
+    let aos = vectors();
+    let soa = SoaField::from(&aos);
+    assert_eq!(soa.len(), 5);
+    assert_eq!(soa.component(0), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(soa.component(1), &[10.0, 11.0, 12.0, 13.0, 14.0]);
+    assert_eq!(&soa.as_slice()[10..], soa.component(2));
+    assert_eq!(soa.get(3), aos[3]);
+
+    let back: Vector3Field = (&soa).into();
+    assert_eq!(back.as_slice(), aos.as_slice());
+
+    // Interleaving reproduces the SolverInterop layout of the AoS field.
+    let mut flat = vec![99.0];
+    soa.interleave_into(&mut flat);
+    assert_eq!(flat, aos.as_flat_slice());
+
+    let empty = Vector3SoaField::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.components().count(), 3);
+    assert!(empty.to_field().is_empty());
+}
+
+#[test]
+fn test_matrix_components_are_column_major() {
+    // m[(i, j)] = 10 i + j, from column-major components.
+    let m = Matrix3::from_slice(&[0.0, 10.0, 20.0, 1.0, 11.0, 21.0, 2.0, 12.0, 22.0]);
+    let soa: Matrix3SoaField = [m, m * 2.0].into_iter().collect();
+    assert_eq!(soa.components().count(), 9);
+    // Component 1 is (1, 0), component 3 is (0, 1).
+    assert_eq!(soa.component(1), &[10.0, 20.0]);
+    assert_eq!(soa.component(3), &[1.0, 2.0]);
+    assert_eq!(soa.get(1), m * 2.0);
+}
+
+#[test]
+fn test_component_mutation() {
+    let mut soa = SoaField::from(&vectors());
+    for (c, component) in soa.components_mut().enumerate() {
+        for x in component {
+            *x += c as Real;
+        }
+    }
+    assert_eq!(soa.get(0), Vector3::new(0.0, 11.0, 2.0));
+
+    soa.set(4, Vector3::new(1.0, 2.0, 3.0));
+    soa.component_mut(2)[0] = 7.0;
+    let aos = soa.to_field();
+    assert_eq!(aos[4], Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(aos[0], Vector3::new(0.0, 11.0, 7.0));
+}
+
+#[test]
+fn test_compound_operators_match_field() {
+    let a = vectors();
+    let b: Vector3Field = a.iter().map(|v| *v * 0.5).collect();
+
+    let mut aos = a.clone();
+    aos += &b;
+    aos *= 3.0;
+    aos -= &a;
+    aos /= 4.0;
+
+    let mut soa = SoaField::from(&a);
+    soa += &SoaField::from(&b);
+    soa *= 3.0;
+    soa -= &SoaField::from(&a);
+    soa /= 4.0;
+    assert_eq!(soa, SoaField::from(&aos));
+
+    let mut s: SoaField<Real> = [1.0, 2.0].into_iter().collect();
+    s += 1.0;
+    s -= 0.5;
+    assert_eq!(s.component(0), &[1.5, 2.5]);
+
+    let mut c: SoaField<Vector3c> = [Vector3c::new(
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(2.0, 2.0),
+    )]
+    .into_iter()
+    .collect();
+    c *= Complex::new(0.0, 1.0);
+    c *= 2.0;
+    assert_eq!(c.component(1), &[Complex::new(-2.0, 0.0)]);
+}
+
+#[test]
+#[should_panic(expected = "Fields must have same length")]
+fn test_length_mismatch_panics() {
+    let mut a = SoaField::from(&vectors());
+    a += &Vector3SoaField::new();
+}
+
+#[test]
+#[should_panic(expected = "SoaField component 3 out of range for 3 components")]
+fn test_component_out_of_range_panics() {
+    let _ = SoaField::from(&vectors()).component(3);
+}
@@ -2,8 +2,12 @@
 
 use std::fs;
 use std::path::PathBuf;
-use strelitzia::fields::{ScalarField, Vector3Field};
-use strelitzia::multiarray::Vector3;
+use strelitzia::fields::{
+    FieldMeta, Matrix3SoaField, MeshLocation, ScalarField, Vector3Field, Vector3SoaField,
+};
+use strelitzia::meshgen::field_set::FieldSet;
+use strelitzia::meshgen::mesh::Mesh;
+use strelitzia::multiarray::{Matrix3, Point2, Vector3};
 use strelitzia::visualiser::*;
 
 /// RAII guard for automatic test file cleanup
@@ -202,6 +206,49 @@ fn test_vector3_field_vtk_export_base64() {
     verify_vtk_content(guard.path(), 2, "force", 3);
 }
 
+#[test]
+fn test_soa_field_vtk_export_matches_aos() {
+    let aos_guard = TestFileGuard::new("vector3_aos.vtu");
+    let soa_guard = TestFileGuard::new("vector3_soa.vtu");
+
+    let mut field = Vector3Field::new();
+    field.push(Vector3::new(1.0, 2.0, 3.0));
+    field.push(Vector3::new(4.0, 5.0, 6.0));
+    let soa = Vector3SoaField::from(&field);
+
+    let points: Vec<[f64; 3]> = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0]];
+    let stress: Matrix3SoaField = [Matrix3::identity(), Matrix3::zeros()]
+        .into_iter()
+        .collect();
+
+    let mut buffer = Vec::new();
+    let mut stress_buffer = Vec::new();
+    let aos_array = vector3_field_to_vtk_array("force", &field);
+    let soa_array = soa_field_to_vtk_array("force", &soa, &mut buffer);
+    let stress_array = soa_field_to_vtk_array("stress", &stress, &mut stress_buffer);
+    assert_eq!(soa_array.data, aos_array.data);
+    assert_eq!(stress_array.num_components, 9);
+
+    for (guard, array) in [(&aos_guard, aos_array), (&soa_guard, soa_array)] {
+        write_vtu::<_, 3>(
+            guard.path(),
+            &points,
+            None,
+            None,
+            &[array],
+            &[],
+            Encoding::Ascii,
+        )
+        .expect("Should write VTU");
+    }
+
+    verify_vtk_content(soa_guard.path(), 2, "force", 3);
+    assert_eq!(
+        fs::read_to_string(aos_guard.path()).unwrap(),
+        fs::read_to_string(soa_guard.path()).unwrap()
+    );
+}
+
 #[test]
 fn test_multiple_fields_export() {
     let guard = TestFileGuard::new("multiple_fields.vtu");
@@ -251,6 +298,49 @@ fn test_multiple_fields_export() {
     );
 }
 
+#[test]
+fn test_field_set_export_matches_manual_write() {
+    let set_guard = TestFileGuard::new("field_set.vtu");
+    let manual_guard = TestFileGuard::new("field_set_manual.vtu");
+
+    let x: ScalarField = [0.0, 1.0, 2.0].into_iter().collect();
+    let y: ScalarField = [0.0, 1.0].into_iter().collect();
+    let mesh = Mesh::<Point2>::tensor_product(&x, &y);
+
+    let temperature: ScalarField = (0..6).map(|i| 300.0 + i as f64).collect();
+    let velocity: Vector3Field = (0..2).map(|i| Vector3::new(i as f64, 1.0, 0.0)).collect();
+    let temperature = temperature.with_meta(
+        FieldMeta::new("temperature")
+            .with_units("K")
+            .with_location(MeshLocation::Vertex),
+    );
+    let velocity = velocity.with_meta(FieldMeta::new("velocity").with_location(MeshLocation::Cell));
+
+    let points: Vec<[f64; 3]> = mesh.vertices.iter().map(|p| [p.x(), p.y(), 0.0]).collect();
+    write_vtu::<_, 3>(
+        manual_guard.path(),
+        &points,
+        Some(&mesh.cells),
+        Some(&mesh.cell_types),
+        &[field_to_vtk_array(&temperature).unwrap()],
+        &[vector3_field_to_vtk_array("velocity", &velocity)],
+        Encoding::Ascii,
+    )
+    .expect("Should write VTU");
+
+    let mut set = FieldSet::new(&mesh);
+    set.insert(velocity).unwrap();
+    set.insert(temperature).unwrap();
+    set.write_vtu(set_guard.path(), Encoding::Ascii)
+        .expect("Should write VTU");
+
+    verify_vtk_content(set_guard.path(), 6, "temperature", 1);
+    assert_eq!(
+        fs::read_to_string(manual_guard.path()).unwrap(),
+        fs::read_to_string(set_guard.path()).unwrap()
+    );
+}
+
 // ============================================================================
 // Golden File Regression Tests
 // ============================================================================
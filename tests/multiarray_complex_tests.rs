@@ -0,0 +1,107 @@
+//! Tests for complex scalars: `Vector3c` / `Matrix3c` aliases, Hermitian
+//! `VectorOps`, and complex fields (`FieldElement`, `SolverInterop`, operators).
+
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::{ComplexField, FieldElement, SolverInterop, SumOps, Vector3cField};
+use strelitzia::multiarray::linalg::VectorOps;
+use strelitzia::multiarray::*;
+
+const TOL: Real = 1e-12;
+
+fn c(re: Real, im: Real) -> Complex {
+    Complex::new(re, im)
+}
+
+#[test]
+fn test_hermitian_dot_and_real_norms() {
+    let v = Vector2c::new(c(1.0, 1.0), c(0.0, 2.0));
+    let w = Vector2c::new(c(2.0, 0.0), c(1.0, -1.0));
+
+    // <v, w> = conj(1 + i) 2 + conj(2i) (1 - i) = (2 - 2i) + (-2 - 2i)
+    assert_eq!(v.dot(&w), c(0.0, -4.0));
+    assert_eq!(w.dot(&v), v.dot(&w).conj());
+    // <v, v> is real and equals the squared norm.
+    assert_eq!(v.dot(&v), c(6.0, 0.0));
+
+    let norm_squared: Real = v.norm_squared();
+    assert_eq!(norm_squared, 6.0);
+    assert!((v.norm() - 6.0_f64.sqrt()).abs() < TOL);
+    assert!((v.l1_norm() - (2.0_f64.sqrt() + 2.0)).abs() < TOL);
+    assert_eq!(v.linf_norm(), 2.0);
+    assert!((v.normalised().norm() - 1.0).abs() < TOL);
+}
+
+#[test]
+fn test_complex_arithmetic_and_real_scaling() {
+    let i = c(0.0, 1.0);
+    let v = Vector3c::new(c(1.0, 0.0), c(0.0, 1.0), c(1.0, 1.0));
+
+    assert_eq!(v * i, Vector3c::new(i, c(-1.0, 0.0), c(-1.0, 1.0)));
+    assert_eq!(i * v, v * i);
+    assert_eq!(2.0 * v, v + v);
+    assert_eq!(v * 2.0, v + v);
+    assert_eq!((v * 2.0) / 2.0, v);
+
+    let mut w = v;
+    w *= 3.0;
+    w /= c(0.0, 3.0);
+    assert_eq!(w, v * -i);
+
+    // A complex matrix acting on a complex vector, e.g. a rotation by e^{iθ}.
+    let m = Matrix3c::identity() * i;
+    assert_eq!(m * v, v * i);
+}
+
+#[test]
+fn test_complex_field_element_and_interleaved_solver_slices() {
+    assert_eq!(<Complex as FieldElement>::COMPONENTS, 1);
+    assert_eq!(<Vector3c as FieldElement>::COMPONENTS, 3);
+
+    let mut field: Vector3cField = [
+        Vector3c::new(c(1.0, 2.0), c(3.0, 4.0), c(5.0, 6.0)),
+        Vector3c::new(c(7.0, 8.0), c(9.0, 10.0), c(11.0, 12.0)),
+    ]
+    .into_iter()
+    .collect();
+    let expected: Vec<Real> = (1..=12).map(|k| k as Real).collect();
+    assert_eq!(field.as_flat_slice(), expected.as_slice());
+
+    // A solver writes [re, im] pairs back.
+    field.as_flat_slice_mut()[3] = -4.0;
+    assert_eq!(field[0].y(), c(3.0, -4.0));
+
+    let scalars: ComplexField = [c(1.0, -1.0), c(0.5, 0.25)].into_iter().collect();
+    assert_eq!(scalars.as_flat_slice(), &[1.0, -1.0, 0.5, 0.25]);
+    assert_eq!(
+        Vector3c::from_scalars(&[c(1.0, 0.0), c(0.0, 1.0), c(2.0, 2.0)]).component(2),
+        c(2.0, 2.0)
+    );
+}
+
+#[test]
+fn test_complex_field_operators() {
+    let i = c(0.0, 1.0);
+    let mut a: ComplexField = [c(1.0, 0.0), c(0.0, 1.0)].into_iter().collect();
+    let b: ComplexField = [c(1.0, 1.0), c(-1.0, 0.0)].into_iter().collect();
+
+    a += &b;
+    assert_eq!(a.as_slice(), &[c(2.0, 1.0), c(-1.0, 1.0)]);
+    a -= &b;
+    a *= i;
+    assert_eq!(a.as_slice(), &[i, c(-1.0, 0.0)]);
+    a /= i;
+    a *= 2.0;
+    a += i;
+    a -= 1.0;
+    assert_eq!(a.as_slice(), &[c(1.0, 1.0), c(-1.0, 3.0)]);
+    assert_eq!(a.sum(), c(0.0, 4.0));
+
+    let mut v: Vector3cField = [Vector3c::new(i, i, i)].into_iter().collect();
+    v *= 2.0;
+    v *= i;
+    v /= c(2.0, 0.0);
+    assert_eq!(
+        v[0],
+        Vector3c::new(c(-1.0, 0.0), c(-1.0, 0.0), c(-1.0, 0.0))
+    );
+}
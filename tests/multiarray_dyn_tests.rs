@@ -0,0 +1,109 @@
+//! Tests for `DynVector` / `DynMatrix` operator parity: runtime-checked
+//! products and the `VectorOps`, `Transpose`, `OuterProduct` and `Hadamard`
+//! traits.
+
+use strelitzia::common::{Complex, Real};
+use strelitzia::multiarray::linalg::{Hadamard, OuterProduct, Transpose, VectorOps};
+use strelitzia::multiarray::*;
+
+/// 2x3 matrix [[1, 2, 3], [4, 5, 6]].
+fn a() -> DynMatrix<Real> {
+    DynMatrix::from_slice(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0], 2, 3)
+}
+
+#[test]
+fn test_dyn_matrix_products() {
+    let a = a();
+    let b = a.transpose();
+    assert_eq!((b.nrows(), b.ncols()), (3, 2));
+
+    // A Aᵀ = [[14, 32], [32, 77]]
+    let aat = &a * &b;
+    assert_eq!(aat, DynMatrix::from_slice(&[14.0, 32.0, 32.0, 77.0], 2, 2));
+    assert_eq!(a.clone() * b.clone(), aat);
+    assert_eq!((&b * &a).nrows(), 3);
+
+    let x = DynVector::from_slice(&[1.0, 0.0, -1.0]);
+    assert_eq!(&a * &x, DynVector::from_slice(&[-2.0, -2.0]));
+    assert_eq!(a.clone() * x.clone(), DynVector::from_slice(&[-2.0, -2.0]));
+
+    // Owned matrix times views.
+    assert_eq!(&a * b.column(0), DynVector::from_slice(&[14.0, 32.0]));
+    assert_eq!(&a * b.view(0, 0, 3, 2), aat);
+    assert_eq!(
+        a.clone() * b.columns(1, 1),
+        DynMatrix::from_slice(&[32.0, 77.0], 2, 1)
+    );
+
+    // Column times row: the outer product.
+    let y = DynVector::from_slice(&[1.0, 2.0]);
+    let row = DynVector::from_slice(&[3.0, 4.0, 5.0]).transpose();
+    assert_eq!(&y * &row, y.outer(&DynVector::from_slice(&[3.0, 4.0, 5.0])));
+}
+
+#[test]
+#[should_panic(expected = "DynMatrix product: lhs ncols must equal rhs nrows")]
+fn test_dyn_matrix_product_shape_mismatch_panics() {
+    let _ = &a() * &a();
+}
+
+#[test]
+#[should_panic(expected = "DynMatrix product: vector length must equal ncols")]
+fn test_dyn_matrix_vector_shape_mismatch_panics() {
+    let _ = a() * DynVector::from_slice(&[1.0, 2.0]);
+}
+
+#[test]
+#[should_panic(expected = "rhs must have exactly one row")]
+fn test_dyn_vector_times_non_row_panics() {
+    let _ = DynVector::from_slice(&[1.0, 2.0]) * a();
+}
+
+#[test]
+fn test_dyn_vector_ops() {
+    let v = DynVector::from_slice(&[3.0, -4.0, 0.0]);
+    let w = DynVector::from_slice(&[1.0, 1.0, 1.0]);
+    assert_eq!(v.dot(&w), -1.0);
+    assert_eq!(v.norm(), 5.0);
+    assert_eq!(v.norm_squared(), 25.0);
+    assert_eq!(v.l1_norm(), 7.0);
+    assert_eq!(v.linf_norm(), 4.0);
+    assert_eq!(v.normalised(), DynVector::from_slice(&[0.6, -0.8, 0.0]));
+
+    // Hermitian for complex entries.
+    let c = DynVector::from_slice(&[Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)]);
+    assert_eq!(c.dot(&c), Complex::new(2.0, 0.0));
+}
+
+#[test]
+#[should_panic(expected = "DynVector dot: lengths differ")]
+fn test_dyn_vector_dot_length_mismatch_panics() {
+    let _ = DynVector::from_slice(&[1.0, 2.0]).dot(&DynVector::from_slice(&[1.0]));
+}
+
+#[test]
+fn test_dyn_transpose_outer_and_hadamard() {
+    let a = a();
+    assert_eq!(a.transpose().transpose(), a);
+    let row = DynVector::from_slice(&[1.0, 2.0, 3.0]).transpose();
+    assert_eq!((row.nrows(), row.ncols()), (1, 3));
+    assert_eq!(row.as_inner()[(0, 2)], 3.0);
+
+    let u = DynVector::from_slice(&[1.0, 2.0]);
+    let v = DynVector::from_slice(&[3.0, 4.0, 5.0]);
+    let uv = u.outer(&v);
+    assert_eq!((uv.nrows(), uv.ncols()), (2, 3));
+    assert_eq!(uv.as_inner()[(1, 2)], 10.0);
+
+    assert_eq!(
+        a.hadamard(&a),
+        DynMatrix::from_slice(&[1.0, 16.0, 4.0, 25.0, 9.0, 36.0], 2, 3)
+    );
+    assert_eq!(u.hadamard(&u), DynVector::from_slice(&[1.0, 4.0]));
+}
+
+#[test]
+#[should_panic(expected = "Hadamard product: operand lengths differ")]
+fn test_dyn_hadamard_length_mismatch_panics() {
+    let _ = DynVector::from_slice(&[1.0, 2.0]).hadamard(&DynVector::from_slice(&[1.0]));
+}
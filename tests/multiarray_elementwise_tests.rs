@@ -0,0 +1,130 @@
+//! Tests for `ElementwiseMath`: element-wise functions, scalar broadcasting,
+//! comparison masks and `select` across the dense backends.
+
+use strelitzia::common::Real;
+use strelitzia::multiarray::linalg::{ElementwiseMath, select};
+use strelitzia::multiarray::*;
+
+#[test]
+fn test_elementwise_functions() {
+    let v = Vector3::new(-4.0, 0.0, 9.0);
+    assert_eq!(v.elem_abs(), Vector3::new(4.0, 0.0, 9.0));
+    assert_eq!(v.elem_abs().elem_sqrt(), Vector3::new(2.0, 0.0, 3.0));
+    assert!(v.elem_sqrt().x().is_nan());
+    assert_eq!(Vector2::new(0.0, 0.0).elem_exp(), Vector2::new(1.0, 1.0));
+
+    // Element-wise, not the matrix exponential.
+    let m = Matrix2::from_slice(&[0.0, 1.0, 1.0, 0.0]);
+    let e = (1.0 as Real).exp();
+    assert_eq!(m.elem_exp(), Matrix2::from_slice(&[1.0, e, e, 1.0]));
+}
+
+#[test]
+fn test_min_max_clamp_and_broadcast() {
+    let a = Vector3::new(1.0, 5.0, -2.0);
+    let b = Vector3::new(3.0, 2.0, -2.0);
+    assert_eq!(a.elem_min(&b), Vector3::new(1.0, 2.0, -2.0));
+    assert_eq!(a.elem_max(&b), Vector3::new(3.0, 5.0, -2.0));
+    assert_eq!(a.elem_min_scalar(0.0), Vector3::new(0.0, 0.0, -2.0));
+    assert_eq!(a.elem_max_scalar(0.0), Vector3::new(1.0, 5.0, 0.0));
+    assert_eq!(a.elem_clamp_scalar(0.0, 2.0), Vector3::new(1.0, 2.0, 0.0));
+
+    let lo = Vector3::new(2.0, 0.0, 0.0);
+    let hi = Vector3::new(4.0, 4.0, 1.0);
+    assert_eq!(a.elem_clamp(&lo, &hi), Vector3::new(2.0, 4.0, 0.0));
+
+    // Integers and unordered pairs.
+    let i = Vector3i::new(-3, 7, 1);
+    assert_eq!(i.elem_clamp_scalar(0, 5), Vector3i::new(0, 5, 1));
+    let nan = Vector2::new(Real::NAN, 1.0);
+    assert!(nan.elem_max_scalar(0.0).x().is_nan());
+    assert_eq!(
+        Vector2::new(1.0, 1.0).elem_min(&nan),
+        Vector2::new(1.0, 1.0)
+    );
+}
+
+#[test]
+fn test_comparisons_produce_bool_masks() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(3.0, 2.0, 1.0);
+    assert_eq!(a.elem_lt(&b), Vector3b::new(true, false, false));
+    assert_eq!(a.elem_le(&b), Vector3b::new(true, true, false));
+    assert_eq!(a.elem_gt(&b), Vector3b::new(false, false, true));
+    assert_eq!(a.elem_ge(&b), Vector3b::new(false, true, true));
+    assert_eq!(a.elem_eq(&b), Vector3b::new(false, true, false));
+    assert_eq!(a.elem_ne(&b), Vector3b::new(true, false, true));
+    assert_eq!(a.elem_ge_scalar(2.0), Vector3b::new(false, true, true));
+    assert_eq!(a.elem_ne_scalar(2.0), Vector3b::new(true, false, true));
+
+    // Masks combine with the existing bitwise operators.
+    let inside = a.elem_gt_scalar(1.0) & a.elem_lt_scalar(3.0);
+    assert_eq!(inside, Vector3b::new(false, true, false));
+    assert_eq!(
+        !inside | a.elem_eq_scalar(3.0),
+        Vector3b::new(true, false, true)
+    );
+
+    let m = Matrix2::from_slice(&[1.0, -1.0, 0.0, 2.0]);
+    let positive: Matrix2b = m.elem_gt_scalar(0.0);
+    assert_eq!(positive.as_inner().as_slice(), &[true, false, false, true]);
+}
+
+#[test]
+fn test_select_blends_by_mask() {
+    let v = Vector3::new(-1.0, 4.0, -9.0);
+    let mask = v.elem_lt_scalar(0.0);
+    assert_eq!(select(&mask, &-v, &v), v.elem_abs());
+
+    let a = DynVector::from_slice(&[1.0, 2.0, 3.0]);
+    let b = DynVector::from_slice(&[10.0, 20.0, 30.0]);
+    let mask = a.elem_ne_scalar(2.0);
+    assert_eq!(
+        select(&mask, &a, &b),
+        DynVector::from_slice(&[1.0, 20.0, 3.0])
+    );
+}
+
+#[test]
+fn test_dynamic_and_native_backends() {
+    let d = DynMatrix::from_slice(&[1.0, -2.0, 3.0, -4.0], 2, 2);
+    assert_eq!(
+        d.elem_max_scalar(0.0),
+        DynMatrix::from_slice(&[1.0, 0.0, 3.0, 0.0], 2, 2)
+    );
+    assert_eq!(d.elem_lt_scalar(0.0).as_inner().shape(), (2, 2));
+
+    let n = NativeDynVector::from_slice(&[4.0, 16.0]);
+    assert_eq!(n.elem_sqrt(), NativeDynVector::from_slice(&[2.0, 4.0]));
+    let mask = n.elem_gt_scalar(5.0);
+    assert_eq!(mask, NativeDynVector::from_slice(&[false, true]));
+
+    let t = Tensor222::from_fn(|i, j, k| (i + 2 * j + 4 * k) as Real - 4.0);
+    let clamped = t.elem_clamp_scalar(-1.0, 1.0);
+    assert_eq!(clamped[(0, 0, 0)], -1.0);
+    assert_eq!(clamped[(1, 0, 1)], 1.0);
+    assert_eq!(clamped[(1, 1, 0)], -1.0);
+    assert!(t.elem_ge_scalar(0.0)[(0, 0, 1)]);
+}
+
+#[test]
+#[should_panic(expected = "Element-wise operation: operand shapes differ")]
+fn test_dyn_length_mismatch_panics() {
+    let a = DynVector::from_slice(&[1.0, 2.0]);
+    let _ = a.elem_lt(&DynVector::from_slice(&[1.0]));
+}
+
+#[test]
+#[should_panic(expected = "Element-wise operation: operand shapes differ")]
+fn test_dyn_transposed_shape_panics() {
+    let a = DynMatrix::from_slice(&[1.0; 6], 2, 3);
+    let _ = a.elem_min(&DynMatrix::from_slice(&[1.0; 6], 3, 2));
+}
+
+#[test]
+#[should_panic(expected = "Element-wise operation: operand shapes differ")]
+fn test_native_dyn_transposed_select_panics() {
+    let a = NativeDynMatrix::from_slice(&[1.0; 6], 2, 3);
+    let b = NativeDynMatrix::from_slice(&[2.0; 6], 3, 2);
+    let _ = select(&a.elem_gt_scalar(0.0), &a, &b);
+}
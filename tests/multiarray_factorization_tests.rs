@@ -0,0 +1,196 @@
+//! Tests for the dense `DynMatrix` factorizations (LU, QR, Cholesky, LDLᵀ).
+
+use strelitzia::common::Real;
+use strelitzia::multiarray::linalg::{DenseFactorizations, Factorization, FactorizationError};
+use strelitzia::multiarray::{DynMatrix, DynVector};
+
+const TOL: Real = 1e-12;
+
+/// Row-major convenience constructor.
+fn matrix(n: usize, rows: &[Real]) -> DynMatrix<Real> {
+    let ncols = rows.len() / n;
+    DynMatrix::from_inner(nalgebra::DMatrix::from_row_slice(n, ncols, rows))
+}
+
+fn assert_solves(a: &DynMatrix<Real>, x: &DynVector<Real>, b: &DynVector<Real>) {
+    let residual = a.as_inner() * x.as_inner() - b.as_inner();
+    assert!(residual.amax() < 1e-10, "residual {residual:?}");
+}
+
+/// Symmetric tridiagonal `[-1, d, -1]`, like a 1D CVT/Laplacian Hessian.
+fn tridiagonal(n: usize, d: Real) -> DynMatrix<Real> {
+    DynMatrix::from_inner(nalgebra::DMatrix::from_fn(n, n, |i, j| {
+        if i == j {
+            d
+        } else if i.abs_diff(j) == 1 {
+            -1.0
+        } else {
+            0.0
+        }
+    }))
+}
+
+#[test]
+fn test_lu_solve_determinant_and_reuse() {
+    // Needs pivoting: a zero in the (0, 0) position.
+    let a = matrix(3, &[0.0, 2.0, 1.0, 1.0, 1.0, 0.0, 3.0, 0.0, 1.0]);
+    let lu = a.lu().unwrap();
+    assert_eq!((lu.nrows(), lu.ncols()), (3, 3));
+    assert!((lu.determinant() - a.as_inner().determinant()).abs() < TOL);
+
+    for rhs in [[1.0, 2.0, 3.0], [0.0, -1.0, 4.0]] {
+        let b = DynVector::from_slice(&rhs);
+        assert_solves(&a, &lu.solve(&b), &b);
+
+        let mut flat = rhs;
+        lu.solve_in_place(&mut flat);
+        assert_eq!(flat.as_slice(), lu.solve(&b).as_inner().as_slice());
+    }
+}
+
+#[test]
+fn test_qr_square_and_least_squares() {
+    let a = matrix(3, &[2.0, -1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+    let qr = a.qr().unwrap();
+    let b = DynVector::from_slice(&[1.0, 0.0, -2.0]);
+    assert_solves(&a, &qr.solve(&b), &b);
+    assert!((qr.determinant() - a.as_inner().determinant()).abs() < 1e-12);
+
+    // Line fit y = c0 + c1 t through points that lie exactly on y = 1 + 2t.
+    let t = [0.0, 1.0, 2.0, 3.0];
+    let rows: Vec<Real> = t.iter().flat_map(|&t| [1.0, t]).collect();
+    let fit = matrix(4, &rows).qr().unwrap();
+    let y = DynVector::from_slice(&t.map(|t| 1.0 + 2.0 * t));
+    let c = fit.solve(&y);
+    assert_eq!(c.dim(), 2);
+    assert!((c.as_inner()[0] - 1.0).abs() < TOL && (c.as_inner()[1] - 2.0).abs() < TOL);
+
+    assert_eq!(
+        matrix(2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).qr().unwrap_err(),
+        FactorizationError::InvalidShape { nrows: 2, ncols: 3 }
+    );
+}
+
+#[test]
+fn test_cholesky_and_ldlt_agree_on_spd() {
+    let a = tridiagonal(6, 2.0);
+    let chol = a.cholesky().unwrap();
+    let ldlt = a.ldlt().unwrap();
+    let lu = a.lu().unwrap();
+    let b = DynVector::from_slice(&[1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+
+    assert_solves(&a, &chol.solve(&b), &b);
+    assert_solves(&a, &ldlt.solve(&b), &b);
+    // det of the n x n [-1, 2, -1] matrix is n + 1.
+    assert!((chol.determinant() - 7.0).abs() < 1e-10);
+    assert!((ldlt.determinant() - 7.0).abs() < 1e-10);
+    assert!((lu.determinant() - 7.0).abs() < 1e-10);
+    assert_eq!(ldlt.inertia(), (6, 0));
+}
+
+#[test]
+fn test_symmetric_factorizations_read_lower_triangle() {
+    let mut a = tridiagonal(4, 3.0);
+    let b = DynVector::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+    let x = a.cholesky().unwrap().solve(&b);
+
+    // Garbage in the strict upper triangle is ignored.
+    let mut garbage = a.as_inner().clone();
+    garbage[(0, 3)] = 100.0;
+    garbage[(1, 2)] = -7.0;
+    a = DynMatrix::from_inner(garbage);
+    assert_eq!(a.cholesky().unwrap().solve(&b), x);
+}
+
+#[test]
+fn test_ldlt_indefinite_saddle_point() {
+    // [[K, Bᵀ], [B, 0]] with K SPD: one negative eigenvalue per constraint.
+    let a = matrix(3, &[2.0, 0.0, 1.0, 0.0, 2.0, 1.0, 1.0, 1.0, 0.0]);
+    assert_eq!(
+        a.cholesky().unwrap_err(),
+        FactorizationError::NotPositiveDefinite { pivot: 2 }
+    );
+
+    let ldlt = a.ldlt().unwrap();
+    assert_eq!(ldlt.inertia(), (2, 1));
+    let b = DynVector::from_slice(&[1.0, -1.0, 2.0]);
+    assert_solves(&a, &ldlt.solve(&b), &b);
+    assert!((ldlt.determinant() - a.as_inner().determinant()).abs() < TOL);
+
+    // A zero leading pivot needs pivoting, which LDLᵀ does not do.
+    let swap = matrix(2, &[0.0, 1.0, 1.0, 0.0]);
+    assert_eq!(
+        swap.ldlt().unwrap_err(),
+        FactorizationError::Singular { pivot: 0 }
+    );
+    assert!(swap.lu().is_ok());
+}
+
+#[test]
+fn test_singular_and_non_square_errors() {
+    let singular = matrix(3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert!(matches!(
+        singular.lu(),
+        Err(FactorizationError::Singular { pivot: 2 })
+    ));
+    assert!(matches!(
+        singular.qr(),
+        Err(FactorizationError::Singular { .. })
+    ));
+    assert!(matches!(
+        DynMatrix::<Real>::zeros(2, 2).cholesky(),
+        Err(FactorizationError::NotPositiveDefinite { pivot: 0 })
+    ));
+
+    let wide = DynMatrix::<Real>::zeros(2, 3);
+    for err in [
+        wide.lu().unwrap_err(),
+        wide.cholesky().unwrap_err(),
+        wide.ldlt().unwrap_err(),
+    ] {
+        assert_eq!(err, FactorizationError::InvalidShape { nrows: 2, ncols: 3 });
+    }
+    assert_eq!(
+        FactorizationError::Singular { pivot: 2 }.to_string(),
+        "matrix is singular (zero pivot at 2)"
+    );
+}
+
+#[test]
+fn test_condition_estimate() {
+    // Diagonal: κ₁ is exactly max/min.
+    let d = matrix(3, &[1e-3, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 5.0]);
+    for kappa in [
+        d.lu().unwrap().condition_estimate(),
+        d.qr().unwrap().condition_estimate(),
+        d.cholesky().unwrap().condition_estimate(),
+        d.ldlt().unwrap().condition_estimate(),
+    ] {
+        assert!((kappa - 5e3).abs() < 1e-6, "{kappa}");
+    }
+
+    // Hager's estimate is a lower bound; compare with the explicit inverse.
+    let a = tridiagonal(8, 2.0);
+    let inv = a.as_inner().clone().try_inverse().unwrap();
+    let norm1 = |m: &nalgebra::DMatrix<Real>| {
+        m.column_iter()
+            .map(|c| c.iter().map(|x| x.abs()).sum::<Real>())
+            .fold(0.0, Real::max)
+    };
+    let exact = norm1(a.as_inner()) * norm1(&inv);
+    let estimate = a.lu().unwrap().condition_estimate();
+    assert!(estimate <= exact * (1.0 + 1e-10));
+    assert!(estimate >= 0.5 * exact, "{estimate} vs {exact}");
+    assert!((a.cholesky().unwrap().condition_estimate() - estimate).abs() < 1e-8 * exact);
+
+    // Nearly singular matrices are reported as ill-conditioned rather than failing.
+    let near = matrix(2, &[1.0, 1.0, 1.0, 1.0 + 1e-10]);
+    assert!(near.lu().unwrap().condition_estimate() > 1e9);
+}
+
+#[test]
+#[should_panic(expected = "right-hand side has 2 entries, expected 3")]
+fn test_solve_in_place_wrong_length_panics() {
+    let lu = tridiagonal(3, 2.0).lu().unwrap();
+    lu.solve_in_place(&mut [1.0, 2.0]);
+}
@@ -0,0 +1,346 @@
+//! Tests for `multiarray::linalg` extension traits.
+
+use strelitzia::common::Real;
+use strelitzia::multiarray::linalg::{
+    MatrixFunctions, PolarDecomposition, SingularValueDecomposition, SquareMatrixOps,
+    SymmetricEigen, Transpose,
+};
+use strelitzia::multiarray::{
+    DynMatrix, Matrix, Matrix2, Matrix3, Matrix4, Rotation3, Vector, Vector3,
+};
+
+const TOL: Real = 1e-12;
+
+fn assert_matrix_near<const N: usize>(a: &Matrix<Real, N, N>, b: &Matrix<Real, N, N>, tol: Real) {
+    for (i, (x, y)) in a.as_inner().iter().zip(b.as_inner().iter()).enumerate() {
+        assert!((x - y).abs() < tol, "component {}: {} vs {}", i, x, y);
+    }
+}
+
+fn diag<const N: usize>(v: &Vector<Real, N>) -> Matrix<Real, N, N> {
+    let mut m = Matrix::<Real, N, N>::zeros();
+    for i in 0..N {
+        m[i * N + i] = v[i];
+    }
+    m
+}
+
+// ============================================================================
+// SquareMatrixOps
+// ============================================================================
+
+#[test]
+fn test_matrix2_inverse() {
+    let m = Matrix2::from_slice(&[4.0, 2.0, 7.0, 6.0]); // column-major
+    let inv = m.inverse();
+    let product = m * inv;
+
+    for (a, b) in product
+        .as_inner()
+        .iter()
+        .zip(Matrix2::identity().as_inner().iter())
+    {
+        assert!((a - b).abs() < TOL);
+    }
+}
+
+#[test]
+fn test_matrix3_determinant_and_trace() {
+    let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+
+    assert!((m.determinant() - 6.0).abs() < TOL);
+    assert_eq!(m.trace(), 7.0);
+}
+
+#[test]
+fn test_matrix3_inverse_roundtrip() {
+    let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+    let product = m.inverse() * m;
+
+    for (a, b) in product
+        .as_inner()
+        .iter()
+        .zip(Matrix3::identity().as_inner().iter())
+    {
+        assert!((a - b).abs() < TOL);
+    }
+}
+
+#[test]
+fn test_matrix4_identity() {
+    let m = Matrix4::identity();
+
+    assert_eq!(m.determinant(), 1.0);
+    assert_eq!(m.trace(), 4.0);
+    assert_eq!(m.inverse(), m);
+}
+
+#[test]
+fn test_try_inverse_singular_returns_none() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+
+    assert!(m.try_inverse().is_none());
+    assert_eq!(m.determinant(), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "singular")]
+fn test_inverse_singular_panics() {
+    let m = Matrix2::zeros();
+    let _ = m.inverse();
+}
+
+#[test]
+fn test_dyn_matrix_square_ops() {
+    let m: DynMatrix<Real> = DynMatrix::from_slice(&[4.0, 2.0, 7.0, 6.0], 2, 2);
+
+    assert!((m.determinant() - 10.0).abs() < TOL);
+    assert_eq!(m.trace(), 10.0);
+
+    let inv = m.try_inverse().unwrap();
+    assert!((inv.as_inner()[(0, 0)] - 0.6).abs() < TOL);
+    assert!((inv.as_inner()[(0, 1)] + 0.7).abs() < TOL);
+    assert!((inv.as_inner()[(1, 0)] + 0.2).abs() < TOL);
+    assert!((inv.as_inner()[(1, 1)] - 0.4).abs() < TOL);
+}
+
+#[test]
+fn test_dyn_matrix_non_square_try_inverse() {
+    let m = DynMatrix::<Real>::zeros(2, 3);
+    assert!(m.try_inverse().is_none());
+}
+
+// ============================================================================
+// Decompositions
+// ============================================================================
+
+#[test]
+fn test_symmetric_eigen_2x2() {
+    let m = Matrix2::from_slice(&[2.0, 1.0, 1.0, 2.0]);
+    let eig = m.symmetric_eigen();
+
+    assert!((eig.eigenvalues[0] - 1.0).abs() < TOL);
+    assert!((eig.eigenvalues[1] - 3.0).abs() < TOL);
+
+    let q = eig.eigenvectors;
+    assert!((q.determinant() - 1.0).abs() < TOL);
+    assert_matrix_near(&(q * diag(&eig.eigenvalues) * q.transpose()), &m, TOL);
+}
+
+#[test]
+fn test_symmetric_eigen_2x2_diagonal() {
+    let m = Matrix2::from_slice(&[5.0, 0.0, 0.0, -1.0]);
+    let eig = m.symmetric_eigen();
+
+    assert!((eig.eigenvalues[0] + 1.0).abs() < TOL);
+    assert!((eig.eigenvalues[1] - 5.0).abs() < TOL);
+    assert_matrix_near(
+        &(eig.eigenvectors * diag(&eig.eigenvalues) * eig.eigenvectors.transpose()),
+        &m,
+        TOL,
+    );
+}
+
+#[test]
+fn test_symmetric_eigen_3x3_reconstruction() {
+    let m = Matrix3::new(4.0, 1.0, -2.0, 1.0, 2.0, 0.5, -2.0, 0.5, 3.0);
+    let eig = m.symmetric_eigen();
+
+    assert!(eig.eigenvalues[0] <= eig.eigenvalues[1]);
+    assert!(eig.eigenvalues[1] <= eig.eigenvalues[2]);
+
+    let q = eig.eigenvectors;
+    assert_matrix_near(&(q.transpose() * q), &Matrix3::identity(), 1e-10);
+    assert_matrix_near(&(q * diag(&eig.eigenvalues) * q.transpose()), &m, 1e-10);
+}
+
+#[test]
+fn test_svd_2x2_reconstruction() {
+    for m in [
+        Matrix2::from_slice(&[1.0, 0.5, 2.0, 3.0]),
+        Matrix2::from_slice(&[0.0, 1.0, 1.0, 0.0]), // reflection, det < 0
+        Matrix2::from_slice(&[1.0, 2.0, 2.0, 4.0]), // rank deficient
+    ] {
+        let svd = m.svd();
+        let s = svd.singular_values;
+
+        assert!(s[0] >= s[1]);
+        assert!(s[1] >= 0.0);
+        assert_matrix_near(&(svd.u * diag(&s) * svd.v_t), &m, TOL);
+        assert_matrix_near(&(svd.u.transpose() * svd.u), &Matrix2::identity(), TOL);
+        assert_matrix_near(&(svd.v_t * svd.v_t.transpose()), &Matrix2::identity(), TOL);
+    }
+}
+
+#[test]
+fn test_svd_3x3_reconstruction() {
+    let m = Matrix3::new(1.0, 2.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0);
+    let svd = m.svd();
+    let s = svd.singular_values;
+
+    assert!(s[0] >= s[1] && s[1] >= s[2] && s[2] >= 0.0);
+    assert_matrix_near(&(svd.u * diag(&s) * svd.v_t), &m, 1e-10);
+}
+
+#[test]
+fn test_polar_decomposition_3x3() {
+    // Deformation gradient: simple shear plus stretch
+    let f = Matrix3::new(1.2, 0.3, 0.0, 0.0, 0.9, 0.1, 0.0, 0.0, 1.1);
+    let polar = f.polar();
+    let r = polar.rotation;
+    let u = polar.stretch;
+
+    assert_matrix_near(&(r * u), &f, 1e-10);
+    assert_matrix_near(&(r.transpose() * r), &Matrix3::identity(), 1e-10);
+    assert!((r.determinant() - 1.0).abs() < 1e-10);
+    assert_matrix_near(&u, &u.transpose(), 1e-10);
+    assert_matrix_near(&(polar.left_stretch() * r), &f, 1e-10);
+}
+
+#[test]
+fn test_polar_decomposition_2x2_pure_rotation() {
+    let (s, c) = (0.3 as Real).sin_cos();
+    let f = Matrix2::from_slice(&[c, s, -s, c]);
+    let polar = f.polar();
+
+    assert_matrix_near(&polar.rotation, &f, TOL);
+    assert_matrix_near(&polar.stretch, &Matrix2::identity(), TOL);
+}
+
+// ============================================================================
+// MatrixFunctions
+// ============================================================================
+
+#[test]
+fn test_exp_diagonal_and_nilpotent() {
+    let d = Matrix3::new(1.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.0);
+    let expected = Matrix3::new(
+        1.0_f64.exp(),
+        0.0,
+        0.0,
+        0.0,
+        (-2.0_f64).exp(),
+        0.0,
+        0.0,
+        0.0,
+        1.0,
+    );
+    assert_matrix_near(&d.exp(), &expected, TOL);
+    assert_eq!(Matrix3::zeros().exp(), Matrix3::identity());
+
+    // exp(N) = I + N + N²/2 for N³ = 0
+    let n = Matrix3::new(0.0, 1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0);
+    let expected = Matrix3::identity() + n + n * n * 0.5;
+    assert_matrix_near(&n.exp(), &expected, TOL);
+}
+
+#[test]
+fn test_exp_large_norm_uses_squaring() {
+    // exp(diag(10, -10)) and exp(t J) for a rotation generator with t = 30
+    let d = Matrix2::from_slice(&[10.0, 0.0, 0.0, -10.0]);
+    let e = d.exp();
+    assert!((e.as_inner()[(0, 0)] / 10.0_f64.exp() - 1.0).abs() < 1e-13);
+    assert!((e.as_inner()[(1, 1)] / (-10.0_f64).exp() - 1.0).abs() < 1e-12);
+
+    let t: Real = 30.0;
+    let j = Matrix2::from_slice(&[0.0, t, -t, 0.0]);
+    let (sin, cos) = t.sin_cos();
+    assert_matrix_near(
+        &j.exp(),
+        &Matrix2::from_slice(&[cos, sin, -sin, cos]),
+        1e-12,
+    );
+}
+
+#[test]
+fn test_exp_of_skew_matrix_is_rotation() {
+    // Rodrigues: exp([ω]×) rotates by |ω| about ω / |ω|
+    let w = Vector3::new(0.3, -0.5, 0.9);
+    let skew = Matrix3::new(
+        0.0, -w[2], w[1], //
+        w[2], 0.0, -w[0], //
+        -w[1], w[0], 0.0,
+    );
+    let r = skew.exp();
+    assert_matrix_near(&r, &Rotation3::from_scaled_axis(&w).to_matrix(), 1e-13);
+    assert_matrix_near(&r.log(), &skew, 1e-12);
+}
+
+#[test]
+fn test_sqrt_spd_and_non_symmetric() {
+    let a = Matrix3::new(4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0);
+    let root = a.sqrt();
+    assert_matrix_near(&(root * root), &a, 1e-12);
+    assert_matrix_near(&root, &root.transpose(), 1e-12);
+    assert!(root.symmetric_eigen().eigenvalues[0] > 0.0);
+
+    // Rotation by 120° has complex eigenvalues; its principal root is the 60° rotation.
+    let (s, c) = (2.0 * std::f64::consts::FRAC_PI_3).sin_cos();
+    let r = Matrix2::from_slice(&[c, s, -s, c]);
+    let (s2, c2) = std::f64::consts::FRAC_PI_3.sin_cos();
+    assert_matrix_near(&r.sqrt(), &Matrix2::from_slice(&[c2, s2, -s2, c2]), 1e-12);
+
+    // Non-normal 4x4
+    let m = Matrix4::from_slice(&[
+        5.0, 1.0, 0.0, 0.0, //
+        2.0, 6.0, 1.0, 0.0, //
+        0.0, 0.5, 7.0, 1.0, //
+        0.0, 0.0, 3.0, 8.0,
+    ]);
+    let root = m.sqrt();
+    assert_matrix_near(&(root * root), &m, 1e-11);
+}
+
+#[test]
+fn test_sqrt_and_log_reject_negative_or_singular() {
+    let negative = Matrix2::from_slice(&[-1.0, 0.0, 0.0, 4.0]);
+    assert!(negative.try_sqrt().is_none());
+    assert!(negative.try_log().is_none());
+    assert!(negative.try_powf(0.5).is_none());
+
+    let half_turn = Matrix2::from_slice(&[-1.0, 0.0, 0.0, -1.0]);
+    assert!(half_turn.try_sqrt().is_none());
+    assert!(half_turn.try_log().is_none());
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+    assert!(singular.try_sqrt().is_none());
+    assert!(singular.try_log().is_none());
+    assert!(singular.try_powi(-1).is_none());
+}
+
+#[test]
+#[should_panic(expected = "negative real eigenvalue")]
+fn test_log_outside_domain_panics() {
+    let _ = Matrix2::from_slice(&[-2.0, 0.0, 0.0, 1.0]).log();
+}
+
+#[test]
+fn test_log_inverts_exp() {
+    let a = Matrix3::new(0.1, 0.4, -0.2, 0.0, -0.3, 0.5, 0.2, 0.1, 0.6);
+    assert_matrix_near(&a.exp().log(), &a, 1e-12);
+    assert_matrix_near(&Matrix3::identity().log(), &Matrix3::zeros(), TOL);
+
+    // Far from the identity: log of an SPD matrix via its eigendecomposition.
+    let spd = Matrix3::new(50.0, 2.0, 0.0, 2.0, 0.2, 0.0, 0.0, 0.0, 3.0);
+    let eig = spd.symmetric_eigen();
+    let mut ln = eig.eigenvalues;
+    for i in 0..3 {
+        ln[i] = ln[i].ln();
+    }
+    let q = eig.eigenvectors;
+    assert_matrix_near(&spd.log(), &(q * diag(&ln) * q.transpose()), 1e-10);
+}
+
+#[test]
+fn test_matrix_powers() {
+    let a = Matrix3::new(2.0, 1.0, 0.0, 0.0, 3.0, 1.0, 1.0, 0.0, 4.0);
+
+    assert_eq!(a.powi(0), Matrix3::identity());
+    assert_eq!(a.powi(3), a * a * a);
+    assert_matrix_near(&(a.powi(-2) * a * a), &Matrix3::identity(), 1e-12);
+
+    let cube_root = a.powf(1.0 / 3.0);
+    assert_matrix_near(&(cube_root * cube_root * cube_root), &a, 1e-11);
+    assert_matrix_near(&a.powf(0.5), &a.sqrt(), 1e-11);
+    assert_matrix_near(&a.powf(2.0), &(a * a), 1e-10);
+}
@@ -0,0 +1,217 @@
+//! Tests for the native `ArrayStorage` / `VecStorage` backends.
+
+use num_traits::{One, Zero};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use strelitzia::fields::{Field, FieldElement};
+use strelitzia::multiarray::linalg::Transpose;
+use strelitzia::multiarray::{
+    DenseMultiArrayOps, MultiArrayOps, NativeDynMatrix, NativeDynVector, NativeMatrix, NativeVector,
+};
+
+/// Integers modulo 7: `Copy` and numeric, but not `Debug`, so it does not
+/// satisfy `nalgebra::Scalar`.
+#[derive(Clone, Copy, PartialEq)]
+struct Mod7(u8);
+
+impl Add for Mod7 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+}
+
+impl Sub for Mod7 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for Mod7 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Mod7((7 - self.0) % 7)
+    }
+}
+
+impl Mul for Mod7 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Mod7((self.0 * rhs.0) % 7)
+    }
+}
+
+impl Div for Mod7 {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        // Multiply by the inverse.
+        const INV: [u8; 7] = [0, 1, 4, 5, 2, 3, 6];
+        self * Mod7(INV[rhs.0 as usize])
+    }
+}
+
+impl Zero for Mod7 {
+    fn zero() -> Self {
+        Mod7(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Mod7 {
+    fn one() -> Self {
+        Mod7(1)
+    }
+}
+
+fn m7(values: &[u8]) -> Vec<Mod7> {
+    values.iter().map(|&v| Mod7(v)).collect()
+}
+
+// ============================================================================
+// Static backend
+// ============================================================================
+
+#[test]
+fn test_native_vector_without_nalgebra_bounds() {
+    let a = NativeVector::<Mod7, 3>::from_slice(&m7(&[1, 2, 3]));
+    let b = NativeVector::<Mod7, 3>::from_slice(&m7(&[6, 6, 6]));
+
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.rank(), 1);
+    assert!((a + b).as_slice() == m7(&[0, 1, 2]).as_slice());
+    assert!((a - b).as_slice() == m7(&[2, 3, 4]).as_slice());
+    assert!((-a).as_slice() == m7(&[6, 5, 4]).as_slice());
+    assert!((a * Mod7(3)).as_slice() == m7(&[3, 6, 2]).as_slice());
+    assert!((a * Mod7(3) / Mod7(3)).as_slice() == a.as_slice());
+
+    let mut c = a;
+    c += b;
+    c *= Mod7(2);
+    assert!(c.as_slice() == m7(&[0, 2, 4]).as_slice());
+
+    let sum: NativeVector<Mod7, 3> = [a, a, a].into_iter().sum();
+    assert!(sum.as_slice() == m7(&[3, 6, 2]).as_slice());
+}
+
+#[test]
+fn test_native_vector_f64_operators() {
+    let a = NativeVector::from_array([1.0, 2.0, 3.0]);
+    let b = NativeVector::from_fn(|i| i as f64);
+
+    assert_eq!((a + b).as_slice(), &[1.0, 3.0, 5.0]);
+    assert_eq!((2.0 * a).as_slice(), &[2.0, 4.0, 6.0]);
+    assert_eq!((a * 2.0).as_slice(), &[2.0, 4.0, 6.0]);
+    assert_eq!((a / 2.0).as_slice(), &[0.5, 1.0, 1.5]);
+    assert_eq!((a % 2.0).as_slice(), &[1.0, 0.0, 1.0]);
+    assert_eq!(a[2], 3.0);
+    assert_eq!(a.dim(), 3);
+    assert_eq!(NativeVector::<f64, 4>::zeros().as_slice(), &[0.0; 4]);
+}
+
+#[test]
+fn test_native_matrix_layout_and_products() {
+    // [1 2]
+    // [3 4]
+    // [5 6]
+    let a = NativeMatrix::<i64, 3, 2>::from_fn(|i, j| (2 * i + j + 1) as i64);
+    assert_eq!(a.as_slice(), &[1, 3, 5, 2, 4, 6]);
+    assert_eq!(a[(2, 1)], 6);
+    assert_eq!((a.nrows(), a.ncols()), (3, 2));
+
+    let at = a.transpose();
+    assert_eq!(at.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+    let ata = at * a;
+    assert_eq!(ata.as_slice(), &[35, 44, 44, 56]);
+
+    let v = NativeVector::from_array([1, -1]);
+    assert_eq!((a * v).as_slice(), &[-1, -1, -1]);
+
+    let i3 = NativeMatrix::<i64, 3, 3>::identity();
+    assert_eq!((i3 * a).as_slice(), a.as_slice());
+
+    assert_eq!(v.transpose().as_slice(), &[1, -1]);
+}
+
+#[test]
+fn test_native_matrix_modular_product() {
+    let a = NativeMatrix::<Mod7, 2, 2>::from_slice(&m7(&[3, 4, 5, 6]));
+    let id = NativeMatrix::<Mod7, 2, 2>::identity();
+    assert!((a * id).as_slice() == a.as_slice());
+
+    // [3 5] [3 5]   [9+20 15+30]   [1 3]
+    // [4 6] [4 6] = [12+24 20+36] = [1 0]  (mod 7)
+    let a2 = a * a;
+    assert!(a2.as_slice() == m7(&[1, 1, 3, 0]).as_slice());
+}
+
+#[test]
+fn test_native_elements_in_field() {
+    assert_eq!(<NativeVector<Mod7, 2> as FieldElement>::COMPONENTS, 2);
+    assert_eq!(<NativeMatrix<f64, 2, 3> as FieldElement>::COMPONENTS, 6);
+
+    let field: Field<NativeVector<Mod7, 2>> = (0..4)
+        .map(|i| NativeVector::from_array([Mod7(i), Mod7(2 * i)]))
+        .collect();
+    let e = field[3];
+    assert!(e.component(0) == Mod7(3));
+    assert!(e.component(1) == Mod7(6));
+}
+
+// ============================================================================
+// Dynamic backend
+// ============================================================================
+
+#[test]
+fn test_native_dyn_vector_operators() {
+    let a = NativeDynVector::from_vec(vec![1.0, 2.0, 3.0]);
+    let b = NativeDynVector::from_slice(&[0.5, 0.5, 0.5]);
+
+    assert_eq!(a.dim(), 3);
+    assert_eq!((a.clone() + b.clone()).as_slice(), &[1.5, 2.5, 3.5]);
+    assert_eq!((a.clone() - b.clone()).as_slice(), &[0.5, 1.5, 2.5]);
+    assert_eq!((-a.clone()).as_slice(), &[-1.0, -2.0, -3.0]);
+    assert_eq!((a.clone() * 2.0).as_slice(), &[2.0, 4.0, 6.0]);
+
+    let mut c = NativeDynVector::zeros(3);
+    c += a;
+    c -= b;
+    c /= 0.5;
+    assert_eq!(c.as_slice(), &[1.0, 3.0, 5.0]);
+}
+
+#[test]
+fn test_native_dyn_matrix_products() {
+    let a = NativeDynMatrix::from_fn(2, 3, |i, j| (3 * i + j) as i64);
+    assert_eq!((a.nrows(), a.ncols()), (2, 3));
+    assert_eq!(a[(1, 2)], 5);
+
+    let at = a.transpose();
+    assert_eq!((at.nrows(), at.ncols()), (3, 2));
+    assert_eq!(at[(2, 1)], 5);
+
+    let aat = &a * &at;
+    assert_eq!(aat.as_slice(), &[5, 14, 14, 50]);
+
+    let x = NativeDynVector::from_vec(vec![1, 1, 1]);
+    assert_eq!((&a * &x).as_slice(), &[3, 12]);
+
+    let id = NativeDynMatrix::<i64>::identity(3);
+    assert_eq!((&a * &id).as_slice(), a.as_slice());
+}
+
+#[test]
+#[should_panic(expected = "lhs ncols must equal rhs nrows")]
+fn test_native_dyn_matrix_product_dimension_mismatch() {
+    let a = NativeDynMatrix::<f64>::zeros(2, 3);
+    let _ = &a * &a;
+}
+
+#[test]
+#[should_panic(expected = "dimension mismatch")]
+fn test_native_dyn_vector_add_dimension_mismatch() {
+    let _ = NativeDynVector::<f64>::zeros(2) + NativeDynVector::zeros(3);
+}
@@ -0,0 +1,254 @@
+//! Tests for `Rotation2`, `Rotation3` and `Quaternion`.
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use strelitzia::common::Real;
+use strelitzia::fields::{Field, FieldElement, RotateOps, SolverInterop};
+use strelitzia::multiarray::linalg::{CrossProduct, VectorOps};
+use strelitzia::multiarray::*;
+
+const TOL: Real = 1e-12;
+
+fn assert_vec_close(a: Vector3, b: Vector3) {
+    assert!((a - b).norm() < 1e-10, "{a:?} vs {b:?}");
+}
+
+fn assert_mat_close(a: Matrix3, b: Matrix3) {
+    for (x, y) in a.as_slice().iter().zip(b.as_slice()) {
+        assert!((x - y).abs() < 1e-10, "{a:?} vs {b:?}");
+    }
+}
+
+// ============================================================================
+// Rotation2
+// ============================================================================
+
+#[test]
+fn test_rotation2_angle_matrix_and_composition() {
+    let r = Rotation2::new(FRAC_PI_2);
+    let v = r * X_AXIS2;
+    assert!((v - Y_AXIS2).norm() < TOL);
+
+    let m = r.to_matrix();
+    assert!((m.as_inner()[(0, 1)] + 1.0).abs() < TOL);
+    assert_eq!(Matrix2::from(r), m);
+    assert!(Rotation2::try_from_matrix(&m).is_some());
+    assert!(Rotation2::try_from_matrix(&(m * 2.0)).is_none());
+    assert!((Rotation2::from_matrix(&(m * 2.0)).angle() - FRAC_PI_2).abs() < 1e-10);
+
+    let composed = r * Rotation2::new(FRAC_PI_4);
+    assert!((composed.angle() - 3.0 * FRAC_PI_4).abs() < TOL);
+    assert!(((r * r.inverse()).angle()).abs() < TOL);
+    assert!((r.slerp(&Rotation2::identity(), 0.5).angle() - FRAC_PI_4).abs() < TOL);
+    assert!((Rotation2::identity().angle_to(&r) - FRAC_PI_2).abs() < TOL);
+}
+
+// ============================================================================
+// Rotation3
+// ============================================================================
+
+#[test]
+fn test_rotation3_axis_angle_round_trip() {
+    let axis = Vector3::new(1.0, 2.0, -0.5);
+    let r = Rotation3::from_axis_angle(&(axis * 3.0), 0.7);
+
+    let (a, angle) = r.axis_angle().unwrap();
+    assert_vec_close(a, axis.normalised());
+    assert!((angle - 0.7).abs() < TOL);
+    assert!((r.angle() - 0.7).abs() < TOL);
+    assert_vec_close(r.scaled_axis(), axis.normalised() * 0.7);
+    assert_vec_close(
+        Rotation3::from_scaled_axis(&r.scaled_axis()) * axis,
+        r * axis,
+    );
+
+    // The axis is invariant, and rotating about z by 90° maps x to y.
+    assert_vec_close(r * axis, axis);
+    assert_vec_close(
+        Rotation3::from_axis_angle(&Z_AXIS3, FRAC_PI_2) * X_AXIS3,
+        Y_AXIS3,
+    );
+
+    assert!(Rotation3::identity().axis_angle().is_none());
+    assert_eq!(
+        Rotation3::from_axis_angle(&Vector3::zeros(), 1.0),
+        Rotation3::identity()
+    );
+}
+
+#[test]
+fn test_rotation3_euler_angles_convention() {
+    let (roll, pitch, yaw) = (0.3, -0.4, 1.1);
+    let r = Rotation3::from_euler_angles(roll, pitch, yaw);
+
+    // R = Rz(yaw) Ry(pitch) Rx(roll)
+    let expected = Rotation3::from_axis_angle(&Z_AXIS3, yaw)
+        * Rotation3::from_axis_angle(&Y_AXIS3, pitch)
+        * Rotation3::from_axis_angle(&X_AXIS3, roll);
+    assert_mat_close(r.to_matrix(), expected.to_matrix());
+
+    let (r2, p2, y2) = r.euler_angles();
+    assert!((r2 - roll).abs() < TOL && (p2 - pitch).abs() < TOL && (y2 - yaw).abs() < TOL);
+}
+
+#[test]
+fn test_rotation3_matrix_conversions() {
+    let r = Rotation3::from_euler_angles(0.1, 0.2, 0.3);
+    let m = r.to_matrix();
+
+    // Orthonormal with determinant +1.
+    assert_mat_close(
+        Matrix3::from_inner(m.as_inner().transpose()),
+        r.inverse().to_matrix(),
+    );
+    assert!((m.as_inner().determinant() - 1.0).abs() < TOL);
+    assert_eq!(Matrix3::from(r), m);
+
+    assert_eq!(Rotation3::try_from_matrix(&m), Some(r));
+    let reflection = Matrix3::new(-1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+    assert!(Rotation3::try_from_matrix(&reflection).is_none());
+    assert!(Rotation3::try_from_matrix(&(m * 1.01)).is_none());
+
+    // Nearest rotation to a stretched rotation recovers the rotation.
+    let stretched = m * Matrix3::new(1.2, 0.0, 0.0, 0.0, 0.9, 0.0, 0.0, 0.0, 1.0);
+    assert_mat_close(Rotation3::from_matrix(&stretched).to_matrix(), m);
+}
+
+#[test]
+fn test_rotation3_composition_and_slerp() {
+    let a = Rotation3::from_axis_angle(&Z_AXIS3, 0.4);
+    let b = Rotation3::from_axis_angle(&Z_AXIS3, 1.0);
+    let v = Vector3::new(0.3, -1.2, 2.0);
+
+    assert_vec_close((a * b) * v, a * (b * v));
+    assert_vec_close(a.inverse() * (a * v), v);
+    assert!((a.angle_to(&b) - 0.6).abs() < TOL);
+
+    let mid = a.slerp(&b, 0.5);
+    assert!((mid.angle() - 0.7).abs() < TOL);
+    assert_mat_close(a.slerp(&b, 0.0).to_matrix(), a.to_matrix());
+    assert_mat_close(a.slerp(&b, 1.0).to_matrix(), b.to_matrix());
+    assert_mat_close(a.slerp(&a, 0.3).to_matrix(), a.to_matrix());
+
+    // Half way to a half turn is a quarter turn about the same axis.
+    let flipped = Rotation3::from_axis_angle(&X_AXIS3, PI);
+    let quarter = Rotation3::identity().slerp(&flipped, 0.5);
+    assert!((quarter.angle() - FRAC_PI_2).abs() < 1e-10);
+    assert_vec_close(quarter.axis_angle().unwrap().0, X_AXIS3);
+}
+
+// ============================================================================
+// Quaternion
+// ============================================================================
+
+#[test]
+fn test_quaternion_matches_rotation3() {
+    let r = Rotation3::from_euler_angles(0.5, -0.2, 2.0);
+    let q = Quaternion::from_euler_angles(0.5, -0.2, 2.0);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_vec_close(q * v, r * v);
+    assert_mat_close(q.to_matrix(), r.to_matrix());
+    assert_mat_close(Rotation3::from(q).to_matrix(), r.to_matrix());
+    assert_mat_close(Quaternion::from(r).to_matrix(), q.to_matrix());
+    assert_mat_close(
+        Quaternion::from_matrix(&r.to_matrix()).to_matrix(),
+        q.to_matrix(),
+    );
+    assert!(Quaternion::try_from_matrix(&(r.to_matrix() * 2.0)).is_none());
+
+    let (roll, pitch, yaw) = q.euler_angles();
+    assert!((roll - 0.5).abs() < TOL && (pitch + 0.2).abs() < TOL && (yaw - 2.0).abs() < TOL);
+
+    let q2 = Quaternion::from_axis_angle(&Vector3::new(0.0, 1.0, 1.0), 0.9);
+    assert_vec_close((q * q2) * v, q * (q2 * v));
+    assert_vec_close(q.inverse() * (q * v), v);
+}
+
+#[test]
+fn test_quaternion_components_and_slerp() {
+    let q = Quaternion::try_new(1.0, 1.0, 0.0, 0.0).unwrap();
+    let h = 0.5_f64.sqrt();
+    assert!((q.w() - h).abs() < TOL && (q.x() - h).abs() < TOL);
+    assert_eq!((q.y(), q.z()), (0.0, 0.0));
+    assert_eq!(q.coords().as_slice(), &[q.x(), q.y(), q.z(), q.w()]);
+    assert!((q.angle() - FRAC_PI_2).abs() < TOL);
+    assert!(Quaternion::try_new(0.0, 0.0, 0.0, 0.0).is_none());
+
+    // Slerp from the identity halves the angle about the same axis.
+    let half = Quaternion::identity().slerp(&q, 0.5);
+    let (axis, angle) = half.axis_angle().unwrap();
+    assert_vec_close(axis, X_AXIS3);
+    assert!((angle - FRAC_PI_4).abs() < TOL);
+    assert_vec_close(half.scaled_axis(), X_AXIS3 * FRAC_PI_4);
+    assert_mat_close(
+        Quaternion::from_scaled_axis(&(X_AXIS3 * FRAC_PI_2)).to_matrix(),
+        q.to_matrix(),
+    );
+
+    // The shorter arc is taken regardless of the quaternion sign.
+    let neg = Quaternion::try_new(-q.w(), -q.x(), 0.0, 0.0).unwrap();
+    assert!((Quaternion::identity().slerp(&neg, 0.5).angle() - FRAC_PI_4).abs() < TOL);
+}
+
+#[test]
+fn test_rotation_preserves_cross_product() {
+    let q = Quaternion::from_euler_angles(0.7, 0.1, -1.3);
+    let (a, b) = (Vector3::new(1.0, 0.5, -2.0), Vector3::new(-0.3, 2.0, 0.4));
+    assert_vec_close((q * a).cross(&(q * b)), q * a.cross(&b));
+    assert!(((q * a).norm() - a.norm()).abs() < TOL);
+}
+
+// ============================================================================
+// Fields
+// ============================================================================
+
+#[test]
+fn test_rotate_vector_fields() {
+    let points: Vec<Point3> = (0..4).map(|i| Point3::new(i as Real, 1.0, 0.0)).collect();
+    let r = Rotation3::from_axis_angle(&Z_AXIS3, FRAC_PI_2);
+
+    let mut by_matrix = Field::from_vec(points.clone());
+    by_matrix.rotate(&r);
+    let mut by_quaternion = Field::from_vec(points.clone());
+    by_quaternion.rotate(&r.to_quaternion());
+
+    for ((p, a), b) in points
+        .iter()
+        .zip(by_matrix.iter())
+        .zip(by_quaternion.iter())
+    {
+        assert_vec_close(*a, Point3::new(-p.y(), p.x(), 0.0));
+        assert_vec_close(*b, *a);
+    }
+
+    let mut planar: Field<Vector2> = [X_AXIS2, Y_AXIS2].into_iter().collect();
+    planar.rotate(&Rotation2::new(PI));
+    assert!((planar[0] + X_AXIS2).norm() < TOL);
+    assert!((planar[1] + Y_AXIS2).norm() < TOL);
+}
+
+#[test]
+fn test_orientation_fields_solver_interop() {
+    assert_eq!(<Quaternion as FieldElement>::COMPONENTS, 4);
+    assert_eq!(<Rotation3 as FieldElement>::COMPONENTS, 9);
+    assert_eq!(<Rotation2 as FieldElement>::COMPONENTS, 4);
+
+    let q = Quaternion::from_axis_angle(&Y_AXIS3, 0.3);
+    let mut orientations: Field<Quaternion> = Field::from_vec(vec![Quaternion::identity(), q]);
+    assert_eq!(
+        orientations.as_flat_slice(),
+        &[0.0, 0.0, 0.0, 1.0, q.x(), q.y(), q.z(), q.w()]
+    );
+
+    // Write a rotation about z through the flat view and read it back.
+    let (s, c) = (0.25_f64.sin(), 0.25_f64.cos());
+    orientations.as_flat_slice_mut()[..4].copy_from_slice(&[0.0, 0.0, s, c]);
+    assert!((orientations[0].angle() - 0.5).abs() < TOL);
+
+    let r = Rotation3::from_euler_angles(0.1, 0.2, 0.3);
+    let frames: Field<Rotation3> = Field::from_vec(vec![r; 2]);
+    assert_eq!(&frames.as_flat_slice()[9..], r.to_matrix().as_slice());
+    assert_eq!(Rotation3::from_scalars(r.to_matrix().as_slice()), r);
+    assert_eq!(r.len(), 9);
+    assert_eq!(q.rank(), 1);
+}
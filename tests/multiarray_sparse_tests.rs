@@ -0,0 +1,155 @@
+//! Tests for the sparse COO / CSR backends.
+
+use strelitzia::common::Real;
+use strelitzia::fields::{Field, SolverInterop};
+use strelitzia::multiarray::linalg::Transpose;
+use strelitzia::multiarray::{
+    CooMatrix, CsrMatrix, DynMatrix, DynVector, MultiArrayOps, SparseMultiArrayOps, Vector3,
+};
+
+/// 1D Laplacian (tridiagonal 2, -1) assembled element by element, so every
+/// interior diagonal entry is pushed twice.
+fn laplacian(n: usize) -> CooMatrix<Real> {
+    let mut coo = CooMatrix::new(n, n);
+    coo.push(0, 0, 1.0);
+    coo.push(n - 1, n - 1, 1.0);
+    for e in 0..n - 1 {
+        coo.push(e, e, 1.0);
+        coo.push(e, e + 1, -1.0);
+        coo.push(e + 1, e, -1.0);
+        coo.push(e + 1, e + 1, 1.0);
+    }
+    coo
+}
+
+// ============================================================================
+// Assembly
+// ============================================================================
+
+#[test]
+fn test_coo_to_csr_sums_duplicates_and_sorts_columns() {
+    let coo = laplacian(4);
+    assert_eq!(coo.nnz(), 2 + 4 * 3);
+
+    let a = coo.to_csr();
+    assert_eq!(a.nrows(), 4);
+    assert_eq!(a.ncols(), 4);
+    assert_eq!(a.nnz(), 10);
+    assert_eq!(a.row_offsets(), &[0, 2, 5, 8, 10]);
+    assert_eq!(a.col_indices(), &[0, 1, 0, 1, 2, 1, 2, 3, 2, 3]);
+    assert_eq!(a.get(1, 1), 2.0);
+    assert_eq!(a.get(1, 2), -1.0);
+    assert_eq!(a.get(0, 3), 0.0);
+}
+
+#[test]
+fn test_unordered_triplets_and_empty_rows() {
+    let mut coo = CooMatrix::new(3, 4);
+    coo.push(2, 3, 1.0);
+    coo.push(0, 2, 2.0);
+    coo.push(2, 0, 3.0);
+    coo.push(0, 1, 4.0);
+
+    let a: CsrMatrix<Real> = coo.into();
+    assert_eq!(a.row(0), (&[1, 2][..], &[4.0, 2.0][..]));
+    assert_eq!(a.row(1), (&[][..], &[][..]));
+    assert_eq!(a.row(2), (&[0, 3][..], &[3.0, 1.0][..]));
+}
+
+#[test]
+fn test_sparse_is_not_dense() {
+    let a = laplacian(5).to_csr();
+
+    // len() is the logical element count; nnz() the stored entries.
+    assert_eq!(a.len(), 25);
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.nnz(), 13);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_push_out_of_bounds_panics() {
+    let mut coo = CooMatrix::<Real>::new(2, 2);
+    coo.push(2, 0, 1.0);
+}
+
+// ============================================================================
+// Products
+// ============================================================================
+
+#[test]
+fn test_matvec_matches_dense() {
+    let a = laplacian(5).to_csr();
+    let x = DynVector::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    let y = &a * &x;
+    let dense: DynMatrix<Real> = a.to_dense();
+    let expected = dense.as_inner() * x.as_inner();
+
+    assert_eq!(y.as_inner(), &expected);
+    assert_eq!(y.as_inner().as_slice(), &[0.0, 0.0, 0.0, 0.0, 6.0]);
+}
+
+#[test]
+fn test_matvec_on_field_flat_slices() {
+    // Block-diagonal 3x3 identity per node, scaled by 2: acts on Field<Vector3>.
+    let mut coo = CooMatrix::new(6, 6);
+    for i in 0..6 {
+        coo.push(i, i, 2.0);
+    }
+    let a = coo.to_csr();
+
+    let mut x: Field<Vector3> = Field::new();
+    x.push(Vector3::new(1.0, 2.0, 3.0));
+    x.push(Vector3::new(4.0, 5.0, 6.0));
+    let mut y: Field<Vector3> = Field::new();
+    y.push(Vector3::zeros());
+    y.push(Vector3::zeros());
+
+    a.mul_vec_into(x.as_flat_slice(), y.as_flat_slice_mut());
+    assert_eq!(y[1], Vector3::new(8.0, 10.0, 12.0));
+}
+
+#[test]
+#[should_panic(expected = "vector length must equal ncols")]
+fn test_matvec_dimension_mismatch_panics() {
+    let a = laplacian(3).to_csr();
+    let x = DynVector::from_slice(&[1.0, 2.0]);
+    let _ = &a * &x;
+}
+
+// ============================================================================
+// Transpose
+// ============================================================================
+
+#[test]
+fn test_transpose() {
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(0, 2, 1.0);
+    coo.push(1, 0, 2.0);
+    coo.push(1, 2, 3.0);
+    coo.push(0, 0, 4.0);
+
+    let a = coo.to_csr();
+    let at = a.transpose();
+    assert_eq!(at.nrows(), 3);
+    assert_eq!(at.ncols(), 2);
+    assert_eq!(
+        at.to_dense().as_inner(),
+        &a.to_dense().as_inner().transpose()
+    );
+    assert_eq!(at.col_indices(), &[0, 1, 0, 1]);
+    assert_eq!(at.transpose(), a);
+
+    assert_eq!(coo.transpose().to_csr(), at);
+}
+
+#[test]
+fn test_values_mut_reassembly() {
+    let mut a = laplacian(3).to_csr();
+    for v in a.values_mut() {
+        *v *= 0.5;
+    }
+    assert_eq!(a.get(1, 1), 1.0);
+    assert_eq!(a.get(2, 1), -0.5);
+}
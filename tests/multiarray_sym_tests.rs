@@ -0,0 +1,159 @@
+//! Tests for `SymMatrix3`: packing, conversions and invariants.
+
+use strelitzia::common::Real;
+use strelitzia::fields::{Field, FieldElement, SolverInterop, SymMatrix3Field};
+use strelitzia::multiarray::linalg::{
+    DoubleContraction, SquareMatrixOps, TensorInvariants, VectorOps,
+};
+use strelitzia::multiarray::{DenseMultiArrayOps, Matrix3, MultiArrayOps, SymMatrix3, Vector};
+
+const TOL: Real = 1e-12;
+
+fn sample() -> SymMatrix3 {
+    SymMatrix3::new(4.0, -1.0, 2.0, 0.5, 1.5, -3.0)
+}
+
+// ============================================================================
+// Construction, indexing and conversions
+// ============================================================================
+
+#[test]
+fn test_voigt_order_and_symmetric_indexing() {
+    let mut s = sample();
+
+    assert_eq!(s.len(), 6);
+    assert_eq!(s.rank(), 2);
+    assert_eq!(s.as_slice(), &[4.0, -1.0, 2.0, 0.5, 1.5, -3.0]);
+    assert_eq!(s[(1, 2)], 0.5);
+    assert_eq!(s[(2, 1)], 0.5);
+    assert_eq!(s[(0, 2)], s.xz());
+    assert_eq!(s[(1, 0)], s.xy());
+
+    s[(2, 0)] = 7.0;
+    assert_eq!(s.xz(), 7.0);
+}
+
+#[test]
+fn test_matrix_round_trip() {
+    let s = sample();
+    let m: Matrix3 = s.into();
+
+    assert_eq!(
+        m,
+        Matrix3::new(4.0, -3.0, 1.5, -3.0, -1.0, 0.5, 1.5, 0.5, 2.0)
+    );
+    assert_eq!(SymMatrix3::from_matrix(&m), s);
+
+    // A non-symmetric input keeps only its symmetric part.
+    let a = Matrix3::new(1.0, 2.0, 0.0, 4.0, 5.0, 0.0, 0.0, 0.0, 9.0);
+    assert_eq!(SymMatrix3::from_matrix(&a).xy(), 3.0);
+}
+
+#[test]
+fn test_voigt_and_mandel_packing() {
+    let s = sample();
+
+    assert_eq!(s.to_voigt().as_slice(), s.as_slice());
+    assert_eq!(
+        s.to_voigt_strain().as_slice(),
+        &[4.0, -1.0, 2.0, 1.0, 3.0, -6.0]
+    );
+    assert_eq!(SymMatrix3::from_voigt_strain(&s.to_voigt_strain()), s);
+    assert_eq!(SymMatrix3::from_voigt(&s.to_voigt()), s);
+
+    let mandel = s.to_mandel();
+    let back = SymMatrix3::from_mandel(&mandel);
+    for (a, b) in back.as_slice().iter().zip(s.as_slice()) {
+        assert!((a - b).abs() < TOL);
+    }
+}
+
+#[test]
+fn test_double_contraction_matches_full_matrix_and_mandel() {
+    let a = sample();
+    let b = SymMatrix3::new(1.0, 2.0, 3.0, -0.5, 0.25, 1.0);
+
+    let full = a.to_matrix().ddot(&b.to_matrix());
+    assert!((a.ddot(&b) - full).abs() < TOL);
+    assert!((a.to_mandel().dot(&b.to_mandel()) - full).abs() < TOL);
+
+    // Voigt stress . Voigt strain is also the work-conjugate product.
+    let voigt: Vector<Real, 6> = a.to_voigt();
+    assert!((voigt.dot(&b.to_voigt_strain()) - full).abs() < TOL);
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = sample();
+    let b = SymMatrix3::identity();
+
+    assert_eq!((a + b).xx(), 5.0);
+    assert_eq!((a - b).yz(), 0.5);
+    assert_eq!((2.0 * a).xy(), -6.0);
+    assert_eq!((a / 2.0).zz(), 1.0);
+    assert_eq!(-b, SymMatrix3::identity() * -1.0);
+}
+
+// ============================================================================
+// Invariants
+// ============================================================================
+
+#[test]
+fn test_invariants_match_full_matrix() {
+    let s = sample();
+    let m = s.to_matrix();
+
+    assert!((s.i1() - m.trace()).abs() < TOL);
+    assert!((s.i1() - m.i1()).abs() < TOL);
+    assert!((s.i2() - m.i2()).abs() < TOL);
+    assert!((s.i3() - m.determinant()).abs() < TOL);
+    assert!((s.i3() - m.i3()).abs() < TOL);
+    assert!((s.j2() - m.j2()).abs() < TOL);
+    assert!((s.von_mises() - m.von_mises()).abs() < TOL);
+}
+
+#[test]
+fn test_deviatoric_and_j2() {
+    let s = sample();
+    let dev = s.deviatoric();
+
+    assert!(dev.i1().abs() < TOL);
+    assert_eq!(dev.xy(), s.xy());
+    // J2 = I1²/3 - I2
+    assert!((s.j2() - (s.i1() * s.i1() / 3.0 - s.i2())).abs() < TOL);
+    // J2 = (s : s) / 2
+    assert!((s.j2() - 0.5 * dev.ddot(&dev)).abs() < TOL);
+}
+
+#[test]
+fn test_von_mises_reference_states() {
+    // Hydrostatic pressure carries no deviatoric stress.
+    let p = SymMatrix3::identity() * -1.0e6;
+    assert!(p.von_mises().abs() < 1e-6);
+
+    // Pure shear: σ_vm = √3 τ
+    let tau = 10.0;
+    let shear = SymMatrix3::new(0.0, 0.0, 0.0, 0.0, 0.0, tau);
+    assert!((shear.von_mises() - 3.0_f64.sqrt() * tau).abs() < TOL);
+}
+
+// ============================================================================
+// Field storage
+// ============================================================================
+
+#[test]
+fn test_sym_field_solver_interop() {
+    assert_eq!(<SymMatrix3 as FieldElement>::COMPONENTS, 6);
+
+    let mut field: SymMatrix3Field = Field::new();
+    field.push(sample());
+    field.push(SymMatrix3::identity());
+
+    let flat = field.as_flat_slice();
+    assert_eq!(flat.len(), 12);
+    assert_eq!(&flat[..6], sample().as_slice());
+    assert_eq!(&flat[6..], &[1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+
+    field.as_flat_slice_mut()[11] = 2.5;
+    assert_eq!(field[1].xy(), 2.5);
+}
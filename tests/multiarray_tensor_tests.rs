@@ -0,0 +1,248 @@
+//! Tests for rank-3 / rank-4 tensors and contractions.
+
+use strelitzia::common::Real;
+use strelitzia::einsum;
+use strelitzia::fields::{Field, SolverInterop, Tensor3333Field};
+use strelitzia::multiarray::linalg::{
+    CrossProduct, DoubleContraction, SingleContraction, Transpose, VectorOps,
+};
+use strelitzia::multiarray::{
+    DenseMultiArrayOps, DynMatrix, DynVector, Matrix, Matrix3, MultiArrayOps, SymMatrix3, Tensor3,
+    Tensor333, Tensor3333, Vector, Vector3,
+};
+
+const TOL: Real = 1e-12;
+
+// ============================================================================
+// Construction, layout and indexing
+// ============================================================================
+
+#[test]
+fn test_tensor3_layout_first_index_fastest() {
+    let t = Tensor3::<Real, 2, 3, 4>::from_fn(|i, j, k| (i + 10 * j + 100 * k) as Real);
+
+    assert_eq!(t.len(), 24);
+    assert_eq!(t.rank(), 3);
+    assert_eq!(t.dims(), [2, 3, 4]);
+    assert_eq!(t[(1, 2, 3)], 321.0);
+    // flat index = i + D1 * (j + D2 * k)
+    assert_eq!(t.as_slice()[1 + 2 * (2 + 3 * 3)], 321.0);
+    assert_eq!(Tensor3::<Real, 2, 3, 4>::from_slice(t.as_slice()), t);
+}
+
+#[test]
+fn test_tensor4_index_mut() {
+    let mut c = Tensor3333::zeros();
+    c[(0, 1, 2, 1)] = 5.0;
+
+    assert_eq!(c[(0, 1, 2, 1)], 5.0);
+    assert_eq!(c.as_slice().iter().filter(|&&x| x != 0.0).count(), 1);
+}
+
+#[test]
+fn test_tensor_arithmetic() {
+    let a = Tensor333::from_fn(|i, j, k| (i + j + k) as Real);
+    let b = Tensor333::from_fn(|_, _, _| 1.0);
+
+    let c = 2.0 * a + b - a;
+    assert_eq!(c[(2, 2, 2)], 7.0);
+    assert_eq!((-c)[(0, 0, 0)], -1.0);
+    assert_eq!((c / 2.0)[(1, 0, 0)], 1.0);
+
+    let mut d = a;
+    d += b;
+    d *= 3.0;
+    assert_eq!(d[(1, 1, 1)], 12.0);
+
+    let total: Tensor333 = vec![a, b, b].into_iter().sum();
+    assert_eq!(total[(0, 0, 0)], 2.0);
+}
+
+// ============================================================================
+// Contractions
+// ============================================================================
+
+#[test]
+fn test_isotropic_stiffness_double_contraction() {
+    let (lambda, mu) = (2.0, 0.5);
+    let c = Tensor3333::isotropic(lambda, mu);
+    let eps = Matrix3::new(1.0, 0.2, 0.0, 0.2, -0.5, 0.1, 0.0, 0.1, 0.3);
+
+    // σ = λ tr(ε) I + 2μ ε
+    let sigma: Matrix3 = c.ddot(&eps);
+    let tr = 1.0 - 0.5 + 0.3;
+    let expected = Matrix3::identity() * (lambda * tr) + eps * (2.0 * mu);
+
+    for (a, b) in sigma.as_slice().iter().zip(expected.as_slice()) {
+        assert!((a - b).abs() < TOL);
+    }
+}
+
+#[test]
+fn test_fourth_order_identities() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+
+    assert_eq!(Tensor3333::identity().ddot(&a), a);
+
+    let sym: Matrix3 = Tensor3333::symmetric_identity().ddot(&a);
+    let expected = (a + a.transpose()) * 0.5;
+    assert_eq!(sym, expected);
+
+    // I : C = C
+    let c = Tensor3333::isotropic(1.0, 1.0);
+    assert_eq!(Tensor3333::identity().ddot(&c), c);
+}
+
+#[test]
+fn test_matrix_double_contraction_is_frobenius_product() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let b = Matrix3::identity();
+
+    assert_eq!(a.ddot(&b), 15.0);
+    assert_eq!(a.ddot(&a), (1..=9).map(|x| (x * x) as Real).sum::<Real>());
+}
+
+#[test]
+fn test_single_contractions() {
+    let t = Tensor3::<Real, 2, 3, 4>::from_fn(|i, j, k| (i + 2 * j + 3 * k) as Real);
+
+    // A_ijk v_k
+    let v = Vector::<Real, 4>::from_slice(&[1.0, 0.0, 0.0, 1.0]);
+    let m: Matrix<Real, 2, 3> = t.contract(&v);
+    assert_eq!(m.as_inner()[(1, 2)], t[(1, 2, 0)] + t[(1, 2, 3)]);
+
+    // u_i A_ijk
+    let u = Vector::<Real, 2>::new(0.0, 1.0);
+    let n: Matrix<Real, 3, 4> = u.contract(&t);
+    assert_eq!(n.as_inner()[(2, 3)], t[(1, 2, 3)]);
+
+    // Rank-3 . matrix and matrix . rank-3
+    let id4 = Matrix::<Real, 4, 4>::identity();
+    assert_eq!(t.contract(&id4), t);
+    let id2 = Matrix::<Real, 2, 2>::identity();
+    assert_eq!(id2.contract(&t), t);
+}
+
+#[test]
+fn test_tensor3_double_contraction_with_levi_civita() {
+    // ε_ijk a_j b_k = (a x b)_i
+    let eps = Tensor333::from_fn(|i, j, k| match (i, j, k) {
+        (0, 1, 2) | (1, 2, 0) | (2, 0, 1) => 1.0,
+        (0, 2, 1) | (2, 1, 0) | (1, 0, 2) => -1.0,
+        _ => 0.0,
+    });
+    let a = Vector3::new(1.0, 0.0, 0.0);
+    let b = Vector3::new(0.0, 1.0, 0.0);
+    let outer = a * b.transpose();
+
+    assert_eq!(eps.ddot(&outer), Vector3::new(0.0, 0.0, 1.0));
+}
+
+// ============================================================================
+// Einsum
+// ============================================================================
+
+#[test]
+fn test_levi_civita_and_einsum_cross_product() {
+    let eps = Tensor333::levi_civita();
+    assert_eq!(eps[(0, 1, 2)], 1.0);
+    assert_eq!(eps[(2, 1, 0)], -1.0);
+    assert_eq!(eps[(1, 1, 2)], 0.0);
+    assert_eq!(eps.as_slice().iter().map(|x| x.abs()).sum::<Real>(), 6.0);
+
+    let a = Vector3::new(1.0, -2.0, 0.5);
+    let b = Vector3::new(3.0, 1.0, -1.0);
+    let c: Vector3 = einsum!("ijk,j,k->i", eps, a, b);
+    assert_eq!(c, a.cross(&b));
+
+    // ε_ijk ε_ijk = 6
+    let full: Real = einsum!("ijk,ijk->", eps, eps);
+    assert_eq!(full, 6.0);
+}
+
+#[test]
+fn test_einsum_matches_existing_contractions() {
+    let a = Matrix::<Real, 2, 3>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = Matrix::<Real, 3, 2>::from_slice(&[0.5, -1.0, 2.0, 1.0, 0.0, 3.0]);
+    let ab: Matrix<Real, 2, 2> = einsum!("ij,jk->ik", a, b);
+    assert_eq!(ab, a * b);
+    let at: Matrix<Real, 3, 2> = einsum!("ij->ji", a);
+    assert_eq!(at, a.transpose());
+
+    let c = Tensor3333::isotropic(2.0, 0.7);
+    let strain = Matrix3::new(1e-3, 2e-4, 0.0, 2e-4, -5e-4, 1e-4, 0.0, 1e-4, 3e-4);
+    let stress: Matrix3 = einsum!("ijkl,kl->ij", c, strain);
+    assert_eq!(stress, c.ddot(&strain));
+
+    let t = Tensor3::<Real, 2, 3, 4>::from_fn(|i, j, k| (i + 2 * j + 3 * k) as Real);
+    let v = Vector::<Real, 4>::from_slice(&[1.0, 0.0, -1.0, 2.0]);
+    let tv: Matrix<Real, 2, 3> = einsum!("ijk,k->ij", t, v);
+    assert_eq!(tv, t.contract(&v));
+
+    // Trace, dot product and outer product
+    let trace: Real = einsum!("ii->", strain);
+    assert!((trace - 8e-4).abs() < TOL);
+    let x = Vector3::new(1.0, 2.0, 3.0);
+    let dot: Real = einsum!("i,i->", x, x);
+    assert_eq!(dot, x.dot(&x));
+    let outer: Matrix3 = einsum!("i,j->ij", x, x);
+    assert_eq!(outer, x * x.transpose());
+
+    // Rank-4 result: C_ijkl = δ_ij δ_kl
+    let id = Matrix3::identity();
+    let dd: Tensor3333 = einsum!("ij,kl->ijkl", id, id);
+    assert_eq!(dd, Tensor3333::isotropic(1.0, 0.0));
+}
+
+#[test]
+fn test_einsum_symmetric_and_dynamic_operands() {
+    let s = SymMatrix3::new(1.0, 2.0, 3.0, 0.1, 0.2, 0.3);
+    let n = Vector3::new(0.0, 0.0, 1.0);
+    let traction: Vector3 = einsum!("ij,j->i", s, n);
+    assert_eq!(traction, Vector3::new(0.2, 0.1, 3.0));
+
+    // Dynamic shapes are checked at runtime; results may be static or dynamic.
+    let m = DynMatrix::from_slice(&[1.0, 0.0, 2.0, 1.0, 0.0, 3.0], 2, 3);
+    let v = DynVector::from_slice(&[1.0, 1.0, 1.0]);
+    let mv: DynVector<Real> = einsum!("ij,j->i", m, v);
+    assert_eq!(mv.as_inner().as_slice(), &[3.0, 4.0]);
+    let mtm: DynMatrix<Real> = einsum!("ki,kj->ij", m, m);
+    assert_eq!(mtm.as_inner(), &(m.as_inner().transpose() * m.as_inner()));
+    let fixed: Vector<Real, 2> = einsum!("ij,j->i", m, Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(fixed.as_slice(), mv.as_inner().as_slice());
+}
+
+#[test]
+#[should_panic(expected = "einsum: index `j` has dimension 2 in operand 1, expected 3")]
+fn test_einsum_dynamic_shape_mismatch_panics() {
+    let m = DynMatrix::<Real>::zeros(2, 3);
+    let v = DynVector::<Real>::zeros(2);
+    let _: DynVector<Real> = einsum!("ij,j->i", m, v);
+}
+
+#[test]
+#[should_panic(expected = "einsum: result axis 0 has dimension 3, index `i` has 2")]
+fn test_einsum_dynamic_result_mismatch_panics() {
+    let m = DynMatrix::<Real>::zeros(2, 3);
+    let v = DynVector::<Real>::zeros(3);
+    let _: Vector3 = einsum!("ij,j->i", m, v);
+}
+
+// ============================================================================
+// Field storage
+// ============================================================================
+
+#[test]
+fn test_tensor4_field_solver_interop() {
+    let mut field: Tensor3333Field = Field::new();
+    field.push(Tensor3333::identity());
+    field.push(Tensor3333::zeros());
+
+    let flat = field.as_flat_slice();
+    assert_eq!(flat.len(), 2 * 81);
+    assert_eq!(flat[0], 1.0);
+    assert_eq!(&flat[81..], &[0.0; 81][..]);
+
+    field.as_flat_slice_mut()[81] = 4.0;
+    assert_eq!(field[1][(0, 0, 0, 0)], 4.0);
+}
@@ -0,0 +1,192 @@
+//! Tests for borrowed views: `VectorView`, `MatrixView`, `DynVectorView`,
+//! `DynMatrixView` and their `_mut` variants.
+
+use strelitzia::common::Real;
+use strelitzia::multiarray::linalg::VectorOps;
+use strelitzia::multiarray::*;
+
+/// 4x4 matrix with entry (i, j) = 10 i + j.
+fn matrix4() -> Matrix4 {
+    Matrix4::from_slice(&std::array::from_fn::<Real, 16, _>(|k| {
+        (10 * (k % 4) + k / 4) as Real
+    }))
+}
+
+/// 4x3 dynamic matrix with entry (i, j) = 10 i + j.
+fn dyn_matrix() -> DynMatrix<Real> {
+    let data: Vec<Real> = (0..12).map(|k| (10 * (k % 4) + k / 4) as Real).collect();
+    DynMatrix::from_slice(&data, 4, 3)
+}
+
+#[test]
+fn test_matrix_column_row_and_block_views() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let c = m.column(1);
+    assert_eq!(c, Vector3::new(2.0, 5.0, 8.0));
+    assert_eq!((c.nrows(), c.ncols()), (3, 1));
+    assert_eq!(c[2], 8.0);
+
+    let r = m.row(2);
+    assert_eq!(r, Vector3::new(7.0, 8.0, 9.0));
+    assert_eq!(r.into_owned(), Vector3::new(7.0, 8.0, 9.0));
+
+    let m4 = matrix4();
+    let b = m4.block::<2, 2>(0, 0);
+    assert_eq!(b, Matrix2::from_slice(&[0.0, 10.0, 1.0, 11.0]));
+    assert_eq!(b[(1, 0)], 10.0);
+    // Flat indexing is column-major, like the owned backends.
+    assert_eq!(b[1], 10.0);
+    assert_eq!(m4.block::<2, 3>(1, 1)[(1, 2)], 23.0);
+}
+
+#[test]
+fn test_view_arithmetic_returns_owned() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let (c0, c1) = (m.column(0), m.column(1));
+    let v = Vector3::new(1.0, 1.0, 1.0);
+
+    let sum: Vector3 = c0 + c1;
+    assert_eq!(sum, Vector3::new(3.0, 9.0, 15.0));
+    assert_eq!(c1 - c0, v);
+    assert_eq!(c0 + v, Vector3::new(2.0, 5.0, 8.0));
+    assert_eq!(v - c0, Vector3::new(0.0, -3.0, -6.0));
+    assert_eq!(-c0, Vector3::new(-1.0, -4.0, -7.0));
+    assert_eq!(c0 * 2.0, 2.0 * c0);
+    assert_eq!(c1 / 2.0, Vector3::new(1.0, 2.5, 4.0));
+
+    let mut acc = Vector3::zeros();
+    acc += c0;
+    acc -= c1;
+    assert_eq!(acc, -v);
+
+    let m4 = matrix4();
+    let upper_left = m4.block::<2, 2>(0, 0);
+    let lower_right = m4.block::<2, 2>(2, 2);
+    assert_eq!(
+        upper_left + lower_right,
+        Matrix2::from_slice(&[22.0, 42.0, 24.0, 44.0])
+    );
+}
+
+#[test]
+fn test_vector_ops_on_views() {
+    let m = Matrix3::new(3.0, 0.0, 1.0, 4.0, 0.0, 1.0, 0.0, 2.0, 1.0);
+    let c = m.column(0);
+    assert_eq!(c.norm(), 5.0);
+    assert_eq!(c.norm_squared(), 25.0);
+    assert_eq!(c.l1_norm(), 7.0);
+    assert_eq!(c.linf_norm(), 4.0);
+    assert_eq!(c.dot(&m.column(2)), 7.0);
+    assert_eq!(m.row(0).dot(&m.row(1)), 13.0);
+
+    let n: Vector3 = c.normalised();
+    assert_eq!(n, Vector3::new(0.6, 0.8, 0.0));
+    assert_eq!(n.normalised(), n);
+
+    let d = dyn_matrix();
+    let col: DynVector<Real> = d.column(2).normalised();
+    assert!((col.as_inner().norm() - 1.0).abs() < 1e-12);
+    assert_eq!(d.row(1).dot(&d.row(1)), 100.0 + 121.0 + 144.0);
+}
+
+#[test]
+fn test_matrix_products_with_views() {
+    let m4 = matrix4();
+    let a = m4.block::<2, 3>(0, 0);
+    let b = m4.block::<3, 2>(1, 1);
+    let expected = a.into_owned() * b.into_owned();
+
+    assert_eq!(a * b, expected);
+    assert_eq!(a * b.into_owned(), expected);
+    assert_eq!(a.into_owned() * b, expected);
+
+    let x = m4.column(3);
+    let xo = x.into_owned();
+    let block = m4.block::<4, 4>(0, 0);
+    assert_eq!(block * x, m4 * xo);
+    assert_eq!(block * xo, m4 * xo);
+    assert_eq!(m4 * x, m4 * xo);
+}
+
+#[test]
+fn test_mutable_views_write_through() {
+    let mut m = Matrix3::identity();
+    m.column_mut(0).fill(2.0);
+    {
+        let mut r = m.row_mut(2);
+        r += Vector3::new(1.0, 1.0, 1.0);
+        r[0] = -1.0;
+        assert_eq!(r, Vector3::new(-1.0, 1.0, 2.0));
+    }
+    assert_eq!(
+        m,
+        Matrix3::new(2.0, 0.0, 0.0, 2.0, 1.0, 0.0, -1.0, 1.0, 2.0)
+    );
+
+    let mut m4 = Matrix4::zeros();
+    let mut b = m4.block_mut::<2, 2>(1, 2);
+    b.copy_from(&Matrix2::from_slice(&[1.0, 3.0, 2.0, 4.0]));
+    b *= 10.0;
+    b[(0, 0)] += 1.0;
+    assert_eq!(b.as_view()[(0, 1)], 20.0);
+    assert_eq!(
+        m4.block::<2, 2>(1, 2),
+        Matrix2::from_slice(&[11.0, 30.0, 20.0, 40.0])
+    );
+    assert_eq!(m4.column(0), Vector4::zeros());
+
+    // Copy from another view of the same matrix.
+    let src = m4.column(2).into_owned();
+    m4.column_mut(0).copy_from(&src);
+    assert_eq!(m4.column(0), m4.column(2));
+}
+
+#[test]
+fn test_dyn_matrix_row_ranges_and_blocks() {
+    let mut d = dyn_matrix();
+    let rows = d.rows(1, 2);
+    assert_eq!((rows.nrows(), rows.ncols()), (2, 3));
+    assert_eq!(rows[(0, 0)], 10.0);
+    assert_eq!(rows[(1, 2)], 22.0);
+    assert_eq!(
+        rows.into_owned(),
+        DynMatrix::from_slice(&[10.0, 20.0, 11.0, 21.0, 12.0, 22.0], 2, 3)
+    );
+    assert_eq!(d.columns(1, 2).ncols(), 2);
+    assert_eq!(d.view(2, 1, 2, 2)[(1, 1)], 32.0);
+
+    // Row range times a vector view, against the owned product.
+    let x = DynVector::from_slice(&[1.0, 0.0, -1.0]);
+    let y = d.rows(1, 2) * x.clone();
+    assert_eq!(y, DynVector::from_slice(&[-2.0, -2.0]));
+    let square = d.view(0, 0, 3, 3);
+    assert_eq!(square * d.row(0), square * d.row(0).into_owned());
+    assert_eq!((d.rows(0, 2) * d.view(0, 0, 3, 1)).nrows(), 2);
+
+    let mut block = d.rows_mut(0, 2);
+    block -= DynMatrix::from_slice(&[0.0, 10.0, 1.0, 11.0, 2.0, 12.0], 2, 3);
+    block /= 2.0;
+    assert_eq!(d.rows(0, 2).into_owned(), DynMatrix::zeros(2, 3));
+    d.column_mut(1).fill(7.0);
+    assert_eq!(d.row(3), DynVector::from_slice(&[30.0, 7.0, 32.0]));
+
+    // Empty blocks are valid anywhere up to the bottom-right corner.
+    let (r, c) = (d.nrows(), d.ncols());
+    assert_eq!(d.view(r, c, 0, 0).into_owned(), DynMatrix::zeros(0, 0));
+    assert_eq!(d.view(r, 1, 0, 2).ncols(), 2);
+    assert_eq!(d.view_mut(r, c, 0, 0).nrows(), 0);
+    assert_eq!(d.rows(r, 0).ncols(), c);
+}
+
+#[test]
+#[should_panic(expected = "2x2 block at (3, 0) does not fit in 4x4")]
+fn test_block_out_of_bounds_panics() {
+    let _ = matrix4().block::<2, 2>(3, 0);
+}
+
+#[test]
+#[should_panic(expected = "inner dimensions differ")]
+fn test_dyn_view_product_dimension_mismatch_panics() {
+    let d = dyn_matrix();
+    let _ = d.rows(0, 2) * d.column(0);
+}
@@ -0,0 +1,214 @@
+//! Tests for the dual-number scalars `Dual<N>` and `Dual2<N>`.
+
+use num_traits::{Float, One, Zero};
+use strelitzia::common::Real;
+use strelitzia::multiarray::{Matrix, Vector};
+use strelitzia::scalars::{Dual, Dual2};
+
+const TOL: Real = 1e-12;
+
+fn assert_close(a: Real, b: Real, what: &str) {
+    assert!((a - b).abs() < 1e-9 * (1.0 + b.abs()), "{what}: {a} vs {b}");
+}
+
+/// Central-difference derivative of `f` at `x`.
+fn numerical_derivative(f: impl Fn(Real) -> Real, x: Real) -> Real {
+    let h = 1e-6;
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+// ============================================================================
+// First order
+// ============================================================================
+
+#[test]
+fn test_dual_arithmetic_rules() {
+    let [x, y] = Dual::<2>::variables([3.0, 2.0]);
+
+    let f = x * y + x / y - 2.0 * x + 1.0;
+    assert!((f.value - (6.0 + 1.5 - 6.0 + 1.0)).abs() < TOL);
+    // df/dx = y + 1/y - 2, df/dy = x - x/y²
+    assert!((f.grad[0] - (2.0 + 0.5 - 2.0)).abs() < TOL);
+    assert!((f.grad[1] - (3.0 - 0.75)).abs() < TOL);
+
+    let g = 1.0 / x;
+    assert!((g.grad[0] + 1.0 / 9.0).abs() < TOL);
+    assert_eq!(g.grad[1], 0.0);
+}
+
+#[test]
+fn test_dual_elementary_functions_match_finite_differences() {
+    type F = fn(Dual<1>) -> Dual<1>;
+    type R = fn(Real) -> Real;
+    let cases: [(&str, F, R, Real); 14] = [
+        ("sqrt", |x| x.sqrt(), Real::sqrt, 1.7),
+        ("cbrt", |x| x.cbrt(), Real::cbrt, 1.7),
+        ("exp", |x| x.exp(), Real::exp, 0.3),
+        ("ln", |x| x.ln(), Real::ln, 1.7),
+        ("log10", |x| x.log10(), Real::log10, 1.7),
+        ("sin", |x| x.sin(), Real::sin, 0.3),
+        ("cos", |x| x.cos(), Real::cos, 0.3),
+        ("tan", |x| x.tan(), Real::tan, 0.3),
+        ("asin", |x| x.asin(), Real::asin, 0.3),
+        ("atan", |x| x.atan(), Real::atan, 0.3),
+        ("tanh", |x| x.tanh(), Real::tanh, 0.3),
+        ("acosh", |x| x.acosh(), Real::acosh, 1.7),
+        ("powi", |x| x.powi(3), |x| x.powi(3), 1.7),
+        (
+            "powf",
+            |x| x.powf(Dual::constant(2.5)),
+            |x| x.powf(2.5),
+            1.7,
+        ),
+    ];
+
+    for (name, f, r, x0) in cases {
+        let y = f(Dual::variable(x0, 0));
+        assert_close(y.value, r(x0), name);
+        assert!(
+            (y.grad[0] - numerical_derivative(r, x0)).abs() < 1e-6,
+            "{name}: derivative {} vs {}",
+            y.grad[0],
+            numerical_derivative(r, x0)
+        );
+    }
+}
+
+#[test]
+fn test_dual_atan2_and_comparisons() {
+    // Derivatives of the polar angle: dθ/dy = x / r², dθ/dx = -y / r²
+    for (x0, y0) in [(1.0, 2.0), (-1.0, 0.5), (-0.5, -2.0), (3.0, -0.1)] {
+        let [x, y] = Dual::<2>::variables([x0, y0]);
+        let theta = y.atan2(x);
+        let r2 = x0 * x0 + y0 * y0;
+        assert!((theta.value - y0.atan2(x0)).abs() < TOL);
+        assert!((theta.grad[0] + y0 / r2).abs() < TOL);
+        assert!((theta.grad[1] - x0 / r2).abs() < TOL);
+    }
+
+    let a = Dual::<1>::variable(1.0, 0);
+    let b = Dual::<1>::constant(1.0);
+    assert_eq!(a, b, "equality compares values only");
+    assert!(a.max(Dual::constant(0.5)).grad[0] == 1.0);
+    assert!(a.min(Dual::constant(0.5)).grad[0] == 0.0);
+    assert!((-a).abs().grad[0] == 1.0);
+}
+
+#[test]
+fn test_singular_point_keeps_zero_derivatives() {
+    // f' is infinite at 0, but a constant (or zero-tangent) input must still
+    // give zero derivatives rather than NaN.
+    let c = Dual::<2>::constant(0.0);
+    for f in [c.sqrt(), c.cbrt(), c.ln(), c.log2()] {
+        assert_eq!(f.grad, [0.0, 0.0]);
+    }
+    let [x, y] = Dual::<2>::variables([0.0, 1.0]);
+    let r = (x * x).sqrt() + y;
+    assert_eq!(r.grad, [0.0, 1.0]);
+
+    let [x, y] = Dual2::<2>::variables([0.0, 2.0]);
+    let f = (x * x).sqrt() + y * y;
+    assert_eq!(f.grad, [0.0, 4.0]);
+    // |x| has an infinite curvature at 0, which must not leak into y.
+    assert_eq!(f.hess[0][0], Real::INFINITY);
+    assert_eq!((f.hess[0][1], f.hess[1][0], f.hess[1][1]), (0.0, 0.0, 2.0));
+    let c = Dual2::<2>::constant(0.0).sqrt();
+    assert_eq!((c.grad, c.hess), ([0.0; 2], [[0.0; 2]; 2]));
+}
+
+/// Generic code written against `num_traits::Float` differentiates unchanged.
+fn smooth_step<T: Float>(x: T) -> T {
+    let three = T::from(3.0).unwrap();
+    let two = T::from(2.0).unwrap();
+    x * x * (three - two * x)
+}
+
+#[test]
+fn test_dual_through_generic_float_code() {
+    let y = smooth_step(Dual::<1>::variable(0.25, 0));
+    assert!((y.value - smooth_step(0.25)).abs() < TOL);
+    // d/dx (3x² - 2x³) = 6x - 6x²
+    assert!((y.grad[0] - (1.5 - 0.375)).abs() < TOL);
+
+    assert!(Dual::<2>::zero().is_zero());
+    assert_eq!(Dual::<2>::one().value, 1.0);
+    assert_eq!(
+        <Dual<2> as num_traits::Num>::from_str_radix("2.5", 10)
+            .unwrap()
+            .value,
+        2.5
+    );
+}
+
+#[test]
+fn test_dual_in_vector_and_matrix() {
+    // d/dt (A(t) v(t)) with A = [[t, 1], [0, t²]], v = [1, t] at t = 2
+    let t = Dual::<1>::variable(2.0, 0);
+    let one = Dual::one();
+    let zero = Dual::zero();
+    let a = Matrix::<Dual<1>, 2, 2>::from_slice(&[t, zero, one, t * t]);
+    let v = Vector::<Dual<1>, 2>::from_slice(&[one, t]);
+
+    let w = a * v;
+    // w = [t + t, t³] -> dw/dt = [2, 3t²]
+    assert_eq!(w[0].value, 4.0);
+    assert_eq!(w[1].value, 8.0);
+    assert!((w[0].grad[0] - 2.0).abs() < TOL);
+    assert!((w[1].grad[0] - 12.0).abs() < TOL);
+
+    let s = (v + v) * t;
+    assert!((s[1].grad[0] - 8.0).abs() < TOL);
+}
+
+// ============================================================================
+// Second order
+// ============================================================================
+
+#[test]
+fn test_dual2_hessian_of_polynomial() {
+    // f = x² y + 3 x y³
+    let [x, y] = Dual2::<2>::variables([1.5, -2.0]);
+    let f = x * x * y + 3.0 * x * y.powi(3);
+    let (xv, yv) = (1.5, -2.0);
+
+    assert!((f.grad[0] - (2.0 * xv * yv + 3.0 * yv.powi(3))).abs() < TOL);
+    assert!((f.grad[1] - (xv * xv + 9.0 * xv * yv * yv)).abs() < TOL);
+    assert!((f.hess[0][0] - 2.0 * yv).abs() < TOL);
+    assert!((f.hess[0][1] - (2.0 * xv + 9.0 * yv * yv)).abs() < TOL);
+    assert!((f.hess[1][0] - f.hess[0][1]).abs() < TOL);
+    assert!((f.hess[1][1] - 18.0 * xv * yv).abs() < TOL);
+}
+
+#[test]
+fn test_dual2_hessian_of_composition() {
+    // f = exp(x y) / (1 + x²) at (0.5, 0.8), checked against
+    // central differences of the exact gradient from Dual<2>.
+    fn f<T: Float>(x: T, y: T) -> T {
+        (x * y).exp() / (T::one() + x * x)
+    }
+    let (x0, y0) = (0.5, 0.8);
+    let [x, y] = Dual2::<2>::variables([x0, y0]);
+    let h2 = f(x, y);
+
+    let grad_at = |xv: Real, yv: Real| {
+        let [x, y] = Dual::<2>::variables([xv, yv]);
+        f(x, y).grad
+    };
+    let h = 1e-6;
+    for j in 0..2 {
+        let (dx, dy) = if j == 0 { (h, 0.0) } else { (0.0, h) };
+        let gp = grad_at(x0 + dx, y0 + dy);
+        let gm = grad_at(x0 - dx, y0 - dy);
+        for i in 0..2 {
+            let numerical = (gp[i] - gm[i]) / (2.0 * h);
+            assert!(
+                (h2.hess[i][j] - numerical).abs() < 1e-6,
+                "H[{i}][{j}]: {} vs {numerical}",
+                h2.hess[i][j]
+            );
+        }
+    }
+    for (a, b) in h2.grad.iter().zip(grad_at(x0, y0)) {
+        assert!((a - b).abs() < TOL);
+    }
+}
@@ -0,0 +1,86 @@
+//! Tests for the interval scalar `Interval`: enclosure of the exact result,
+//! certain signs, and use as the element type of a `Vector`.
+
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use strelitzia::common::Real;
+use strelitzia::multiarray::Vector;
+use strelitzia::scalars::Interval;
+
+const E: Real = Real::EPSILON;
+
+#[test]
+fn test_arithmetic_encloses_exact_result() {
+    // 0.1 + 0.2 rounds up past 0.3; the interval keeps both candidates.
+    let s = Interval::point(0.1) + 0.2;
+    assert!(s.contains(0.1 + 0.2) && s.contains(0.3));
+    assert!(s.width() > 0.0);
+
+    let a = Interval::new(-1.0, 2.0);
+    let b = Interval::new(3.0, 4.0);
+    let p = a * b;
+    assert!(p.lo() <= -4.0 && p.hi() >= 8.0 && p.width() < 12.0 + 1e-12);
+    let d = a - b;
+    assert!(d.lo() <= -5.0 && d.hi() >= -1.0);
+    let q = 1.0 / b;
+    assert!(q.contains(0.25) && q.contains(1.0 / 3.0));
+    assert_eq!(-a, Interval::new(-2.0, 1.0));
+
+    // Dividing by an interval that contains zero gives no information.
+    assert_eq!(Interval::one() / a, Interval::ENTIRE);
+    assert_eq!(Interval::ENTIRE.sign(), None);
+}
+
+#[test]
+fn test_sign_is_certain_or_none() {
+    assert_eq!(Interval::new(1.0, 2.0).sign(), Some(Ordering::Greater));
+    assert_eq!(Interval::new(-2.0, -1.0).sign(), Some(Ordering::Less));
+    assert_eq!(Interval::zero().sign(), Some(Ordering::Equal));
+    assert_eq!(Interval::new(-1.0, 1.0).sign(), None);
+    assert_eq!(Interval::new(0.0, 1.0).sign(), None);
+
+    // (1 + e)² - (1 + 2e) = e², but the product rounds to 1 + 2e.
+    let x = Interval::point(1.0 + E);
+    let naive = (1.0 + E) * (1.0 + E) - (1.0 + 2.0 * E);
+    assert_eq!(naive, 0.0);
+    let det = x * x - (1.0 + 2.0 * E);
+    assert!(det.contains(E * E) && det.contains(0.0));
+    assert_eq!(det.sign(), None);
+
+    // Well-separated inputs still give a certain answer.
+    let det = Interval::point(3.0) * Interval::point(0.1) - 0.2;
+    assert_eq!(det.sign(), Some(Ordering::Greater));
+}
+
+#[test]
+fn test_assign_ops_sum_and_display() {
+    let mut x = Interval::point(1.0);
+    x += 1.0;
+    x *= Interval::new(1.0, 2.0);
+    x -= 2.0;
+    x /= 2.0;
+    assert!(x.contains(0.0) && x.contains(1.0));
+    assert!(!x.is_point() && x.mid() > 0.0 && x.mid() < 1.0);
+
+    let total: Interval = [0.1, 0.2, 0.3].into_iter().map(Interval::from).sum();
+    assert!(total.contains(0.6));
+    assert_eq!(Interval::new(-1.0, 2.5).to_string(), "[-1, 2.5]");
+}
+
+#[test]
+fn test_interval_vectors() {
+    let a = Vector::<Interval, 2>::new((1.0 + E).into(), (1.0 + 2.0 * E).into());
+    let b = Vector::<Interval, 2>::new(1.0.into(), (1.0 + E).into());
+    let cross = a.x() * b.y() - a.y() * b.x();
+    assert_eq!(cross.sign(), None);
+
+    let c = (a - b) * Interval::point(2.0);
+    assert!(c.x().contains(2.0 * E) && c.y().contains(2.0 * E));
+    assert_eq!(c.x().sign(), Some(Ordering::Greater));
+}
+
+#[test]
+#[should_panic(expected = "Interval::new: lo must not exceed hi")]
+fn test_reversed_bounds_panic() {
+    let _ = Interval::new(1.0, 0.0);
+}
@@ -0,0 +1,205 @@
+//! Tests for the optional `serde` feature: round trips of arrays, fields,
+//! meshes and CVT state, and shape validation on deserialization.
+//!
+//! Run with `cargo test --features serde`.
+#![cfg(feature = "serde")]
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use strelitzia::assert_approx_eq;
+use strelitzia::common::{Complex, Real};
+use strelitzia::fields::{Field, FieldMeta, MeshLocation, ScalarField, Vector3Field};
+use strelitzia::meshgen::cvt::CvtState;
+use strelitzia::meshgen::cvt_solvers::CvtSolverSettings;
+use strelitzia::meshgen::mesh::Mesh;
+use strelitzia::multiarray::*;
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+fn parse_err<T: DeserializeOwned>(json: &str) -> String {
+    match serde_json::from_str::<T>(json) {
+        Ok(_) => panic!("{json} deserialized"),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[test]
+fn test_multiarray_round_trips() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.0,2.0,3.0]");
+    assert_eq!(round_trip(&v), v);
+
+    let m = Matrix2::from_slice(&[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(round_trip(&m), m);
+    let d = DynMatrix::from_slice(&[1.0, 4.0, 2.0, 5.0, 3.0, 6.0], 2, 3);
+    assert_eq!(round_trip(&d), d);
+    assert_eq!(
+        round_trip(&Vector3i::new(-1, 0, 7)),
+        Vector3i::new(-1, 0, 7)
+    );
+    let c = Vector2c::new(Complex::new(1.0, -1.0), Complex::new(0.0, 2.0));
+    assert_eq!(round_trip(&c), c);
+
+    // Native backends serialize flat in storage order.
+    let n = NativeMatrix::<Real, 2, 2>::from_fn(|i, j| (i + 2 * j) as Real);
+    assert_eq!(serde_json::to_string(&n).unwrap(), "[0.0,1.0,2.0,3.0]");
+    assert_eq!(round_trip(&n), n);
+    let t = Tensor222::from_fn(|i, j, k| (i + 2 * j + 4 * k) as Real);
+    assert_eq!(round_trip(&t), t);
+    let nd = NativeDynMatrix::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3, 2);
+    assert_eq!(
+        serde_json::to_string(&nd).unwrap(),
+        "[[1.0,2.0,3.0,4.0,5.0,6.0],3,2]"
+    );
+    assert_eq!(round_trip(&nd), nd);
+
+    let mut coo = CooMatrix::new(3, 3);
+    coo.push(0, 0, 2.0);
+    coo.push(2, 1, -1.0);
+    coo.push(0, 0, 1.0);
+    assert_eq!(round_trip(&coo), coo);
+    let csr = coo.to_csr();
+    assert_eq!(round_trip(&csr), csr);
+}
+
+#[test]
+fn test_rotations_round_trip() {
+    let r2 = Rotation2::new(0.3);
+    assert_eq!(round_trip(&r2), r2);
+    // serde_json's default float parsing may be off by an ULP.
+    let r3 = Rotation3::from_euler_angles(0.1, -0.4, 1.2);
+    assert_approx_eq!(round_trip(&r3).to_matrix(), r3.to_matrix(), ulps = 2);
+    let q = r3.to_quaternion();
+    assert_approx_eq!(round_trip(&q).coords(), q.coords(), ulps = 2);
+    assert_eq!(
+        serde_json::to_string(&Quaternion::identity()).unwrap(),
+        "[0.0,0.0,0.0,1.0]"
+    );
+}
+
+#[test]
+fn test_rotations_are_validated() {
+    assert!(parse_err::<Quaternion>("[1.0,2.0,3.0,4.0]").contains("Quaternion: norm"));
+    let scaled = "[2.0,0.0,0.0,0.0,2.0,0.0,0.0,0.0,2.0]";
+    assert!(parse_err::<Rotation3>(scaled).contains("Rotation3: matrix is not orthonormal"));
+    let reflection = "[1.0,0.0,0.0,-1.0]";
+    assert!(parse_err::<Rotation2>(reflection).contains("determinant +1"));
+}
+
+#[test]
+fn test_multiarray_shapes_are_validated() {
+    assert!(parse_err::<Vector3>("[1.0,2.0]").contains("invalid length 2"));
+    assert!(parse_err::<NativeMatrix<Real, 2, 2>>("[1.0,2.0,3.0]").contains("4 components"));
+    assert!(parse_err::<DynMatrix<Real>>("[[1.0,2.0,3.0],2,2]").contains("Expected 4"));
+    assert!(
+        parse_err::<NativeDynMatrix<Real>>("[[1.0,2.0,3.0],2,2]")
+            .contains("VecStorage: expected 4 components for 2x2, found 3")
+    );
+    assert!(
+        parse_err::<NativeDynVector<Real>>("[[1.0,2.0,3.0,4.0],2,2]")
+            .contains("a vector needs a single column, found 2x2")
+    );
+    let huge = format!("[[],{},2]", usize::MAX);
+    assert!(parse_err::<NativeDynMatrix<Real>>(&huge).contains("overflows usize"));
+
+    let bad_offsets =
+        r#"{"nrows":2,"ncols":2,"row_offsets":[0,1],"col_indices":[0],"values":[1.0]}"#;
+    assert!(parse_err::<CsrMatrix<Real>>(bad_offsets).contains("expected 3 row offsets"));
+    let unsorted =
+        r#"{"nrows":1,"ncols":3,"row_offsets":[0,2],"col_indices":[2,0],"values":[1.0,2.0]}"#;
+    assert!(parse_err::<CsrMatrix<Real>>(unsorted).contains("row 0"));
+    let huge_rows = format!(
+        r#"{{"nrows":{},"ncols":0,"row_offsets":[0],"col_indices":[],"values":[]}}"#,
+        usize::MAX
+    );
+    assert!(parse_err::<CsrMatrix<Real>>(&huge_rows).contains("overflow usize"));
+    let out_of_bounds = r#"{"nrows":2,"ncols":2,"rows":[0,2],"cols":[0,0],"values":[1.0,2.0]}"#;
+    assert!(parse_err::<CooMatrix<Real>>(out_of_bounds).contains("(2, 0) out of bounds"));
+}
+
+#[test]
+fn test_fields_round_trip() {
+    let f: Vector3Field = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        serde_json::to_string(&f).unwrap(),
+        "[[1.0,2.0,3.0],[4.0,5.0,6.0]]"
+    );
+    assert_eq!(round_trip(&f).as_slice(), f.as_slice());
+
+    let z: Field<Complex> = [Complex::new(0.5, -0.5)].into_iter().collect();
+    assert_eq!(round_trip(&z).as_slice(), z.as_slice());
+    assert!(parse_err::<Vector3Field>("[[1.0,2.0,3.0],[4.0]]").contains("invalid length 1"));
+
+    // A field with metadata serializes as {meta, data} and keeps it.
+    let meta = FieldMeta::new("u")
+        .with_units("m/s")
+        .with_location(MeshLocation::Cell)
+        .with_time(0.5);
+    let named = f.clone().with_meta(meta.clone());
+    assert_eq!(
+        serde_json::to_string(&named).unwrap(),
+        r#"{"meta":{"name":"u","units":"m/s","location":"Cell","time":0.5},"data":[[1.0,2.0,3.0],[4.0,5.0,6.0]]}"#
+    );
+    let restored = round_trip(&named);
+    assert_eq!(restored.meta(), Some(&meta));
+    assert_eq!(restored.as_slice(), f.as_slice());
+    assert_eq!(round_trip(&meta), meta);
+
+    // The map form without metadata is accepted too; data is required.
+    let bare: ScalarField = serde_json::from_str(r#"{"data":[1.0,2.0]}"#).unwrap();
+    assert_eq!((bare.as_slice(), bare.meta()), (&[1.0, 2.0][..], None));
+    assert!(parse_err::<ScalarField>(r#"{"meta":null}"#).contains("missing field `data`"));
+    assert!(parse_err::<ScalarField>(r#"{"data":[],"values":[]}"#).contains("unknown field"));
+}
+
+#[test]
+fn test_mesh_round_trip_and_validation() {
+    let x: ScalarField = [0.0, 1.0, 2.0].into_iter().collect();
+    let y: ScalarField = [0.0, 1.0].into_iter().collect();
+    let mesh = Mesh::<Point2>::tensor_product(&x, &y);
+    let back = round_trip(&mesh);
+    assert_eq!(back.vertices.as_slice(), mesh.vertices.as_slice());
+    assert_eq!(back.cells, mesh.cells);
+    assert_eq!(back.cell_types, mesh.cell_types);
+
+    let dangling = r#"{"vertices":[0.0,1.0],"cells":[[0,1],[1,2]],"cell_types":["Edge","Edge"]}"#;
+    assert!(
+        parse_err::<Mesh<Real>>(dangling)
+            .contains("Mesh: cell 1 references vertex 2, but there are 2 vertices")
+    );
+    let untyped = r#"{"vertices":[0.0,1.0],"cells":[[0,1]],"cell_types":[]}"#;
+    assert!(parse_err::<Mesh<Real>>(untyped).contains("1 cells but 0 cell types"));
+}
+
+#[test]
+fn test_cvt_state_and_settings() {
+    let state = CvtState::<Point2> {
+        iteration: 4,
+        seeds: [Point2::new(0.25, 0.5), Point2::new(0.75, 0.5)]
+            .into_iter()
+            .collect(),
+        energy: 0.125,
+        residual: 1e-3,
+        max_mass: Real::NAN,
+        min_mass: Real::NAN,
+    };
+    let json = serde_json::to_string(&state).unwrap();
+    assert!(json.contains(r#""max_mass":null"#));
+    let back = round_trip(&state);
+    assert_eq!(back.iteration, 4);
+    assert_eq!(back.seeds.as_slice(), state.seeds.as_slice());
+    assert_eq!((back.energy, back.residual), (0.125, 1e-3));
+    assert!(back.max_mass.is_nan() && back.min_mass.is_nan());
+
+    // Configs only list what they change.
+    let settings: CvtSolverSettings = serde_json::from_str(r#"{"tol":1e-9}"#).unwrap();
+    assert_eq!(settings.tol, 1e-9);
+    assert_eq!(settings.max_iter, usize::MAX);
+    assert_eq!(settings.converge_sustain, 100);
+    let back = round_trip(&settings);
+    assert_eq!((back.tol, back.max_iter), (1e-9, usize::MAX));
+}
@@ -0,0 +1,342 @@
+//! Tests for the Krylov solvers and preconditioners.
+
+use strelitzia::common::Real;
+use strelitzia::fields::{Field, RealField, SolverInterop};
+use strelitzia::multiarray::{CooMatrix, CsrMatrix, DynVector, Vector3};
+use strelitzia::solvers::{
+    IdentityPreconditioner, Ilu0Preconditioner, JacobiPreconditioner, LinearOperator,
+    LinearSolverResult, LinearSolverSettings, Preconditioner, bicgstab, cg, gmres,
+};
+
+/// 1D Laplacian with Dirichlet ends, scaled by a varying diagonal shift so
+/// Jacobi has something to do.
+fn spd_matrix(n: usize) -> CsrMatrix<Real> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0 + i as Real * 0.1);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+        }
+    }
+    coo.to_csr()
+}
+
+/// Upwinded 1D convection-diffusion: non-symmetric.
+fn nonsymmetric_matrix(n: usize) -> CsrMatrix<Real> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 3.0);
+        if i > 0 {
+            coo.push(i, i - 1, -2.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -0.5);
+        }
+    }
+    coo.to_csr()
+}
+
+fn rhs(n: usize) -> Vec<Real> {
+    (0..n).map(|i| (i as Real * 0.3).sin() + 1.0).collect()
+}
+
+fn true_residual(a: &CsrMatrix<Real>, b: &[Real], x: &[Real]) -> Real {
+    let mut ax = vec![0.0; b.len()];
+    a.mul_vec_into(x, &mut ax);
+    let r: Real = ax.iter().zip(b).map(|(p, q)| (p - q).powi(2)).sum();
+    let b_norm: Real = b.iter().map(|v| v * v).sum();
+    (r / b_norm).sqrt()
+}
+
+fn assert_solved(result: &LinearSolverResult, a: &CsrMatrix<Real>, b: &[Real], x: &[Real]) {
+    assert!(result.converged, "not converged: {result:?}");
+    assert_eq!(result.history.len(), result.iterations + 1);
+    assert_eq!(result.final_residual, *result.history.last().unwrap());
+    assert!(true_residual(a, b, x) < 1e-8);
+}
+
+// ============================================================================
+// Conjugate gradient
+// ============================================================================
+
+#[test]
+fn test_cg_all_preconditioners() {
+    let n = 50;
+    let a = spd_matrix(n);
+    let b = rhs(n);
+    let settings = LinearSolverSettings::default();
+
+    let preconds: Vec<Box<dyn Preconditioner>> = vec![
+        Box::new(IdentityPreconditioner),
+        Box::new(JacobiPreconditioner::try_new(&a).unwrap()),
+        Box::new(Ilu0Preconditioner::try_new(&a).unwrap()),
+    ];
+    for precond in &preconds {
+        let mut x = vec![0.0; n];
+        let result = cg(&a, &b, &mut x, precond.as_ref(), &settings);
+        assert_solved(&result, &a, &b, &x);
+    }
+}
+
+#[test]
+fn test_cg_matrix_free_matches_assembled() {
+    let n = 30;
+    let a = spd_matrix(n);
+    let b = rhs(n);
+    let settings = LinearSolverSettings::default();
+
+    let op = |x: &[Real], y: &mut [Real]| {
+        for i in 0..x.len() {
+            let mut v = (2.0 + i as Real * 0.1) * x[i];
+            if i > 0 {
+                v -= x[i - 1];
+            }
+            if i + 1 < x.len() {
+                v -= x[i + 1];
+            }
+            y[i] = v;
+        }
+    };
+
+    let mut x_free = vec![0.0; n];
+    let mut x_csr = vec![0.0; n];
+    let r_free = cg(&op, &b, &mut x_free, &IdentityPreconditioner, &settings);
+    let r_csr = cg(&a, &b, &mut x_csr, &IdentityPreconditioner, &settings);
+
+    assert_solved(&r_free, &a, &b, &x_free);
+    assert_eq!(r_free.iterations, r_csr.iterations);
+}
+
+#[test]
+fn test_cg_on_field_flat_slices() {
+    // Three decoupled SPD systems interleaved as Vector3 components.
+    let n = 20;
+    let scalar = spd_matrix(n);
+    let mut coo = CooMatrix::new(3 * n, 3 * n);
+    for i in 0..n {
+        let (cols, vals) = scalar.row(i);
+        for (&j, &v) in cols.iter().zip(vals) {
+            for c in 0..3 {
+                coo.push(3 * i + c, 3 * j + c, v);
+            }
+        }
+    }
+    let a = coo.to_csr();
+
+    let b: Field<Vector3> = (0..n).map(|i| Vector3::new(1.0, i as Real, -2.0)).collect();
+    let mut x: Field<Vector3> = (0..n).map(|_| Vector3::zeros()).collect();
+
+    let precond = JacobiPreconditioner::try_new(&a).unwrap();
+    let settings = LinearSolverSettings::default();
+    let result = cg(
+        &a,
+        b.as_flat_slice(),
+        x.as_flat_slice_mut(),
+        &precond,
+        &settings,
+    );
+    assert_solved(&result, &a, b.as_flat_slice(), x.as_flat_slice());
+
+    // Each component solves the scalar system independently.
+    let bx: Vec<Real> = b.iter().map(|v| v.x()).collect();
+    let mut xx = vec![0.0; n];
+    cg(&scalar, &bx, &mut xx, &IdentityPreconditioner, &settings);
+    for i in 0..n {
+        assert!((x[i].x() - xx[i]).abs() < 1e-8);
+    }
+}
+
+// ============================================================================
+// BiCGStab and GMRES
+// ============================================================================
+
+#[test]
+fn test_bicgstab_nonsymmetric() {
+    let n = 60;
+    let a = nonsymmetric_matrix(n);
+    let b = rhs(n);
+    let settings = LinearSolverSettings::default();
+
+    let mut x = vec![0.0; n];
+    let result = bicgstab(&a, &b, &mut x, &IdentityPreconditioner, &settings);
+    assert_solved(&result, &a, &b, &x);
+
+    let ilu = Ilu0Preconditioner::try_new(&a).unwrap();
+    let mut x_ilu = vec![0.0; n];
+    let result_ilu = bicgstab(&a, &b, &mut x_ilu, &ilu, &settings);
+    assert_solved(&result_ilu, &a, &b, &x_ilu);
+    assert!(result_ilu.iterations <= result.iterations);
+}
+
+#[test]
+fn test_gmres_restarted_nonsymmetric() {
+    let n = 60;
+    let a = nonsymmetric_matrix(n);
+    let b = rhs(n);
+    let settings = LinearSolverSettings {
+        restart: 5,
+        ..Default::default()
+    };
+
+    let mut x = vec![0.0; n];
+    let result = gmres(&a, &b, &mut x, &IdentityPreconditioner, &settings);
+    assert_solved(&result, &a, &b, &x);
+    assert!(result.iterations > settings.restart);
+
+    let jacobi = JacobiPreconditioner::try_new(&a).unwrap();
+    let mut x_jac = vec![0.0; n];
+    let result = gmres(&a, &b, &mut x_jac, &jacobi, &settings);
+    assert_solved(&result, &a, &b, &x_jac);
+}
+
+#[test]
+fn test_ilu0_is_exact_for_tridiagonal() {
+    // A tridiagonal matrix has no fill-in, so ILU(0) is the exact LU and
+    // preconditioned GMRES converges in a single iteration.
+    let n = 40;
+    let a = nonsymmetric_matrix(n);
+    let b = rhs(n);
+    let ilu = Ilu0Preconditioner::try_new(&a).unwrap();
+
+    let mut x = vec![0.0; n];
+    let result = gmres(&a, &b, &mut x, &ilu, &LinearSolverSettings::default());
+    assert_solved(&result, &a, &b, &x);
+    assert_eq!(result.iterations, 1);
+
+    let mut z = vec![0.0; n];
+    ilu.apply(&b, &mut z);
+    assert!(true_residual(&a, &b, &z) < 1e-12);
+}
+
+// ============================================================================
+// Termination and edge cases
+// ============================================================================
+
+#[test]
+fn test_zero_rhs_gives_zero_solution() {
+    let a = spd_matrix(5);
+    let b = vec![0.0; 5];
+    let mut x = vec![1.0; 5];
+
+    let result = bicgstab(&a, &b, &mut x, &IdentityPreconditioner, &Default::default());
+    assert!(result.converged);
+    assert_eq!(result.iterations, 0);
+    assert_eq!(x, vec![0.0; 5]);
+}
+
+#[test]
+fn test_initial_guess_already_solution() {
+    let a = spd_matrix(8);
+    let x_exact: Vec<Real> = (0..8).map(|i| i as Real).collect();
+    let b = (&a * &DynVector::from_slice(&x_exact)).into_inner();
+    let mut x = x_exact.clone();
+
+    let result = gmres(
+        &a,
+        b.as_slice(),
+        &mut x,
+        &IdentityPreconditioner,
+        &Default::default(),
+    );
+    assert!(result.converged);
+    assert_eq!(result.iterations, 0);
+}
+
+#[test]
+fn test_max_iter_reports_not_converged() {
+    let n = 100;
+    let a = spd_matrix(n);
+    let b = rhs(n);
+    let settings = LinearSolverSettings {
+        max_iter: 3,
+        ..Default::default()
+    };
+
+    for solver in [cg, bicgstab, gmres] {
+        let mut x = vec![0.0; n];
+        let result = solver(&a, &b, &mut x, &IdentityPreconditioner, &settings);
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.history.len(), 4);
+        assert!(result.final_residual < result.history[0]);
+    }
+}
+
+#[test]
+fn test_cg_stops_on_indefinite_operator() {
+    let neg = |x: &[Real], y: &mut [Real]| {
+        for (yi, xi) in y.iter_mut().zip(x) {
+            *yi = -xi;
+        }
+    };
+    let b = vec![1.0; 4];
+    let mut x = vec![0.0; 4];
+    let result = cg(
+        &neg,
+        &b,
+        &mut x,
+        &IdentityPreconditioner,
+        &Default::default(),
+    );
+    assert!(!result.converged);
+}
+
+#[test]
+fn test_preconditioner_construction_failures() {
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 0, 1.0);
+    coo.push(1, 0, 1.0);
+    let missing_diag = coo.to_csr();
+
+    assert!(JacobiPreconditioner::try_new(&missing_diag).is_none());
+    assert!(Ilu0Preconditioner::try_new(&missing_diag).is_none());
+    assert!(JacobiPreconditioner::try_from_diagonal(&[1.0, 0.0]).is_none());
+
+    // Zero pivot after elimination: [[1, 1], [1, 1]]
+    let mut coo = CooMatrix::new(2, 2);
+    for (i, j) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+        coo.push(i, j, 1.0);
+    }
+    assert!(Ilu0Preconditioner::try_new(&coo.to_csr()).is_none());
+
+    let mut coo = CooMatrix::new(2, 3);
+    coo.push(0, 0, 1.0);
+    coo.push(1, 1, 1.0);
+    assert!(Ilu0Preconditioner::try_new(&coo.to_csr()).is_none());
+}
+
+#[test]
+fn test_bicgstab_breakdown_keeps_iterate() {
+    // Skew-symmetric A = [[0, 1], [-1, 0]] with b = e0 gives r̂·v = 0 on
+    // the first step.
+    let mut coo = CooMatrix::new(2, 2);
+    coo.push(0, 1, 1.0);
+    coo.push(1, 0, -1.0);
+    let a = coo.to_csr();
+    let b = vec![1.0, 0.0];
+    let mut x = vec![0.0; 2];
+    let result = bicgstab(&a, &b, &mut x, &IdentityPreconditioner, &Default::default());
+    assert!(!result.converged);
+    assert_eq!(x, vec![0.0, 0.0]);
+}
+
+#[test]
+fn test_real_field_solve() {
+    let n = 10;
+    let a = spd_matrix(n);
+    let b: RealField = rhs(n).into_iter().collect();
+    let mut x: RealField = (0..n).map(|_| 0.0).collect();
+
+    let op: &dyn LinearOperator = &a;
+    let result = cg(
+        op,
+        b.as_flat_slice(),
+        x.as_flat_slice_mut(),
+        &IdentityPreconditioner,
+        &Default::default(),
+    );
+    assert_solved(&result, &a, b.as_flat_slice(), x.as_flat_slice());
+}
@@ -0,0 +1,111 @@
+//! Tests for the compile-time units layer: `Quantity`, SI dimensions, unit
+//! constants and fields of quantities.
+
+use strelitzia::common::Real;
+use strelitzia::fields::{Field, FieldElement, ReductionOps, SolverInterop, SumOps, Vector3Field};
+use strelitzia::multiarray::{Matrix3, Vector3};
+use strelitzia::units::dim::{self, Dimension};
+use strelitzia::units::*;
+
+const TOL: Real = 1e-12;
+
+#[test]
+fn test_dimensions_combine_at_compile_time() {
+    let length: Length = 6.0 * METRE;
+    let time: Time = 2.0 * SECOND;
+
+    // The annotations are the test: each right-hand side must have that type.
+    let velocity: Velocity = length / time;
+    let acceleration: Acceleration = velocity / time;
+    let force: Force = 3.0 * KILOGRAM * acceleration;
+    let pressure: Pressure = force / (length * length);
+    let energy: Energy = force * length;
+    let frequency: Frequency = 1.0 / time;
+    let ratio: Dimensionless = length / length;
+
+    assert_eq!(velocity.into_value(), 3.0);
+    assert_eq!(force.into_value(), 4.5);
+    assert_eq!(pressure.into_value(), 0.125);
+    assert_eq!(energy.into_value(), 27.0);
+    assert_eq!(frequency.into_value(), 0.5);
+    assert_eq!(ratio, Dimensionless::from(1.0));
+
+    assert_eq!(dim::Pressure::EXPONENTS, [-1, 1, -2, 0, 0, 0, 0]);
+    assert_eq!(dim::Acceleration::symbol(), "m s^-2");
+    assert_eq!(dim::Dimensionless::symbol(), "");
+    assert_eq!(format!("{:?}", acceleration), "1.5 [m s^-2]");
+}
+
+#[test]
+fn test_unit_constants_convert_to_si() {
+    let a = 1500.0 * MILLIMETRE;
+    let b = 1.5 * METRE;
+    assert!((a - b).into_value().abs() < TOL);
+    assert!((a.in_units(CENTIMETRE) - 150.0).abs() < TOL);
+    assert_eq!((2.0 * MEGAPASCAL).in_units(KILOPASCAL), 2000.0);
+    assert_eq!((1.5 * HOUR).in_units(MINUTE), 90.0);
+
+    let mut t = 10.0 * MILLISECOND;
+    t += 5.0 * MILLISECOND;
+    t *= 2.0;
+    t /= 3.0;
+    assert!((t.in_units(MILLISECOND) - 10.0).abs() < TOL);
+    assert!(t < SECOND);
+    assert_eq!(-t + t, Time::default());
+}
+
+#[test]
+fn test_vector_and_matrix_quantities() {
+    let dx: Length<Vector3> = Vector3::new(3.0, 0.0, 4.0) * MILLIMETRE;
+    let dt = 0.5 * SECOND;
+    let v: Velocity<Vector3> = dx / dt;
+    assert!((v.norm().into_value() - 0.01).abs() < TOL);
+
+    let f: Force<Vector3> = NEWTON * Vector3::new(1.0, 2.0, 2.0);
+    let work: Energy = f.dot(&dx);
+    assert!((work.into_value() - 0.011).abs() < TOL);
+    let length: Length = dx.norm();
+    assert!((length.in_units(MILLIMETRE) - 5.0).abs() < TOL);
+
+    // Stress tensor acting on a unit normal gives a traction (a pressure).
+    let sigma: Pressure<Matrix3> = Matrix3::identity() * (2.0 * PASCAL);
+    let n = Dimensionless::from(Vector3::new(0.0, 0.0, 1.0));
+    let traction: Pressure<Vector3> = sigma * n;
+    assert_eq!(traction.into_value(), Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(v.map(|x| x.z()).into_value(), 0.008);
+}
+
+#[test]
+fn test_fields_of_quantities_stay_zero_copy() {
+    assert_eq!(
+        std::mem::size_of::<Velocity<Vector3>>(),
+        std::mem::size_of::<Vector3>()
+    );
+    assert_eq!(<Velocity<Vector3> as FieldElement>::COMPONENTS, 3);
+
+    let raw: Vector3Field = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)]
+        .into_iter()
+        .collect();
+    let mut velocity: Field<Velocity<Vector3>> = Field::from_values(raw);
+    assert_eq!(velocity.as_flat_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    // A solver writes back through the flat view.
+    velocity.as_flat_slice_mut()[5] = 0.0;
+    assert_eq!(velocity[1].into_value(), Vector3::new(4.0, 5.0, 0.0));
+    velocity.values_mut()[0] = Vector3::zeros();
+
+    let increment: Field<Velocity<Vector3>> = [Vector3::new(1.0, 0.0, 0.0) * METRE / SECOND; 2]
+        .into_iter()
+        .collect();
+    velocity += &increment;
+    velocity *= 2.0;
+    assert_eq!(
+        velocity.values(),
+        &[Vector3::new(2.0, 0.0, 0.0), Vector3::new(10.0, 10.0, 0.0)]
+    );
+    assert_eq!(velocity.sum().into_value(), Vector3::new(12.0, 10.0, 0.0));
+
+    let pressure: Field<Pressure> = [1.0 * BAR, 2.0 * BAR].into_iter().collect();
+    assert_eq!(pressure.max().unwrap().in_units(BAR), 2.0);
+    assert_eq!(pressure.into_values().as_slice(), &[1e5, 2e5]);
+}